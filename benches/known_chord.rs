@@ -0,0 +1,76 @@
+//! Benchmarks the [`KnownChord`](klib::core::known_chord::KnownChord) cache of [`Chord`] over 10k chords.
+//!
+//! Run with `cargo bench --bench known_chord`.
+
+#![feature(test)]
+
+extern crate test;
+
+use klib::core::{
+    base::HasDescription,
+    chord::{Chord, Chordable, HasChord, HasScale},
+    modifier::{Degree, Modifier},
+    note::ALL_PITCH_NOTES,
+};
+use test::{black_box, Bencher};
+
+const CHORD_COUNT: usize = 10_000;
+
+/// The number of times that each chord is queried.
+const QUERY_COUNT: usize = 4;
+
+// Benches.
+
+/// Queries chords whose classification is cached after the first query (as in batch analysis).
+#[bench]
+fn bench_cached_queries(b: &mut Bencher) {
+    let chords = chords();
+
+    b.iter(|| {
+        for chord in &chords {
+            query(chord);
+        }
+    });
+}
+
+/// Queries chords whose cache is reset before every query (i.e., the cost without the cache).
+#[bench]
+fn bench_fresh_queries(b: &mut Bencher) {
+    let chords = chords();
+
+    b.iter(|| {
+        for chord in &chords {
+            for _ in 0..QUERY_COUNT {
+                // Changing the modifiers resets the cache (and none of the chords has a dominant 13).
+                let fresh = chord.clone().without_modifier(Modifier::Dominant(Degree::Thirteen));
+
+                query_once(&fresh);
+            }
+        }
+    });
+}
+
+// Helpers.
+
+/// Returns 10k chords of every known quality, over the roots in octaves `0` through `8` (so that no chord tone overflows).
+fn chords() -> Vec<Chord> {
+    ALL_PITCH_NOTES
+        .iter()
+        .take(9 * 12)
+        .flat_map(|root| Chord::all_known_qualities(*root))
+        .cycle()
+        .take(CHORD_COUNT)
+        .collect()
+}
+
+fn query(chord: &Chord) {
+    for _ in 0..QUERY_COUNT {
+        query_once(chord);
+    }
+}
+
+fn query_once(chord: &Chord) {
+    black_box(chord.scale());
+    black_box(chord.chord());
+    black_box(chord.description());
+}
@@ -2,36 +2,498 @@
 //!
 //! Performs ffts, frequency space smoothing, peak detection, harmonic collapsing, and note detection.
 
-use std::{collections::HashMap, ops::Deref};
+use std::{collections::HashMap, f32::consts::PI, ops::Deref};
 
 use rustfft::{
     num_complex::{Complex, ComplexFloat},
     FftPlanner,
 };
 
-use crate::core::note::{HasPrimaryHarmonicSeries, ALL_PITCH_NOTES_WITH_FREQUENCY};
+use crate::core::note::{HasPrimaryHarmonicSeries, ALL_PITCH_NOTES, ALL_PITCH_NOTES_WITH_FREQUENCY};
 
-use crate::core::{base::Res, note::Note, pitch::HasFrequency};
+use crate::core::{base::Res, chord::Chord, note::Note, pitch::HasFrequency};
+
+// Enums.
+
+/// A window function, which is applied to the audio data before the FFT in order to reduce spectral leakage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WindowFunction {
+    /// No windowing (every sample is weighted equally).
+    Rectangular,
+    /// The Hann window.
+    #[default]
+    Hann,
+    /// The Hamming window.
+    Hamming,
+    /// The Blackman window.
+    Blackman,
+}
+
+// Structs.
+
+/// Configuration for the audio analysis.
+///
+/// By default, the entire clip is transformed at once, which yields the best frequency resolution.  Setting a `window_size`
+/// instead averages the spectra of (possibly overlapping) windows, which trades frequency resolution for time resolution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AnalysisConfig {
+    /// The size of each FFT window in samples (must be a power of two), or `None` to transform the entire clip at once.
+    pub window_size: Option<usize>,
+    /// The number of samples between the starts of consecutive windows, or `None` to use half of the window size.
+    pub hop_size: Option<usize>,
+    /// The window function applied to the samples before each FFT.
+    pub window_fn: WindowFunction,
+    /// Whether or not to apply the [harmonic product spectrum](harmonic_product_spectrum) before peak picking.
+    pub harmonic_product_spectrum: bool,
+}
+
+/// The result of analyzing audio data in one pass (see [`analyze_audio_data`]).
+///
+/// This bundles the detected notes with the intermediate data that they were detected from, so that callers which need
+/// more than the notes (e.g., a frequency diagram, or a pitch visualizer) do not need to recompute the FFT.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnalysisResult {
+    /// The detected notes (as in [`get_notes_from_audio_data`]).
+    pub notes: Vec<Note>,
+    /// The smoothed frequency space (pairs of frequency and magnitude, in 1 Hz bins) that the notes were detected from.
+    pub frequency_space: Vec<(f32, f32)>,
+    /// The detection confidence of each of the 12 pitch classes (as in [`get_pitch_confidences`]).
+    pub pitch_confidences: [f32; 12],
+    /// The candidate chords for the notes, most likely first (as in [`Chord::try_from_notes`]), or empty if there are none.
+    pub candidate_chords: Vec<Chord>,
+}
+
+// Impls.
+
+impl WindowFunction {
+    /// Returns the weight of the window at sample `n` of a window of `size` samples.
+    pub fn coefficient(&self, n: usize, size: usize) -> f32 {
+        if size < 2 {
+            return 1.0;
+        }
+
+        let phase = 2.0 * PI * n as f32 / (size - 1) as f32;
+
+        match self {
+            WindowFunction::Rectangular => 1.0,
+            WindowFunction::Hann => 0.5 - 0.5 * phase.cos(),
+            WindowFunction::Hamming => 0.54 - 0.46 * phase.cos(),
+            WindowFunction::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos(),
+        }
+    }
+
+    /// Applies the window to the given data.
+    pub fn apply(&self, data: &[f32]) -> Vec<f32> {
+        if *self == WindowFunction::Rectangular {
+            return data.to_vec();
+        }
+
+        data.iter().enumerate().map(|(n, d)| d * self.coefficient(n, data.len())).collect()
+    }
+}
+
+impl AnalysisConfig {
+    /// Returns a new config with the given FFT window size.
+    pub fn with_window_size(mut self, window_size: usize) -> Self {
+        self.window_size = Some(window_size);
+        self
+    }
+
+    /// Returns a new config with the given hop size.
+    pub fn with_hop_size(mut self, hop_size: usize) -> Self {
+        self.hop_size = Some(hop_size);
+        self
+    }
+
+    /// Returns a new config with the given window function.
+    pub fn with_window_fn(mut self, window_fn: WindowFunction) -> Self {
+        self.window_fn = window_fn;
+        self
+    }
+
+    /// Returns a new config that does (or does not) apply the [harmonic product spectrum](harmonic_product_spectrum).
+    ///
+    /// This suppresses octave errors for tones whose harmonics are louder than their fundamental, but it is best suited to
+    /// single tones (or sparse chords), since it also suppresses notes whose harmonics are missing (e.g., pure sine waves).
+    pub fn with_harmonic_product_spectrum(mut self, harmonic_product_spectrum: bool) -> Self {
+        self.harmonic_product_spectrum = harmonic_product_spectrum;
+        self
+    }
+
+    /// Validates the config, ensuring that the window size is a power of two, and that the hop size fits within a window.
+    pub fn validate(&self) -> Res<()> {
+        let Some(window_size) = self.window_size else {
+            return Ok(());
+        };
+
+        if window_size < MIN_WINDOW_SIZE || !window_size.is_power_of_two() {
+            return Err(anyhow::Error::msg(format!("Window size must be a power of two of at least {MIN_WINDOW_SIZE}, but got {window_size}.")));
+        }
+
+        if let Some(hop_size) = self.hop_size {
+            if hop_size == 0 || hop_size > window_size {
+                return Err(anyhow::Error::msg(format!("Hop size must be between 1 and the window size ({window_size}), but got {hop_size}.")));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Functions.
 
 /// Gets notes from audio data.
 pub fn get_notes_from_audio_data(data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
+    get_notes_from_audio_data_with_config(data, length_in_seconds, &AnalysisConfig::default())
+}
+
+/// Gets notes from audio data using the given analysis config.
+pub fn get_notes_from_audio_data_with_config(data: &[f32], length_in_seconds: u8, config: &AnalysisConfig) -> Res<Vec<Note>> {
+    let smoothed_frequency_space = get_smoothed_frequency_space_from_audio_data(data, length_in_seconds, config)?;
+    //plot_frequency_space(&smoothed_frequency_space, "frequency_space", 100f32, 1000f32);
+
+    Ok(get_notes_from_smoothed_frequency_space(&smoothed_frequency_space))
+}
+
+/// Analyzes audio data in one pass, returning the notes, the frequency space, the pitch confidences, and the candidate chords.
+pub fn analyze_audio_data(data: &[f32], length_in_seconds: u8) -> Res<AnalysisResult> {
+    analyze_audio_data_with_config(data, length_in_seconds, &AnalysisConfig::default())
+}
+
+/// Analyzes audio data in one pass using the given analysis config (see [`analyze_audio_data`]).
+pub fn analyze_audio_data_with_config(data: &[f32], length_in_seconds: u8, config: &AnalysisConfig) -> Res<AnalysisResult> {
+    let frequency_space = get_smoothed_frequency_space_from_audio_data(data, length_in_seconds, config)?;
+
+    let notes = get_notes_from_smoothed_frequency_space(&frequency_space);
+    let pitch_confidences = fold_note_confidences(&note_confidences_from_smoothed_frequency_space(&frequency_space));
+    let candidate_chords = Chord::try_from_notes(&notes).unwrap_or_default();
+
+    Ok(AnalysisResult {
+        notes,
+        frequency_space,
+        pitch_confidences,
+        candidate_chords,
+    })
+}
+
+/// Gets the detection confidence of each of the 128 MIDI notes (indexed by MIDI number) from audio data.
+///
+/// Unlike [`get_notes_from_audio_data`], no thresholds are applied: each value is the summed magnitude of the spectral peaks
+/// closest to that note, normalized so that the strongest note is `1.0`.
+pub fn get_note_confidences(data: &[f32], length_in_seconds: u8) -> Res<[f32; 128]> {
+    let smoothed_frequency_space = get_smoothed_frequency_space_from_audio_data(data, length_in_seconds, &AnalysisConfig::default())?;
+
+    Ok(note_confidences_from_smoothed_frequency_space(&smoothed_frequency_space))
+}
+
+/// Gets the detection confidence of each of the 12 pitch classes (indexed from C) from audio data.
+///
+/// This folds the values of [`get_note_confidences`] across octaves, normalized so that the strongest pitch class is `1.0`.
+pub fn get_pitch_confidences(data: &[f32], length_in_seconds: u8) -> Res<[f32; 12]> {
+    Ok(fold_note_confidences(&get_note_confidences(data, length_in_seconds)?))
+}
+
+/// Gets the detection confidence of each of the 128 MIDI notes from a smoothed frequency space (see [`get_note_confidences`]).
+fn note_confidences_from_smoothed_frequency_space(smoothed_frequency_space: &[(f32, f32)]) -> [f32; 128] {
+    let peak_space = translate_frequency_space_to_peak_space(smoothed_frequency_space);
+
+    let mut confidences = [0f32; 128];
+
+    for (frequency, magnitude) in peak_space.into_iter().filter(|(_, m)| *m > 0.0) {
+        if let Some((note, _)) = binary_search_closest(ALL_PITCH_NOTES_WITH_FREQUENCY.deref(), frequency, |t| t.1) {
+            if let Some(confidence) = confidences.get_mut(note.to_midi() as usize) {
+                *confidence += magnitude;
+            }
+        }
+    }
+
+    normalize(&mut confidences);
+
+    confidences
+}
+
+/// Folds the detection confidences of the 128 MIDI notes across octaves into the 12 pitch classes (see [`get_pitch_confidences`]).
+fn fold_note_confidences(note_confidences: &[f32; 128]) -> [f32; 12] {
+    let mut confidences = [0f32; 12];
+
+    for (midi, confidence) in note_confidences.iter().enumerate() {
+        confidences[midi % 12] += confidence;
+    }
+
+    normalize(&mut confidences);
+
+    confidences
+}
+
+/// Estimates the tempo of the given mono samples, returning the estimated beats per minute along with a confidence in `[0, 1]`.
+///
+/// The tempo is found by autocorrelating the onset strength (the rectified increase in energy between short frames) over the
+/// lags that correspond to 40 - 240 BPM.  The confidence is the strength of the winning lag relative to the zero lag.
+pub fn estimate_bpm_from_samples(samples: &[f32], sample_rate: u32) -> Res<(f32, f32)> {
+    let frame_size = (sample_rate / BPM_FRAMES_PER_SECOND) as usize;
+
+    if frame_size == 0 {
+        return Err(anyhow::Error::msg(format!("Sample rate must be at least {BPM_FRAMES_PER_SECOND} Hz.")));
+    }
+
+    // Compute the energy envelope, and the onset strength.
+
+    let energies = samples.chunks_exact(frame_size).map(|f| f.iter().map(|s| s * s).sum::<f32>() / frame_size as f32).collect::<Vec<_>>();
+    let onsets = energies.windows(2).map(|w| (w[1] - w[0]).max(0.0)).collect::<Vec<_>>();
+
+    let frame_duration = frame_size as f32 / sample_rate as f32;
+    let min_lag = (60.0 / (MAX_BPM * frame_duration)).floor() as usize;
+    let max_lag = (60.0 / (MIN_BPM * frame_duration)).ceil() as usize;
+
+    if onsets.len() <= max_lag + 1 {
+        return Err(anyhow::Error::msg(format!(
+            "At least {:.1} seconds of audio are required to estimate the tempo.",
+            (max_lag + 2) as f32 * frame_duration
+        )));
+    }
+
+    // Autocorrelate the onset strength, and find the strongest lag.
+
+    let autocorrelation = |lag: usize| onsets.iter().zip(&onsets[lag..]).map(|(a, b)| a * b).sum::<f32>();
+
+    let energy = autocorrelation(0);
+    if energy == 0.0 {
+        return Err(anyhow::Error::msg("No onsets found in audio data."));
+    }
+
+    let correlations = (min_lag - 1..=max_lag + 1).map(autocorrelation).collect::<Vec<_>>();
+    let (k, peak) = correlations[1..correlations.len() - 1].iter().enumerate().max_by(|a, b| a.1.partial_cmp(b.1).unwrap()).unwrap();
+
+    // Refine the lag with a parabolic interpolation of the neighboring correlations.
+
+    let (left, right) = (correlations[k], correlations[k + 2]);
+    let denominator = left - 2.0 * peak + right;
+    let offset = if denominator == 0.0 { 0.0 } else { 0.5 * (left - right) / denominator };
+
+    let lag = (min_lag + k) as f32 + offset;
+    let bpm = 60.0 / (lag * frame_duration);
+    let confidence = (peak / energy).clamp(0.0, 1.0);
+
+    Ok((bpm, confidence))
+}
+
+/// Segments the given mono samples into the time spans (start and end, in seconds) of the detected note events.
+///
+/// Onsets are detected with a spectral flux detector (the rectified increase in magnitude of each frequency bin between
+/// consecutive frames), and each segment spans from one onset to the next (or the end of the samples).  This allows a long
+/// clip to be split into per-chord windows, each of which can be analyzed separately.
+pub fn segment_by_onsets(samples: &[f32], sample_rate: u32) -> Vec<(f32, f32)> {
+    let onsets = get_onset_frames(samples);
+
+    let duration = samples.len() as f32 / sample_rate as f32;
+    let times = onsets.into_iter().map(|frame| (frame * ONSET_HOP_SIZE) as f32 / sample_rate as f32).collect::<Vec<_>>();
+
+    times.iter().enumerate().map(|(k, start)| (*start, times.get(k + 1).copied().unwrap_or(duration))).collect()
+}
+
+/// Transcribes the given mono samples into a list of (onset time in seconds, notes) events.
+///
+/// The samples are split with [`segment_by_onsets`], and the notes of each segment are detected independently (short segments are
+/// zero-padded to a whole second).  This works best on clean, sustained chords: overlapping releases, percussive sounds, and
+/// segments shorter than ~100 ms all reduce accuracy, and segments in which no notes are detected are dropped.
+pub fn transcribe_samples(samples: &[f32], sample_rate: u32) -> Res<Vec<(f32, Vec<Note>)>> {
+    if sample_rate < MIN_SAMPLE_RATE {
+        return Err(anyhow::Error::msg(format!("Sample rate must be at least {MIN_SAMPLE_RATE} Hz.")));
+    }
+
+    let mut events = Vec::new();
+
+    for (start, end) in segment_by_onsets(samples, sample_rate) {
+        let start_index = ((start * sample_rate as f32) as usize).min(samples.len());
+        let end_index = ((end * sample_rate as f32) as usize).clamp(start_index, samples.len());
+
+        let length_in_seconds = ((end - start).ceil() as usize).clamp(1, u8::MAX as usize);
+
+        let mut segment = samples[start_index..end_index].to_vec();
+        segment.resize(length_in_seconds * sample_rate as usize, 0.0);
+
+        let notes = get_notes_from_audio_data(&segment, length_in_seconds as u8)?;
+
+        if !notes.is_empty() {
+            events.push((start, notes));
+        }
+    }
+
+    Ok(events)
+}
+
+/// Folds transcribed (onset time, notes) events into (onset time, chord) events, using the most likely chord for each event.
+///
+/// Events whose notes do not form a known chord are dropped.
+pub fn chords_from_transcription(events: &[(f32, Vec<Note>)]) -> Vec<(f32, Chord)> {
+    events
+        .iter()
+        .filter_map(|(time, notes)| Chord::try_from_notes(notes).ok().and_then(|chords| chords.into_iter().next()).map(|chord| (*time, chord)))
+        .collect()
+}
+
+/// Gets the frames (of [`ONSET_HOP_SIZE`] samples) at which onsets occur, using a spectral flux detector.
+fn get_onset_frames(samples: &[f32]) -> Vec<usize> {
+    let mut planner = FftPlanner::new();
+    let fft = planner.plan_fft_forward(ONSET_WINDOW_SIZE);
+
+    // Compute the magnitude spectrum of each frame (centered on the frame start), and the spectral flux between frames.
+
+    let num_frames = samples.len().div_ceil(ONSET_HOP_SIZE);
+    let mut previous = vec![0f32; ONSET_WINDOW_SIZE / 2];
+    let mut flux = Vec::with_capacity(num_frames);
+
+    for frame in 0..num_frames {
+        let center = frame * ONSET_HOP_SIZE;
+
+        let mut buffer = (0..ONSET_WINDOW_SIZE)
+            .map(|n| {
+                let sample = (center + n).checked_sub(ONSET_WINDOW_SIZE / 2).and_then(|k| samples.get(k)).copied().unwrap_or_default();
+
+                Complex::new(sample * WindowFunction::Hann.coefficient(n, ONSET_WINDOW_SIZE), 0.0)
+            })
+            .collect::<Vec<_>>();
+        fft.process(&mut buffer);
+
+        let magnitudes = buffer.into_iter().take(ONSET_WINDOW_SIZE / 2).map(|d| d.abs()).collect::<Vec<_>>();
+        flux.push(magnitudes.iter().zip(&previous).map(|(m, p)| (m - p).max(0.0)).sum::<f32>());
+
+        previous = magnitudes;
+    }
+
+    normalize(&mut flux);
+
+    // Compute the energy that follows each frame, so that abrupt endings (which also splatter energy across the spectrum) are not
+    // mistaken for onsets.
+
+    let mut energies = (0..num_frames)
+        .map(|frame| {
+            let start = (frame * ONSET_HOP_SIZE).min(samples.len());
+            let end = (start + ONSET_WINDOW_SIZE / 2).min(samples.len());
+
+            samples[start..end].iter().map(|s| s * s).sum::<f32>()
+        })
+        .collect::<Vec<_>>();
+
+    normalize(&mut energies);
+
+    // Pick the peaks that stand out from their surroundings.
+
+    let mut onsets: Vec<usize> = Vec::new();
+
+    for k in 0..flux.len() {
+        let neighborhood = &flux[k.saturating_sub(ONSET_PEAK_RADIUS)..(k + ONSET_PEAK_RADIUS + 1).min(flux.len())];
+
+        let is_peak = neighborhood.iter().all(|f| *f <= flux[k]);
+        let is_strong = flux[k] > ONSET_THRESHOLD && flux[k] > neighborhood.iter().sum::<f32>() / neighborhood.len() as f32 + ONSET_THRESHOLD / 2.0;
+        let is_sounding = energies[k] > ONSET_THRESHOLD;
+        let is_separate = onsets.last().map(|last| k - last > ONSET_PEAK_RADIUS).unwrap_or(true);
+
+        if is_peak && is_strong && is_sounding && is_separate {
+            onsets.push(k);
+        }
+    }
+
+    onsets
+}
+
+/// Downmixes interleaved multi-channel samples into mono samples by averaging the channels of each frame.
+pub fn downmix_to_mono(samples: &[f32], num_channels: u16) -> Vec<f32> {
+    if num_channels <= 1 {
+        return samples.to_vec();
+    }
+
+    samples.chunks_exact(num_channels as usize).map(|frame| frame.iter().sum::<f32>() / num_channels as f32).collect()
+}
+
+/// Resamples mono samples from one sample rate to another using linear interpolation.
+pub fn resample(samples: &[f32], from_sample_rate: u32, to_sample_rate: u32) -> Vec<f32> {
+    if from_sample_rate == to_sample_rate || samples.is_empty() {
+        return samples.to_vec();
+    }
+
+    let ratio = from_sample_rate as f64 / to_sample_rate as f64;
+    let num_samples = (samples.len() as f64 / ratio).round() as usize;
+
+    (0..num_samples)
+        .map(|k| {
+            let position = k as f64 * ratio;
+            let index = position as usize;
+            let fraction = (position - index as f64) as f32;
+
+            let current = samples[index.min(samples.len() - 1)];
+            let next = samples[(index + 1).min(samples.len() - 1)];
+
+            current + (next - current) * fraction
+        })
+        .collect()
+}
+
+/// Validates the audio data, and computes its frequency space in 1 Hz bins using the given analysis config.
+fn get_smoothed_frequency_space_from_audio_data(data: &[f32], length_in_seconds: u8, config: &AnalysisConfig) -> Res<Vec<(f32, f32)>> {
     if length_in_seconds < 1 {
         return Err(anyhow::Error::msg("Listening length in seconds must be greater than 1."));
     }
 
+    config.validate()?;
+
     let num_nan = data.iter().filter(|n| n.is_nan()).count();
     if num_nan > 0 {
         return Err(anyhow::Error::msg(format!("{num_nan} NaNs in audio data.")));
     }
 
-    let frequency_space = get_frequency_space(data, length_in_seconds);
+    // Compute the frequency space, and smooth it into 1 Hz bins.
 
-    // Smooth the frequency space.
+    let mut smoothed_frequency_space = match config.window_size {
+        Some(window_size) => {
+            let sample_rate = data.len() as f32 / length_in_seconds as f32;
+            let hop_size = config.hop_size.unwrap_or(window_size / 2);
 
-    let smoothed_frequency_space = get_smoothed_frequency_space(&frequency_space, length_in_seconds);
-    //plot_frequency_space(&smoothed_frequency_space, "frequency_space", 100f32, 1000f32);
+            get_windowed_frequency_space(data, sample_rate, window_size, hop_size, config.window_fn)
+        }
+        None => {
+            let frequency_space = get_frequency_space(&config.window_fn.apply(data), length_in_seconds);
 
-    Ok(get_notes_from_smoothed_frequency_space(&smoothed_frequency_space))
+            get_smoothed_frequency_space(&frequency_space, length_in_seconds)
+        }
+    };
+
+    if config.harmonic_product_spectrum {
+        let magnitudes = smoothed_frequency_space.iter().map(|(_, m)| *m).collect::<Vec<_>>();
+
+        for ((_, m), h) in smoothed_frequency_space.iter_mut().zip(harmonic_product_spectrum(&magnitudes)) {
+            *m = h;
+        }
+    }
+
+    Ok(smoothed_frequency_space)
+}
+
+/// Gets notes from an in-memory buffer of PCM samples.
+///
+/// The samples are expected to be mono `f32` values (nominally in `[-1.0, 1.0]`) recorded at `sample_rate`, and `duration` is
+/// the length of the clip in seconds.  Since the analysis works in 1 Hz bins, the clip is truncated to the nearest whole second,
+/// so at least one second of audio is required.  The sample rate must be high enough to cover the analyzed range (up to ~8 kHz).
+pub fn get_notes_from_samples(samples: &[f32], sample_rate: u32, duration: f32) -> Res<Vec<Note>> {
+    if sample_rate < MIN_SAMPLE_RATE {
+        return Err(anyhow::Error::msg(format!("Sample rate must be at least {MIN_SAMPLE_RATE} Hz.")));
+    }
+
+    if duration.is_nan() || duration < 1.0 {
+        return Err(anyhow::Error::msg("Sample duration in seconds must be at least 1."));
+    }
+
+    let length_in_seconds = duration.min(u8::MAX as f32) as u8;
+    let num_samples = length_in_seconds as usize * sample_rate as usize;
+
+    if samples.len() < num_samples {
+        return Err(anyhow::Error::msg(format!(
+            "Expected at least {num_samples} samples for {length_in_seconds} second(s) at {sample_rate} Hz, but got {}.",
+            samples.len()
+        )));
+    }
+
+    get_notes_from_audio_data(&samples[..num_samples], length_in_seconds)
 }
 
 /// Gets notes from pre-smoothed frequency data (helps with model training deterministic features).
@@ -70,6 +532,71 @@ pub fn get_frequency_space(data: &[f32], length_in_seconds: u8) -> Vec<(f32, f32
     buffer.into_iter().enumerate().map(|(k, d)| (k as f32 / length_in_seconds as f32, d.abs())).collect::<Vec<_>>()
 }
 
+/// Gets the frequency space from the audio data by averaging the magnitude spectra of the windows of `window_size` samples,
+/// each starting `hop_size` samples after the previous one, and weighted by `window_fn`.
+///
+/// The result is resampled into 1 Hz bins (covering `sample_rate` bins, like the smoothed frequency space), so that it can be
+/// fed directly into the peak detection.
+pub fn get_windowed_frequency_space(data: &[f32], sample_rate: f32, window_size: usize, hop_size: usize, window_fn: WindowFunction) -> Vec<(f32, f32)> {
+    let mut planner = FftPlanner::new();
+    let fft = planner.plan_fft_forward(window_size);
+
+    // Average the magnitude spectra of every window (the last window is zero-padded, if needed).
+
+    let mut spectrum = vec![0f32; window_size];
+    let mut num_windows = 0;
+
+    let mut start = 0;
+    loop {
+        let end = (start + window_size).min(data.len());
+
+        let mut buffer = data[start..end]
+            .iter()
+            .enumerate()
+            .map(|(n, d)| Complex::new(d * window_fn.coefficient(n, window_size), 0.0))
+            .collect::<Vec<_>>();
+        buffer.resize(window_size, Complex::new(0.0, 0.0));
+        fft.process(&mut buffer);
+
+        for (total, d) in spectrum.iter_mut().zip(buffer) {
+            *total += d.abs();
+        }
+        num_windows += 1;
+
+        if end == data.len() {
+            break;
+        }
+
+        start += hop_size.max(1);
+    }
+
+    spectrum.iter_mut().for_each(|m| *m /= num_windows as f32);
+
+    // Resample into 1 Hz bins by averaging the FFT bins that fall into each bin, or interpolating between the two nearest FFT bins
+    // when there are none (i.e., when the FFT resolution is coarser than 1 Hz).
+
+    let bins_per_hertz = window_size as f32 / sample_rate;
+    let magnitude_at = |k: usize| spectrum[k % window_size];
+
+    (0..sample_rate as usize)
+        .map(|frequency| {
+            let low = ((frequency as f32 - 0.5) * bins_per_hertz).max(0.0).ceil() as usize;
+            let high = ((frequency as f32 + 0.5) * bins_per_hertz).ceil() as usize;
+
+            let magnitude = if high > low {
+                (low..high).map(magnitude_at).sum::<f32>() / (high - low) as f32
+            } else {
+                let position = frequency as f32 * bins_per_hertz;
+                let fraction = position.fract();
+
+                magnitude_at(position as usize) * (1.0 - fraction) + magnitude_at(position as usize + 1) * fraction
+            };
+
+            (frequency as f32, magnitude)
+        })
+        .collect()
+}
+
 /// Gets the time space from the frequency space.
 pub fn get_time_space(data: &[f32]) -> Vec<(f32, f32)> {
     let num_samples = data.len();
@@ -85,52 +612,77 @@ pub fn get_time_space(data: &[f32]) -> Vec<(f32, f32)> {
     buffer.into_iter().enumerate().map(|(k, d)| (k as f32, d.abs())).collect::<Vec<_>>()
 }
 
-/// Computes the CQT (constant Q transform) from the frequency space.
+/// Computes the CQT (constant-Q transform) from the frequency space (i.e., magnitudes in 1 Hz bins, where the index is the frequency).
+///
+/// The result has [`CQT_NUM_BINS`] bins, one per semitone, where bin `k` is centered on the `k`th semitone above `C2` (i.e.,
+/// bin `0` is `C2`, bin `12` is `C3`, and the last bin is `B6`).  Each bin is the Hann-weighted average of the magnitudes within
+/// a semitone-wide band around its center, so every bin spans the same musical interval (unlike the linear frequency space,
+/// where a fixed bin width spans several semitones in the bass).  See [`cqt_to_notes`] to pick the notes from the result.
 pub fn compute_cqt(frequency_space: &[f32]) -> Vec<f32> {
-    const Q_FACTOR: f32 = 24.7; // Q-factor for the CQT
-    const MIN_FREQ: f32 = 65.41; // minimum frequency for the CQT
-    const MAX_FREQ: f32 = 2093.0; // maximum frequency for the CQT
-    const N_BINS: usize = 60; // number of frequency bins for the CQT
+    let half_band = 2f32.powf(1.0 / 24.0);
 
-    let mut cqt_output = vec![vec![0.0; frequency_space.len()]; N_BINS];
+    (0..CQT_NUM_BINS)
+        .map(|k| {
+            let center = ALL_PITCH_NOTES[CQT_LOWEST_NOTE_INDEX + k].frequency();
+            let (low, high) = (center / half_band, center * half_band);
+            let half_width = (high - low) / 2.0;
 
-    let log_min_freq = MIN_FREQ.log2();
-    let log_max_freq = MAX_FREQ.log2();
-    let log_freq_step = (log_max_freq - log_min_freq) / (N_BINS as f32 - 1.0);
+            let start = low.ceil() as usize;
+            let end = (high.floor() as usize).min(frequency_space.len().saturating_sub(1));
 
-    for i in 0..N_BINS {
-        let log_freq_center = log_min_freq + i as f32 * log_freq_step;
-        let freq_center = 2.0f32.powf(log_freq_center);
-        let freq_bw = freq_center / Q_FACTOR;
-        let fft_freq_step = 1.0;
+            let (sum, weight) = (start..=end).fold((0.0, 0.0), |(sum, weight), j| {
+                let w = 0.5 + 0.5 * (PI * (j as f32 - center) / half_width).cos();
 
-        let start_bin = (freq_center - freq_bw / 2.0) / fft_freq_step;
-        let end_bin = (freq_center + freq_bw / 2.0) / fft_freq_step;
+                (sum + w * frequency_space[j], weight + w)
+            });
 
-        let mut cqt_bin = vec![rustfft::num_complex::Complex::new(0.0, 0.0); frequency_space.len()];
-
-        for j in start_bin as usize..=end_bin as usize {
-            let weight = (j as f32 - freq_center / fft_freq_step) / freq_bw;
-            let weight = weight * std::f32::consts::PI * 2.0;
-            let fft_bin = frequency_space[j];
-            cqt_bin[j] = rustfft::num_complex::Complex::new(fft_bin * weight.sin(), 0.0);
-        }
+            if weight > 0.0 {
+                sum / weight
+            } else {
+                frequency_space.get(center.round() as usize).copied().unwrap_or_default()
+            }
+        })
+        .collect()
+}
 
-        let ifft = rustfft::FftPlanner::<f32>::new().plan_fft_inverse(cqt_bin.len());
-        ifft.process(&mut cqt_bin);
+/// Picks the notes from the result of [`compute_cqt`].
+///
+/// A note is picked when its bin is a local maximum (i.e., a peak) with at least a fifth of the magnitude of the largest bin.
+/// The notes are returned in ascending order.
+pub fn cqt_to_notes(cqt: &[f32]) -> Vec<Note> {
+    let max = cqt.iter().copied().fold(0f32, f32::max);
 
-        for j in 0..frequency_space.len() {
-            cqt_output[i][j] = cqt_bin[j].abs();
-        }
+    if max <= 0.0 {
+        return Vec::new();
     }
 
-    let mut result = vec![];
-    for k in 0..N_BINS {
-        let mut sum = 0.0;
-        for j in 0..frequency_space.len() {
-            sum += cqt_output[k][j];
-        }
-        result.push(sum);
+    (0..cqt.len().min(CQT_NUM_BINS))
+        .filter(|&k| {
+            let previous = if k > 0 { cqt[k - 1] } else { 0.0 };
+            let next = cqt.get(k + 1).copied().unwrap_or_default();
+
+            cqt[k] >= CQT_PEAK_THRESHOLD * max && cqt[k] > previous && cqt[k] >= next
+        })
+        .map(|k| ALL_PITCH_NOTES[CQT_LOWEST_NOTE_INDEX + k])
+        .collect()
+}
+
+/// Computes the HPS (harmonic product spectrum) of the frequency space (i.e., magnitudes in 1 Hz bins, where the index is the frequency).
+///
+/// Each bin is the product of the magnitudes at its first [`HPS_NUM_HARMONICS`] harmonics (i.e., at one, two, and three times its
+/// frequency), so a fundamental is reinforced by its harmonics, while a harmonic (whose own harmonics are mostly missing) is
+/// suppressed.  This avoids guessing a note an octave too high when its second harmonic is louder than its fundamental.  Bins whose
+/// harmonics fall beyond the end of the frequency space are zero, and the result is scaled to have the same maximum as the input.
+pub fn harmonic_product_spectrum(frequency_space: &[f32]) -> Vec<f32> {
+    let mut result = (0..frequency_space.len())
+        .map(|k| (1..=HPS_NUM_HARMONICS).map(|h| frequency_space.get(k * h).copied().unwrap_or_default()).product::<f32>())
+        .collect::<Vec<_>>();
+
+    let input_max = frequency_space.iter().copied().fold(0f32, f32::max);
+    let output_max = result.iter().copied().fold(0f32, f32::max);
+
+    if output_max > 0.0 {
+        result.iter_mut().for_each(|v| *v *= input_max / output_max);
     }
 
     result
@@ -222,7 +774,9 @@ fn get_likely_notes_from_peak_space(peak_space: &[(f32, f32)], cutoff: f32) -> V
     let mut peak_space = peak_space.iter().filter(|(_, m)| *m > 0.1).copied().collect::<Vec<_>>();
     peak_space.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
-    let max_power = peak_space[0].1;
+    let Some(&(_, max_power)) = peak_space.first() else {
+        return Vec::new();
+    };
 
     // Take all peaks with 10% or more of the max power.
     let peak_space = peak_space.into_iter().filter(|(_, m)| *m > max_power * cutoff).collect::<Vec<_>>();
@@ -256,7 +810,7 @@ fn reduce_notes_by_harmonic_series(notes: &[(Note, f32)], cutoff: f32) -> Vec<No
             let other_note = working_set[j].0;
 
             for harmonic in note.primary_harmonic_series() {
-                if harmonic.frequency() == other_note.frequency() {
+                if harmonic.is_enharmonic_with(&other_note) {
                     working_set[k].1 += working_set[j].1;
                     working_set.remove(j);
                     j -= 1;
@@ -275,7 +829,11 @@ fn reduce_notes_by_harmonic_series(notes: &[(Note, f32)], cutoff: f32) -> Vec<No
 
     // Remove notes that are below the threshold.
 
-    let cutoff = working_set[0].1 * cutoff;
+    let Some(&(_, max_magnitude)) = working_set.first() else {
+        return Vec::new();
+    };
+
+    let cutoff = max_magnitude * cutoff;
     working_set.retain(|(_, magnitude)| *magnitude > cutoff);
 
     working_set.into_iter().map(|(note, _)| note).collect()
@@ -350,13 +908,69 @@ where
     }
 }
 
+/// Normalizes the values in place so that the largest is `1.0` (leaving all-zero values untouched).
+fn normalize(values: &mut [f32]) {
+    let max = values.iter().copied().fold(0f32, f32::max);
+
+    if max > 0.0 {
+        values.iter_mut().for_each(|v| *v /= max);
+    }
+}
+
+// Statics.
+
+/// The sample rate that decoded audio is resampled to before analysis.
+pub const ANALYSIS_SAMPLE_RATE: u32 = 44_100;
+
+/// The number of (semitone) bins in the [constant-Q transform](compute_cqt), covering five octaves from `C2`.
+pub const CQT_NUM_BINS: usize = 60;
+
+/// The index (in [`ALL_PITCH_NOTES`]) of the note at the center of the lowest CQT bin (i.e., `C2`).
+const CQT_LOWEST_NOTE_INDEX: usize = 24;
+
+/// The (relative) magnitude a CQT bin must reach to be picked as a note.
+const CQT_PEAK_THRESHOLD: f32 = 0.2;
+
+/// The number of harmonics (including the fundamental) multiplied by the [harmonic product spectrum](harmonic_product_spectrum).
+pub const HPS_NUM_HARMONICS: usize = 3;
+
+/// The lowest sample rate that still covers the analyzed frequency range.
+const MIN_SAMPLE_RATE: u32 = 16_000;
+
+/// The smallest FFT window size allowed by [`AnalysisConfig`].
+const MIN_WINDOW_SIZE: usize = 256;
+
+/// The number of energy frames per second used for tempo estimation.
+const BPM_FRAMES_PER_SECOND: u32 = 200;
+
+/// The FFT window size used for onset detection.
+const ONSET_WINDOW_SIZE: usize = 2048;
+
+/// The number of samples between consecutive frames of the onset detection.
+const ONSET_HOP_SIZE: usize = 512;
+
+/// The number of frames on either side of an onset that it must dominate.
+const ONSET_PEAK_RADIUS: usize = 8;
+
+/// The (normalized) spectral flux an onset must exceed.
+const ONSET_THRESHOLD: f32 = 0.2;
+
+/// The slowest tempo considered by the tempo estimation.
+const MIN_BPM: f32 = 40.0;
+
+/// The fastest tempo considered by the tempo estimation.
+const MAX_BPM: f32 = 240.0;
+
 // Tests.
 
 #[cfg(test)]
 pub(crate) mod tests {
     use std::{fs::File, io::Read};
 
-    use crate::core::note::ALL_PITCH_NOTES;
+    use crate::core::{
+        base::HasName,
+        note::{AThree, BFlatThree, CTwo, DFlatTwo, GThree, A, ALL_PITCH_NOTES, B, C, D, E, G},
+    };
 
     use super::*;
 
@@ -388,6 +1002,253 @@ pub(crate) mod tests {
         get_notes_from_audio_data(&[0.0, 0.0, f32::NAN], 10).unwrap();
     }
 
+    pub fn synthesize_notes(notes: &[Note], sample_rate: u32, length_in_seconds: f32) -> Vec<f32> {
+        let num_samples = (sample_rate as f32 * length_in_seconds) as usize;
+
+        (0..num_samples)
+            .map(|k| {
+                let t = k as f32 / sample_rate as f32;
+                notes.iter().map(|n| (2.0 * PI * n.frequency() * t).sin()).sum::<f32>() / notes.len() as f32
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_get_notes_from_samples() {
+        let chord = [C, E, G];
+        let samples = synthesize_notes(&chord, 44_100, 2.0);
+
+        let mut notes = get_notes_from_samples(&samples, 44_100, 2.0).unwrap();
+        notes.sort();
+
+        assert_eq!(notes, chord.to_vec());
+    }
+
+    #[test]
+    fn test_get_notes_from_samples_failure() {
+        assert!(get_notes_from_samples(&[0.0; 16_000], 8_000, 2.0).is_err());
+        assert!(get_notes_from_samples(&[0.0; 44_100], 44_100, 0.5).is_err());
+        assert!(get_notes_from_samples(&[0.0; 44_100], 44_100, 2.0).is_err());
+    }
+
+    #[test]
+    fn test_analysis_config() {
+        let data = synthesize_notes(&[AThree, BFlatThree], 44_100, 2.0);
+
+        let coarse = get_notes_from_audio_data_with_config(&data, 2, &AnalysisConfig::default().with_window_size(2048)).unwrap();
+        let fine = get_notes_from_audio_data_with_config(&data, 2, &AnalysisConfig::default().with_window_size(16_384)).unwrap();
+
+        assert!(!(coarse.contains(&AThree) && coarse.contains(&BFlatThree)));
+        assert!(fine.contains(&AThree) && fine.contains(&BFlatThree));
+    }
+
+    #[test]
+    fn test_analysis_config_validation() {
+        assert!(AnalysisConfig::default().validate().is_ok());
+        assert!(AnalysisConfig::default().with_window_size(4096).with_hop_size(1024).validate().is_ok());
+        assert!(AnalysisConfig::default().with_window_size(3000).validate().is_err());
+        assert!(AnalysisConfig::default().with_window_size(64).validate().is_err());
+        assert!(AnalysisConfig::default().with_window_size(4096).with_hop_size(0).validate().is_err());
+        assert!(AnalysisConfig::default().with_window_size(4096).with_hop_size(8192).validate().is_err());
+    }
+
+    #[test]
+    fn test_window_function() {
+        // The fraction of the spectral energy that lands within a few bins of the peak.
+        let sharpness = |window_fn: WindowFunction| {
+            let data = window_fn.apply(&synthesize_notes(&[C], 44_100, 1.0));
+            let frequency_space = get_frequency_space(&data, 1);
+            let half = &frequency_space[..frequency_space.len() / 2];
+
+            let peak = half.iter().enumerate().max_by(|a, b| a.1 .1.partial_cmp(&b.1 .1).unwrap()).unwrap().0;
+            let total = half.iter().map(|(_, m)| m).sum::<f32>();
+            let near = half[peak - 3..=peak + 3].iter().map(|(_, m)| m).sum::<f32>();
+
+            near / total
+        };
+
+        let rectangular = sharpness(WindowFunction::Rectangular);
+
+        assert!(sharpness(WindowFunction::Hann) > rectangular);
+        assert!(sharpness(WindowFunction::Hamming) > rectangular);
+        assert!(sharpness(WindowFunction::Blackman) > rectangular);
+    }
+
+    #[test]
+    fn test_get_pitch_confidences() {
+        let data = synthesize_notes(&[C, E, G], 44_100, 2.0);
+
+        let pitch_confidences = get_pitch_confidences(&data, 2).unwrap();
+        let note_confidences = get_note_confidences(&data, 2).unwrap();
+
+        for (k, confidence) in pitch_confidences.iter().enumerate() {
+            if [0, 4, 7].contains(&k) {
+                assert!(*confidence > 0.5);
+            } else {
+                assert!(*confidence < 0.1);
+            }
+        }
+
+        assert!(note_confidences.iter().all(|c| (0.0..=1.0).contains(c)));
+        assert!([C, E, G].iter().all(|n| note_confidences[n.to_midi() as usize] > 0.5));
+    }
+
+    #[test]
+    fn test_analyze_audio_data() {
+        let data = synthesize_notes(&[C, E, G], 44_100, 2.0);
+
+        let result = analyze_audio_data(&data, 2).unwrap();
+
+        assert_eq!(result.notes, get_notes_from_audio_data(&data, 2).unwrap());
+        assert_eq!(result.pitch_confidences, get_pitch_confidences(&data, 2).unwrap());
+        assert!(!result.frequency_space.is_empty());
+
+        // The candidate chords are those of the notes (the order of equally likely candidates may differ between guesses).
+        let mut candidates = result.candidate_chords.iter().map(|c| c.name()).collect::<Vec<_>>();
+        let mut expected = Chord::try_from_notes(&result.notes).unwrap().iter().map(|c| c.name()).collect::<Vec<_>>();
+
+        assert_eq!(candidates[0], "C");
+        assert_eq!(candidates[0], expected[0]);
+
+        candidates.sort();
+        expected.sort();
+
+        assert_eq!(candidates, expected);
+
+        // Notes that do not form a chord have no candidates.
+        let result = analyze_audio_data(&synthesize_notes(&[A], 44_100, 1.0), 1).unwrap();
+
+        assert!(result.candidate_chords.is_empty());
+    }
+
+    pub fn synthesize_clicks(bpm: f32, sample_rate: u32, length_in_seconds: f32) -> Vec<f32> {
+        let beat_length = (60.0 / bpm * sample_rate as f32) as usize;
+        let click_length = sample_rate as usize / 100;
+
+        (0..(sample_rate as f32 * length_in_seconds) as usize)
+            .map(|k| {
+                if k % beat_length < click_length {
+                    (2.0 * PI * 1000.0 * k as f32 / sample_rate as f32).sin()
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_estimate_bpm_from_samples() {
+        for bpm in [72.0, 120.0, 150.0] {
+            let data = synthesize_clicks(bpm, 44_100, 10.0);
+
+            let (estimate, confidence) = estimate_bpm_from_samples(&data, 44_100).unwrap();
+
+            assert!((estimate - bpm).abs() < 1.0, "expected {bpm}, got {estimate}");
+            assert!(confidence > 0.5);
+        }
+    }
+
+    #[test]
+    fn test_estimate_bpm_from_samples_failure() {
+        assert!(estimate_bpm_from_samples(&[0.0; 44_100], 44_100).is_err());
+        assert!(estimate_bpm_from_samples(&[0.0; 441_000], 44_100).is_err());
+    }
+
+    #[test]
+    fn test_segment_by_onsets() {
+        let mut data = synthesize_notes(&[C, E, G], 44_100, 2.0);
+        data.extend(synthesize_notes(&[G, B, D], 44_100, 2.0));
+
+        let segments = segment_by_onsets(&data, 44_100);
+
+        assert_eq!(segments.len(), 2);
+        assert!(segments[0].0 < 0.05);
+        assert!((segments[0].1 - 2.0).abs() < 0.05);
+        assert_eq!(segments[0].1, segments[1].0);
+        assert_eq!(segments[1].1, 4.0);
+
+        assert!(segment_by_onsets(&[0.0; 44_100], 44_100).is_empty());
+    }
+
+    #[test]
+    fn test_transcribe_samples() {
+        let mut data = synthesize_notes(&[C, E, G], 44_100, 2.0);
+        data.extend(synthesize_notes(&[G, B, D], 44_100, 2.0));
+
+        let events = transcribe_samples(&data, 44_100).unwrap();
+        let chords = chords_from_transcription(&events);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(chords.len(), 2);
+        assert!(chords[0].0 < chords[1].0);
+        assert_eq!(chords[0].1.name(), "C");
+        assert_eq!(chords[1].1.name(), "G");
+    }
+
+    #[test]
+    fn test_downmix_to_mono() {
+        assert_eq!(downmix_to_mono(&[1.0, 0.0, 0.5, 0.5, -1.0, 0.0], 2), vec![0.5, 0.5, -0.5]);
+        assert_eq!(downmix_to_mono(&[1.0, 0.0, 0.5], 1), vec![1.0, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_resample() {
+        let data = synthesize_notes(&[A], 48_000, 1.0);
+        let resampled = resample(&data, 48_000, ANALYSIS_SAMPLE_RATE);
+
+        assert_eq!(resampled.len(), ANALYSIS_SAMPLE_RATE as usize);
+        assert_eq!(resample(&[0.0, 1.0], 1, 2), vec![0.0, 0.5, 1.0, 1.0]);
+        assert_eq!(get_notes_from_samples(&resampled, ANALYSIS_SAMPLE_RATE, 1.0).unwrap(), vec![A]);
+    }
+
+    #[test]
+    fn test_compute_cqt() {
+        // A linear FFT of a 2048-sample window at 44.1 kHz has ~21.5 Hz bins, so `C2` and `D♭2` share a bin.
+        let bin_width = ANALYSIS_SAMPLE_RATE as f32 / 2048.0;
+        assert_eq!((CTwo.frequency() / bin_width) as usize, (DFlatTwo.frequency() / bin_width) as usize);
+
+        // The CQT resolves them (and a bass fifth).
+        for notes in [vec![CTwo], vec![DFlatTwo], vec![CTwo, GThree]] {
+            let samples = synthesize_notes(&notes, ANALYSIS_SAMPLE_RATE, 1.0);
+            let frequency_space = get_frequency_space(&samples, 1).into_iter().map(|(_, m)| m).collect::<Vec<_>>();
+
+            let cqt = compute_cqt(&frequency_space);
+
+            assert_eq!(cqt.len(), CQT_NUM_BINS);
+            assert_eq!(cqt_to_notes(&cqt), notes);
+        }
+
+        assert!(cqt_to_notes(&[0.0; CQT_NUM_BINS]).is_empty());
+    }
+
+    #[test]
+    fn test_harmonic_product_spectrum() {
+        // A tone whose second harmonic is much louder than its fundamental.
+        let num_samples = ANALYSIS_SAMPLE_RATE as usize;
+        let samples = (0..num_samples)
+            .map(|k| {
+                let t = k as f32 / ANALYSIS_SAMPLE_RATE as f32;
+                let f = AThree.frequency();
+
+                0.2 * (2.0 * PI * f * t).sin() + 1.0 * (2.0 * PI * 2.0 * f * t).sin() + 0.4 * (2.0 * PI * 3.0 * f * t).sin()
+            })
+            .collect::<Vec<_>>();
+
+        let frequency_space = get_frequency_space(&samples, 1).into_iter().map(|(_, m)| m).collect::<Vec<_>>();
+        let argmax = |values: &[f32]| values.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).unwrap().0;
+
+        assert_eq!(argmax(&frequency_space[..4000]), 440);
+
+        let hps = harmonic_product_spectrum(&frequency_space[..4000]);
+
+        assert_eq!(hps.len(), 4000);
+        assert_eq!(argmax(&hps), 220);
+
+        let config = AnalysisConfig::default().with_harmonic_product_spectrum(true);
+
+        assert_eq!(get_notes_from_audio_data_with_config(&samples, 1, &config).unwrap(), vec![AThree]);
+    }
+
     #[test]
     fn test_get_time_space() {
         let data = load_test_data();
@@ -10,18 +10,65 @@ use std::{
 
 use rodio::{buffer::SamplesBuffer, Decoder, OutputStream, Source};
 
-use crate::core::{base::Res, note::Note};
+use crate::core::{base::Res, chord::Chord, note::Note};
 
-use super::base::get_notes_from_audio_data;
+use super::base::{
+    analyze_audio_data_with_config, chords_from_transcription, downmix_to_mono, estimate_bpm_from_samples, get_notes_from_audio_data_with_config, resample, transcribe_samples, AnalysisConfig,
+    AnalysisResult, ANALYSIS_SAMPLE_RATE,
+};
 
 /// Retrieve a list of notes which are guessed from the given audio clip.
 pub fn get_notes_from_audio_file(file: impl AsRef<Path>, start: Option<Duration>, end: Option<Duration>) -> Res<Vec<Note>> {
+    get_notes_from_audio_file_with_config(file, start, end, &AnalysisConfig::default())
+}
+
+/// Retrieve a list of notes which are guessed from the given audio clip, using the given analysis config.
+pub fn get_notes_from_audio_file_with_config(file: impl AsRef<Path>, start: Option<Duration>, end: Option<Duration>, config: &AnalysisConfig) -> Res<Vec<Note>> {
+    let (data, length_in_seconds) = get_audio_data_from_file(file, start, end)?;
+
+    get_notes_from_audio_data_with_config(&data, length_in_seconds, config)
+}
+
+/// Analyzes the given audio clip in one pass, returning the notes, the frequency space, the pitch confidences, and the candidate chords.
+pub fn analyze_audio_file(file: impl AsRef<Path>, start: Option<Duration>, end: Option<Duration>) -> Res<AnalysisResult> {
+    analyze_audio_file_with_config(file, start, end, &AnalysisConfig::default())
+}
+
+/// Analyzes the given audio clip in one pass, using the given analysis config (see [`analyze_audio_file`]).
+pub fn analyze_audio_file_with_config(file: impl AsRef<Path>, start: Option<Duration>, end: Option<Duration>, config: &AnalysisConfig) -> Res<AnalysisResult> {
     let (data, length_in_seconds) = get_audio_data_from_file(file, start, end)?;
 
-    get_notes_from_audio_data(&data, length_in_seconds)
+    analyze_audio_data_with_config(&data, length_in_seconds, config)
+}
+
+/// Transcribes the given audio file into a list of (onset time in seconds, notes) events.
+///
+/// See [`transcribe_samples`] for the accuracy limitations.
+pub fn transcribe(file: impl AsRef<Path>) -> Res<Vec<(f32, Vec<Note>)>> {
+    let (data, _) = get_audio_data_from_file(file, None, None)?;
+
+    transcribe_samples(&data, ANALYSIS_SAMPLE_RATE)
+}
+
+/// Transcribes the given audio file into a list of (onset time in seconds, chord) events.
+///
+/// Events whose notes do not form a known chord are dropped.
+pub fn transcribe_chords(file: impl AsRef<Path>) -> Res<Vec<(f32, Chord)>> {
+    Ok(chords_from_transcription(&transcribe(file)?))
+}
+
+/// Estimates the tempo of the given audio clip, returning the estimated beats per minute along with a confidence in `[0, 1]`.
+pub fn estimate_bpm(file: impl AsRef<Path>, start: Option<Duration>, end: Option<Duration>) -> Res<(f32, f32)> {
+    let (data, _) = get_audio_data_from_file(file, start, end)?;
+
+    estimate_bpm_from_samples(&data, ANALYSIS_SAMPLE_RATE)
 }
 
 /// Gets the audio data from a file.
+///
+/// The decoded samples are downmixed to mono, resampled to [`ANALYSIS_SAMPLE_RATE`], and cut to the nearest second.  WAV and FLAC
+/// are always supported, while MP3, OGG (Vorbis), AAC, and ALAC require the `analyze_file_mp3`, `analyze_file_ogg`, `analyze_file_aac`,
+/// and `analyze_file_alac` features, respectively.
 pub fn get_audio_data_from_file(file: impl AsRef<Path>, start: Option<Duration>, end: Option<Duration>) -> Res<(Vec<f32>, u8)> {
     let path = file.as_ref();
     let start = start.unwrap_or_default();
@@ -32,12 +79,14 @@ pub fn get_audio_data_from_file(file: impl AsRef<Path>, start: Option<Duration>,
     let sample_rate = decoder.sample_rate();
     let samples: Vec<_> = if let Some(end) = end { decoder.take_duration(end - start).collect() } else { decoder.collect() };
 
-    let num_samples = samples.len();
+    // Normalize the samples to mono at the analysis sample rate.
 
-    let length_in_seconds = dbg!(num_samples as f32 / (sample_rate as f32 * num_channels as f32)) as u8;
+    let samples = resample(&downmix_to_mono(&samples, num_channels), sample_rate, ANALYSIS_SAMPLE_RATE);
+
+    let length_in_seconds = (samples.len() / ANALYSIS_SAMPLE_RATE as usize).min(u8::MAX as usize) as u8;
 
     // Cut the samples to the nearest second.
-    let data = samples[..(length_in_seconds as f32 * sample_rate as f32 * num_channels as f32) as usize].to_vec();
+    let data = samples[..length_in_seconds as usize * ANALYSIS_SAMPLE_RATE as usize].to_vec();
 
     Ok((data, length_in_seconds))
 }
@@ -98,6 +147,35 @@ mod tests {
         assert_eq!(Chord::parse("C7b9").unwrap(), Chord::try_from_notes(&notes).unwrap()[0]);
     }
 
+    #[cfg(feature = "analyze_file")]
+    #[test]
+    fn test_analyze_audio_file() {
+        let result = analyze_audio_file("tests/C7b9.wav", None, None).unwrap();
+
+        assert_eq!(result.notes, get_notes_from_audio_file("tests/C7b9.wav", None, None).unwrap());
+        assert_eq!(result.candidate_chords[0], Chord::parse("C7b9").unwrap());
+        assert_eq!(result.candidate_chords[0], Chord::try_from_notes(&result.notes).unwrap()[0]);
+    }
+
+    #[cfg(feature = "analyze_file")]
+    #[test]
+    fn test_estimate_bpm() {
+        let (bpm, confidence) = estimate_bpm("tests/click120.wav", None, None).unwrap();
+
+        assert!((bpm - 120.0).abs() < 1.0);
+        assert!(confidence > 0.5);
+    }
+
+    #[cfg(feature = "analyze_file")]
+    #[cfg(feature = "analyze_file_mp3")]
+    #[test]
+    fn test_get_audio_data_from_mp3_file() {
+        let (data, length_in_seconds) = get_audio_data_from_file("tests/C7b9.mp3", None, None).unwrap();
+
+        assert!(length_in_seconds > 0);
+        assert_eq!(data.len(), length_in_seconds as usize * ANALYSIS_SAMPLE_RATE as usize);
+    }
+
     #[cfg(feature = "analyze_file")]
     #[cfg(feature = "analyze_file_mp3")]
     #[test]
@@ -13,22 +13,38 @@ use cpal::{
 
 use crate::core::{base::Res, note::Note};
 
-use super::base::get_notes_from_audio_data;
+use super::base::{analyze_audio_data_with_config, get_notes_from_audio_data_with_config, AnalysisConfig, AnalysisResult};
 
 /// Gets notes from the microphone input over the specified period of time.
 #[coverage(off)]
 pub async fn get_notes_from_microphone(length_in_seconds: u8) -> Res<Vec<Note>> {
+    get_notes_from_microphone_with_config(length_in_seconds, &AnalysisConfig::default()).await
+}
+
+/// Gets notes from the microphone input over the specified period of time, using the given analysis config.
+#[coverage(off)]
+pub async fn get_notes_from_microphone_with_config(length_in_seconds: u8, config: &AnalysisConfig) -> Res<Vec<Note>> {
     // Get data.
 
     let data_from_microphone = get_audio_data_from_microphone(length_in_seconds).await?;
 
     // Get notes.
 
-    let result = get_notes_from_audio_data(&data_from_microphone, length_in_seconds)?;
+    let result = get_notes_from_audio_data_with_config(&data_from_microphone, length_in_seconds, config)?;
 
     Ok(result)
 }
 
+/// Analyzes the microphone input over the specified period of time in one pass, using the given analysis config.
+///
+/// See [`analyze_audio_data`](super::base::analyze_audio_data) for the contents of the result.
+#[coverage(off)]
+pub async fn analyze_microphone_with_config(length_in_seconds: u8, config: &AnalysisConfig) -> Res<AnalysisResult> {
+    let data_from_microphone = get_audio_data_from_microphone(length_in_seconds).await?;
+
+    analyze_audio_data_with_config(&data_from_microphone, length_in_seconds, config)
+}
+
 /// Gets audio data from the microphone.
 #[coverage(off)]
 pub async fn get_audio_data_from_microphone(length_in_seconds: u8) -> Res<Vec<f32>> {
@@ -1,18 +1,93 @@
 use std::path::PathBuf;
 
-use clap::{ArgAction, Parser, Subcommand};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
 use klib::core::{
-    base::{Parsable, Res, Void},
-    chord::{Chord, Chordable},
+    base::{HasDescription, HasName, HasPreciseName, HasStaticName, Parsable, Res, Void},
+    chord::{Chord, Chordable, HasChord, HasInversion, HasRoot, HasScale},
+    interval::Interval,
+    known_chord::HasScaleNames,
     note::Note,
     octave::Octave,
+    progression::Progression,
+    tuning::Tuning,
 };
+use serde::{Deserialize, Serialize};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     #[command(subcommand)]
     command: Option<Command>,
+
+    /// Sets the output format of the chord descriptions (for `describe`, `guess`, `transpose`, and `progression`).
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Sets the reference frequency of `A4` (in Hz, from 380 to 480, defaulting to 440) used to synthesize audio (for `play` and
+    /// `loop`); when given, the frequencies of the chord tones are also printed.
+    #[arg(long, global = true, value_parser = parse_a4)]
+    a4: Option<f32>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    /// Human-readable text.
+    Text,
+    /// JSON (with a stable schema, suitable for scripting).
+    Json,
+}
+
+/// The (stable) JSON schema of an analyzed chord in a progression.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ProgressionChordOutput {
+    /// The roman numeral of the chord in the key (e.g., `ii7`).
+    numeral: String,
+    /// The harmonic function of the chord in the key (e.g., `subdominant`, or `borrowed`).
+    function: String,
+    /// The chord itself.
+    chord: ChordOutput,
+}
+
+/// The (stable) JSON schema of a described chord.
+///
+/// This is intentionally decoupled from the internal representation of [`Chord`].
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ChordOutput {
+    /// The friendly name of the chord (e.g., `Cmaj7`).
+    name: String,
+    /// The precise name of the chord (e.g., `Cmaj7@4`).
+    precise_name: String,
+    /// The description of the chord (e.g., `major 7, ionian, first mode of major scale`).
+    description: String,
+    /// The root of the chord, with its octave (e.g., `C4`).
+    root: String,
+    /// The inversion of the chord.
+    inversion: u8,
+    /// The scale of the chord, with octaves (e.g., `["C4", "D4", ...]`).
+    scale: Vec<String>,
+    /// The names of the scales that fit the chord (e.g., `["ionian", "major"]`).
+    scale_candidates: Vec<String>,
+    /// The chord tones, with octaves (e.g., `["C4", "E4", "G4", "B4"]`).
+    chord: Vec<String>,
+    /// The frequencies of the chord tones (in Hz), using the `--a4` tuning (only present when `--a4` is given).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    frequencies: Option<Vec<f32>>,
+}
+
+impl ChordOutput {
+    fn new(chord: &Chord, tuning: Option<&Tuning>) -> Self {
+        Self {
+            name: chord.name(),
+            precise_name: chord.precise_name(),
+            description: chord.description().to_string(),
+            root: chord.root().name(),
+            inversion: chord.inversion(),
+            scale: chord.scale().iter().map(HasName::name).collect(),
+            scale_candidates: chord.scale_names().iter().map(|s| (*s).to_owned()).collect(),
+            chord: chord.chord().iter().map(HasName::name).collect(),
+            frequencies: tuning.map(|t| chord.frequencies_with(t)),
+        }
+    }
 }
 
 #[derive(Subcommand, Debug)]
@@ -75,6 +150,43 @@ enum Command {
         bpm: f32,
     },
 
+    /// Transposes a chord, and describes the result.
+    ///
+    /// Exactly one of `--by`, `--semitones`, or `--to` must be given.  Please see `describe` for more information on the chord symbol syntax.
+    Transpose {
+        /// Chord symbol to transpose.
+        symbol: String,
+
+        /// Transposes by the given interval shorthand (e.g., `P5`, `m3`, or `M9`), prefixed with `-` to transpose down.
+        #[arg(short, long, allow_hyphen_values = true)]
+        by: Option<String>,
+
+        /// Transposes by the given number of semitones (negative values transpose down).
+        #[arg(short, long, allow_hyphen_values = true)]
+        semitones: Option<i8>,
+
+        /// Transposes so that the root becomes the given note (e.g., `Eb`).
+        #[arg(short, long)]
+        to: Option<String>,
+    },
+
+    /// Analyzes a chord progression in a (major) key, and describes each chord.
+    ///
+    /// The progression may be given as roman numerals (e.g., "ii V7 I"), or as chord symbols (e.g., "Dm7 G7 Cmaj7"),
+    /// and each chord is annotated with its roman numeral and harmonic function in the key.
+    Progression {
+        /// The roman numerals, or chord symbols, of the progression.
+        progression: Vec<String>,
+
+        /// Sets the (major) key of the progression.
+        #[arg(short, long, default_value = "C")]
+        key: String,
+
+        /// Makes bare roman numerals the diatonic sevenths of the key (e.g., "ii V7 I" is "Dm7 G7 Cmaj7"), rather than triads.
+        #[arg(long, action=ArgAction::SetTrue, default_value_t = false)]
+        sevenths: bool,
+    },
+
     /// Attempt to guess the chord from a set of notes (ordered by simplicity).
     Guess {
         /// A set of notes from which the guesser will attempt to build a chord.
@@ -312,16 +424,33 @@ fn main() -> Void {
 }
 
 fn start(args: Args) -> Void {
+    let format = args.format;
+    let tuning = args.a4.map(Tuning::equal);
+    let tuning = tuning.as_ref();
+
     match args.command {
         Some(Command::Describe { symbol, octave }) => {
-            let chord = Chord::parse(&symbol)?.with_octave(Octave::Zero + octave);
+            let octave = Octave::try_from_i8(octave).ok_or_else(|| anyhow::Error::msg("The octave must be between 0 and 15."))?;
+            let chord = Chord::parse(&symbol)?.with_octave(octave);
 
-            describe(&chord);
+            println!("{}", describe_output(&chord, format, tuning)?);
         }
         Some(Command::Play { symbol, delay, length, fade_in }) => {
             let chord = Chord::parse(&symbol)?;
 
-            play(&chord, delay, length, fade_in)?;
+            play(&chord, tuning, delay, length, fade_in)?;
+        }
+        Some(Command::Transpose { symbol, by, semitones, to }) => {
+            let chord = Chord::parse(&symbol)?;
+            let transposed = transpose(&chord, by.as_deref(), semitones, to.as_deref())?;
+
+            println!("{}", describe_output(&transposed, format, tuning)?);
+        }
+        Some(Command::Progression { progression, key, sevenths }) => {
+            let key = Note::parse(&key)?;
+            let chords = parse_progression(&progression.join(" "), key, sevenths)?;
+
+            println!("{}", progression_output(&chords, key, format, tuning)?);
         }
         Some(Command::Guess { notes }) => {
             // Parse the notes.
@@ -330,28 +459,18 @@ fn start(args: Args) -> Void {
             // Get the chord from the notes.
             let candidates = Chord::try_from_notes(&notes)?;
 
-            for candidate in candidates {
-                describe(&candidate);
-            }
+            println!("{}", guess_output(&candidates, format, tuning)?);
         }
         Some(Command::Loop { chords, bpm }) => {
-            let chord_pairs = chords
+            let chord_pairs = Chord::parse_many_timed(&chords.join(" "))?
                 .into_iter()
-                .map(|c| {
-                    let mut parts = c.split('|');
-
-                    let chord = Chord::parse(parts.next().unwrap()).unwrap();
-
-                    let length = parts.next().map_or(32, |l| l.parse::<u16>().unwrap());
-
-                    (chord, length)
-                })
+                .map(|(chord, length)| (chord, length.unwrap_or(32)))
                 .collect::<Vec<_>>();
 
             loop {
                 for (chord, length) in &chord_pairs {
                     let length = (*length as f32) * 60f32 / bpm / 8f32;
-                    play(chord, 0.0, length, 0.1)?;
+                    play(chord, tuning, 0.0, length, 0.1)?;
                 }
             }
         }
@@ -361,7 +480,7 @@ fn start(args: Args) -> Void {
             Some(AnalyzeCommand::Mic { length }) => {
                 let notes = futures::executor::block_on(Note::try_from_mic(length))?;
 
-                show_notes_and_chords(&notes)?;
+                show_notes_and_chords(&notes, tuning)?;
             }
             #[cfg(feature = "analyze_file")]
             Some(AnalyzeCommand::File { preview, start_time, end_time, source }) => {
@@ -373,7 +492,7 @@ fn start(args: Args) -> Void {
                     preview_audio_file_clip(&source, start_time, end_time)?;
                 }
                 let notes = get_notes_from_audio_file(&source, start_time, end_time)?;
-                show_notes_and_chords(&notes)?;
+                show_notes_and_chords(&notes, tuning)?;
             }
             None => {
                 return Err(anyhow::Error::msg("No subcommand given for `analyze`."));
@@ -482,7 +601,7 @@ fn start(args: Args) -> Void {
                     let notes = infer(&audio_data, length)?;
 
                     // Show the results.
-                    show_notes_and_chords(&notes)?;
+                    show_notes_and_chords(&notes, tuning)?;
                 }
                 #[cfg(feature = "analyze_file")]
                 Some(InferCommand::File { preview, start_time, end_time, source }) => {
@@ -505,7 +624,7 @@ fn start(args: Args) -> Void {
                     let notes = infer(&audio_data, length)?;
 
                     // Show the results.
-                    show_notes_and_chords(&notes)?;
+                    show_notes_and_chords(&notes, tuning)?;
                 }
                 _ => {
                     return Err(anyhow::Error::msg("Invalid inference command."));
@@ -515,7 +634,7 @@ fn start(args: Args) -> Void {
             Some(MlCommand::Plot { source, x_min, x_max }) => {
                 use anyhow::Context;
                 use klib::{
-                    analyze::base::{compute_cqt, translate_frequency_space_to_peak_space},
+                    analyze::base::{compute_cqt, translate_frequency_space_to_peak_space, CQT_NUM_BINS},
                     helpers::plot_frequency_space,
                     ml::base::{
                         helpers::{harmonic_convolution, load_kord_item, mel_filter_banks_from},
@@ -541,7 +660,7 @@ fn start(args: Args) -> Void {
                 // Plot CQT space.
                 let cqt_file_name = format!("{}_cqt", name);
                 let cqt_space = compute_cqt(&kord_item.frequency_space).into_iter().enumerate().map(|(k, v)| (k as f32, v)).collect::<Vec<_>>();
-                plot_frequency_space(&cqt_space, "KordItem CQT Space", &cqt_file_name, 0.0, 256.0);
+                plot_frequency_space(&cqt_space, "KordItem CQT Space", &cqt_file_name, 0.0, CQT_NUM_BINS as f32);
 
                 // Plot mel space.
                 let mel_file_name = format!("{}_mel", name);
@@ -599,26 +718,125 @@ fn start(args: Args) -> Void {
     Ok(())
 }
 
-fn describe(chord: &Chord) {
-    println!("{chord}");
+fn describe(chord: &Chord, tuning: Option<&Tuning>) {
+    println!("{}", chord_text(chord, tuning));
+}
+
+/// Returns the text description of the chord, followed by the frequencies of the chord tones if a tuning is given (i.e., with `--a4`).
+fn chord_text(chord: &Chord, tuning: Option<&Tuning>) -> String {
+    let Some(tuning) = tuning else {
+        return chord.to_string();
+    };
+
+    let frequencies = chord.frequencies_with(tuning).iter().map(|f| format!("{:.2} Hz", f)).collect::<Vec<_>>().join(", ");
+
+    format!("{}\n   {}", chord, frequencies)
+}
+
+fn describe_output(chord: &Chord, format: OutputFormat, tuning: Option<&Tuning>) -> Res<String> {
+    match format {
+        OutputFormat::Text => Ok(chord_text(chord, tuning)),
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(&ChordOutput::new(chord, tuning))?),
+    }
+}
+
+fn guess_output(candidates: &[Chord], format: OutputFormat, tuning: Option<&Tuning>) -> Res<String> {
+    match format {
+        OutputFormat::Text => Ok(candidates.iter().map(|c| chord_text(c, tuning)).collect::<Vec<_>>().join("\n")),
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(&candidates.iter().map(|c| ChordOutput::new(c, tuning)).collect::<Vec<_>>())?),
+    }
+}
+
+fn parse_progression(input: &str, key: Note, sevenths: bool) -> Res<Vec<Chord>> {
+    // Note letters may be lowercase (e.g., `c am f g`), so treat the input as chord symbols whenever the first token parses as one.
+    let first = input.split(|c: char| c.is_whitespace() || c == ',' || c == '|').find(|t| !t.is_empty()).unwrap_or_default();
+
+    if Chord::parse(first).is_ok() {
+        Chord::parse_many(input)
+    } else if sevenths {
+        Progression::parse_sevenths(input, key)
+    } else {
+        Progression::parse(input, key)
+    }
+}
+
+fn progression_output(chords: &[Chord], key: Note, format: OutputFormat, tuning: Option<&Tuning>) -> Res<String> {
+    match format {
+        OutputFormat::Text => Ok(chords
+            .iter()
+            .map(|c| {
+                format!(
+                    "{} ({})\n{}",
+                    Progression::roman_numeral(c, key),
+                    Progression::harmonic_function(c, key).static_name(),
+                    chord_text(c, tuning)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")),
+        OutputFormat::Json => {
+            let output = chords
+                .iter()
+                .map(|c| ProgressionChordOutput {
+                    numeral: Progression::roman_numeral(c, key),
+                    function: Progression::harmonic_function(c, key).static_name().to_owned(),
+                    chord: ChordOutput::new(c, tuning),
+                })
+                .collect::<Vec<_>>();
+
+            Ok(serde_json::to_string_pretty(&output)?)
+        }
+    }
+}
+
+fn transpose(chord: &Chord, by: Option<&str>, semitones: Option<i8>, to: Option<&str>) -> Res<Chord> {
+    match (by, semitones, to) {
+        (Some(by), None, None) => {
+            let (is_down, shorthand) = match by.strip_prefix('-') {
+                Some(shorthand) => (true, shorthand),
+                None => (false, by),
+            };
+
+            let interval = shorthand.parse::<Interval>()?;
+
+            if is_down {
+                chord.transpose_down(interval)
+            } else {
+                chord.transpose(interval)
+            }
+        }
+        (None, Some(semitones), None) => chord.transpose_semitones(semitones),
+        (None, None, Some(to)) => chord.transpose_to(Note::parse(to)?),
+        _ => Err(anyhow::Error::msg("Please specify exactly one of `--by`, `--semitones`, or `--to`.")),
+    }
 }
 
-fn play(chord: &Chord, delay: f32, length: f32, fade_in: f32) -> Void {
-    describe(chord);
+fn play(chord: &Chord, tuning: Option<&Tuning>, delay: f32, length: f32, fade_in: f32) -> Void {
+    describe(chord, tuning);
 
     #[cfg(feature = "audio")]
     {
-        use klib::core::base::Playable;
         use std::time::Duration;
 
-        let _playable = chord.play(Duration::from_secs_f32(delay), Duration::from_secs_f32(length), Duration::from_secs_f32(fade_in))?;
+        let tuning = tuning.copied().unwrap_or_default();
+        let _playable = chord.play_with_tuning(&tuning, Duration::from_secs_f32(delay), Duration::from_secs_f32(length), Duration::from_secs_f32(fade_in))?;
         std::thread::sleep(Duration::from_secs_f32(length));
     }
 
     Ok(())
 }
 
-fn show_notes_and_chords(notes: &[Note]) -> Res<()> {
+fn parse_a4(a4: &str) -> Result<f32, String> {
+    let a4 = a4.parse::<f32>().map_err(|e| e.to_string())?;
+
+    if !(380.0..=480.0).contains(&a4) {
+        return Err(format!("The A4 frequency must be between 380 and 480 Hz (got {a4})."));
+    }
+
+    Ok(a4)
+}
+
+fn show_notes_and_chords(notes: &[Note], tuning: Option<&Tuning>) -> Res<()> {
     println!("Notes: {}", notes.iter().map(ToString::to_string).collect::<Vec<_>>().join(" "));
 
     let candidates = Chord::try_from_notes(notes)?;
@@ -627,7 +845,7 @@ fn show_notes_and_chords(notes: &[Note]) -> Res<()> {
         println!("No chord candidates found");
     } else {
         for candidate in candidates {
-            describe(&candidate);
+            describe(&candidate, tuning);
         }
     }
     Ok(())
@@ -646,6 +864,8 @@ mod tests {
                 symbol: "Cmaj7b9@3^2!".to_string(),
                 octave: 4,
             }),
+            format: OutputFormat::Text,
+            a4: None,
         })
         .unwrap();
     }
@@ -656,7 +876,140 @@ mod tests {
             command: Some(Command::Guess {
                 notes: vec!["C".to_owned(), "E".to_owned(), "G".to_owned()],
             }),
+            format: OutputFormat::Text,
+            a4: None,
         })
         .unwrap();
     }
+
+    #[test]
+    fn test_transpose() {
+        let args = Args::try_parse_from(["kord", "transpose", "Cmaj7", "--by", "P5"]).unwrap();
+
+        start(args).unwrap();
+
+        let chord = Chord::parse("Cmaj7").unwrap();
+
+        assert_eq!(transpose(&chord, Some("P5"), None, None).unwrap(), Chord::parse("Gmaj7").unwrap());
+        assert_eq!(transpose(&chord, Some("-M2"), None, None).unwrap(), Chord::parse("Bbmaj7@3").unwrap());
+        assert_eq!(transpose(&chord, None, Some(7), None).unwrap(), Chord::parse("Gmaj7").unwrap());
+        assert_eq!(transpose(&chord, None, Some(-5), None).unwrap(), Chord::parse("Gmaj7@3").unwrap());
+        assert_eq!(transpose(&chord, None, None, Some("Eb")).unwrap(), Chord::parse("Ebmaj7").unwrap());
+
+        let args = Args::try_parse_from(["kord", "transpose", "Cmaj7", "--semitones", "-5"]).unwrap();
+
+        start(args).unwrap();
+    }
+
+    #[test]
+    fn test_transpose_failure() {
+        let chord = Chord::parse("Cmaj7").unwrap();
+
+        assert!(transpose(&chord, Some("X5"), None, None).unwrap_err().to_string().contains("Invalid interval `X5`"));
+        assert!(transpose(&chord, Some("P5"), Some(7), None).is_err());
+        assert!(transpose(&chord, None, None, None).is_err());
+        assert!(transpose(&chord, None, None, Some("H")).is_err());
+
+        // Transpositions beyond the octave range are errors (rather than panics).
+        assert!(transpose(&chord, None, Some(-100), None).unwrap_err().to_string().contains("octave range"));
+        assert!(transpose(&Chord::parse("C@0").unwrap(), Some("-m2"), None, None).is_err());
+        assert!(start(Args::try_parse_from(["kord", "transpose", "C", "--semitones=-100"]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_progression() {
+        let args = Args::try_parse_from(["kord", "progression", "--key", "C", "ii V7 I"]).unwrap();
+
+        start(args).unwrap();
+
+        let chords = parse_progression("ii V7 I", Note::parse("C").unwrap(), false).unwrap();
+        let output = progression_output(&chords, Note::parse("C").unwrap(), OutputFormat::Text, None).unwrap();
+        let headers = output.lines().filter(|l| l.contains('(')).collect::<Vec<_>>();
+
+        assert_eq!(headers, vec!["ii (subdominant)", "V7 (dominant)", "I (tonic)"]);
+        assert!(output.contains("Dm\n"));
+        assert!(output.contains("G7\n"));
+        assert!(output.contains("C\n"));
+
+        // Bare numerals are diatonic sevenths with `--sevenths`.
+        let args = Args::try_parse_from(["kord", "progression", "--sevenths", "ii V7 I"]).unwrap();
+
+        start(args).unwrap();
+
+        let chords = parse_progression("ii V7 I", Note::parse("C").unwrap(), true).unwrap();
+        let output = progression_output(&chords, Note::parse("C").unwrap(), OutputFormat::Text, None).unwrap();
+        let headers = output.lines().filter(|l| l.contains('(')).collect::<Vec<_>>();
+
+        assert_eq!(chords, Chord::parse_many("Dm7 G7 Cmaj7").unwrap());
+        assert_eq!(headers, vec!["ii7 (subdominant)", "V7 (dominant)", "Imaj7 (tonic)"]);
+
+        // Chord symbols are annotated with numerals (including borrowed chords).
+        let chords = parse_progression("Dm7 G7 Cmaj7 Bb7", Note::parse("C").unwrap(), false).unwrap();
+        let output = progression_output(&chords, Note::parse("C").unwrap(), OutputFormat::Json, None).unwrap();
+        let output: Vec<ProgressionChordOutput> = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(output.iter().map(|c| c.numeral.as_str()).collect::<Vec<_>>(), vec!["ii7", "V7", "Imaj7", "bVII7"]);
+        assert_eq!(output[3].function, "borrowed");
+        assert_eq!(output[3].chord.name, "B♭7");
+
+        // Lowercase note letters are chord symbols (rather than roman numerals).
+        let chords = parse_progression("c am f g", Note::parse("C").unwrap(), true).unwrap();
+
+        assert_eq!(chords, Chord::parse_many("C Am F G").unwrap());
+        assert_eq!(parse_progression("vi IV", Note::parse("C").unwrap(), false).unwrap(), Chord::parse_many("Am F").unwrap());
+    }
+
+    #[test]
+    fn test_a4() {
+        let args = Args::try_parse_from(["kord", "play", "Am"]).unwrap();
+        assert_eq!(args.a4, None);
+
+        let args = Args::try_parse_from(["kord", "--a4", "432", "play", "Am"]).unwrap();
+        assert_eq!(args.a4, Some(432.0));
+
+        // Without `--a4`, the output is unchanged (i.e., no frequencies are printed).
+        let chord = Chord::parse("Am").unwrap();
+        let json: ChordOutput = serde_json::from_str(&describe_output(&chord, OutputFormat::Json, None).unwrap()).unwrap();
+
+        assert_eq!(describe_output(&chord, OutputFormat::Text, None).unwrap(), chord.to_string());
+        assert_eq!(json.frequencies, None);
+        assert!(!serde_json::to_string(&json).unwrap().contains("frequencies"));
+
+        // With `--a4`, the printed frequencies use the tuning.
+        let tuning = Tuning::equal(432.0);
+        let text = describe_output(&chord, OutputFormat::Text, Some(&tuning)).unwrap();
+        let json: ChordOutput = serde_json::from_str(&describe_output(&chord, OutputFormat::Json, Some(&tuning)).unwrap()).unwrap();
+
+        assert!(text.ends_with("432.00 Hz, 513.74 Hz, 647.27 Hz"));
+        assert_eq!(json.frequencies, Some(chord.frequencies_with(&tuning)));
+
+        // The reference frequency must be within range.
+        assert!(Args::try_parse_from(["kord", "--a4", "500", "play", "Am"]).is_err());
+        assert!(Args::try_parse_from(["kord", "--a4", "A", "play", "Am"]).is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        let args = Args::try_parse_from(["kord", "describe", "Cmaj7", "--format", "json"]).unwrap();
+
+        assert_eq!(args.format, OutputFormat::Json);
+
+        start(args).unwrap();
+
+        let output = describe_output(&Chord::parse("Cmaj7").unwrap(), OutputFormat::Json, None).unwrap();
+        let output: ChordOutput = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(output.name, "Cmaj7");
+        assert_eq!(output.root, "C4");
+        assert_eq!(output.inversion, 0);
+        assert_eq!(output.chord, vec!["C4", "E4", "G4", "B4"]);
+        assert_eq!(output.scale_candidates, vec!["ionian", "major"]);
+
+        let candidates = Chord::try_from_notes(&[Note::parse("C").unwrap(), Note::parse("E").unwrap(), Note::parse("G").unwrap()]).unwrap();
+        let output = guess_output(&candidates, OutputFormat::Json, None).unwrap();
+        let output: Vec<ChordOutput> = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(output.len(), candidates.len());
+        assert_eq!(output[0].name, "C");
+    }
 }
@@ -5,7 +5,7 @@
 use std::time::Duration;
 
 #[cfg(feature = "audio")]
-use rodio::{OutputStream, OutputStreamHandle, Sink};
+use rodio::{OutputStream, OutputStreamHandle, Sink, Source};
 
 /// Global result type.
 pub type Res<T> = anyhow::Result<T>;
@@ -50,12 +50,291 @@ pub trait Parsable {
         Self: Sized;
 }
 
+/// An enum representing the waveform used when playing a [`Playable`].
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum Waveform {
+    /// A sine wave (the purest tone).
+    #[default]
+    Sine,
+    /// A square wave.
+    Square,
+    /// A triangle wave.
+    Triangle,
+    /// A sawtooth wave.
+    Sawtooth,
+}
+
+/// An enum representing the direction in which a chord is strummed when played.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum StrumDirection {
+    /// An up-strum (from the lowest chord tone to the highest).
+    #[default]
+    Up,
+    /// A down-strum (from the highest chord tone to the lowest).
+    Down,
+}
+
+impl Waveform {
+    /// Returns the gain applied to this waveform during playback.
+    ///
+    /// Square and sawtooth waves carry much more harmonic energy than a sine wave, so they are
+    /// attenuated to keep the perceived loudness roughly consistent across waveforms.
+    pub fn gain(&self) -> f32 {
+        match self {
+            Waveform::Sine => 0.20,
+            Waveform::Triangle => 0.20,
+            Waveform::Square => 0.08,
+            Waveform::Sawtooth => 0.10,
+        }
+    }
+
+    /// Returns the value of the waveform at the given phase (in the range `[0, 1)`).
+    pub fn sample(&self, phase: f32) -> f32 {
+        match self {
+            Waveform::Sine => (2.0 * std::f32::consts::PI * phase).sin(),
+            Waveform::Square => {
+                if phase < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Waveform::Triangle => 1.0 - 4.0 * (phase - 0.5).abs(),
+            Waveform::Sawtooth => 2.0 * phase - 1.0,
+        }
+    }
+}
+
+/// An ADSR (attack, decay, sustain, and release) envelope applied to each tone when playing a [`Playable`].
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Envelope {
+    /// The time it takes to ramp up from silence to the full level.
+    pub attack: Duration,
+    /// The time it takes to fall from the full level to the sustain level.
+    pub decay: Duration,
+    /// The level (from `0` to `1`) held until the tone is released.
+    pub sustain: f32,
+    /// The time it takes to fall from the level at release to silence (after the tone's length).
+    pub release: Duration,
+}
+
+impl Envelope {
+    /// Creates a new [`Envelope`].
+    pub fn new(attack: Duration, decay: Duration, sustain: f32, release: Duration) -> Self {
+        Self {
+            attack,
+            decay,
+            sustain: sustain.clamp(0.0, 1.0),
+            release,
+        }
+    }
+
+    /// Returns the level (from `0` to `1`) of the envelope at the given elapsed time for a tone released after `hold`.
+    pub fn level(&self, elapsed: Duration, hold: Duration) -> f32 {
+        if elapsed < hold {
+            return self.held_level(elapsed);
+        }
+
+        let released = elapsed - hold;
+
+        if released >= self.release {
+            return 0.0;
+        }
+
+        self.held_level(hold) * (1.0 - released.as_secs_f32() / self.release.as_secs_f32())
+    }
+
+    /// Returns the level of the envelope before the tone is released.
+    fn held_level(&self, elapsed: Duration) -> f32 {
+        if elapsed < self.attack {
+            elapsed.as_secs_f32() / self.attack.as_secs_f32()
+        } else if elapsed < self.attack + self.decay {
+            1.0 - (1.0 - self.sustain) * (elapsed - self.attack).as_secs_f32() / self.decay.as_secs_f32()
+        } else {
+            self.sustain
+        }
+    }
+}
+
+impl Default for Envelope {
+    /// Returns an envelope that sounds the tone at its full level for its entire length (with no release tail).
+    fn default() -> Self {
+        Self::new(Duration::ZERO, Duration::ZERO, 1.0, Duration::ZERO)
+    }
+}
+
+/// An infinite [`Source`] that produces a [`Waveform`] at a given frequency.
+///
+/// Like [`rodio::source::SineWave`], this has a rate of 48kHz (unless created with [`WaveformSource::with_sample_rate`])
+/// and one channel.
+#[cfg(feature = "audio")]
+#[derive(Clone, Debug)]
+pub struct WaveformSource {
+    waveform: Waveform,
+    frequency: f32,
+    sample_rate: u32,
+    num_sample: usize,
+}
+
+#[cfg(feature = "audio")]
+impl WaveformSource {
+    /// Creates a new [`WaveformSource`].
+    pub fn new(waveform: Waveform, frequency: f32) -> Self {
+        Self::with_sample_rate(waveform, frequency, 48000)
+    }
+
+    /// Creates a new [`WaveformSource`] with the given sample rate (e.g., for rendering to a file).
+    pub fn with_sample_rate(waveform: Waveform, frequency: f32, sample_rate: u32) -> Self {
+        Self {
+            waveform,
+            frequency,
+            sample_rate,
+            num_sample: 0,
+        }
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Iterator for WaveformSource {
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<f32> {
+        self.num_sample = self.num_sample.wrapping_add(1);
+
+        let phase = (self.frequency * self.num_sample as f32 / self.sample_rate as f32).fract();
+
+        Some(self.waveform.sample(phase))
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Source for WaveformSource {
+    #[inline]
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// A [`Source`] adapter that applies an [`Envelope`] to an inner source.
+///
+/// The tone is released after `hold`, and the source ends once the release has finished.
+#[cfg(feature = "audio")]
+#[derive(Clone, Debug)]
+pub struct EnvelopeSource<S> {
+    inner: S,
+    envelope: Envelope,
+    hold: Duration,
+    num_sample: usize,
+}
+
+#[cfg(feature = "audio")]
+impl<S> EnvelopeSource<S>
+where
+    S: Source<Item = f32>,
+{
+    /// Creates a new [`EnvelopeSource`].
+    pub fn new(inner: S, envelope: Envelope, hold: Duration) -> Self {
+        Self { inner, envelope, hold, num_sample: 0 }
+    }
+
+    /// Returns the elapsed time at the current sample.
+    fn elapsed(&self) -> Duration {
+        let samples_per_second = self.inner.sample_rate() as f64 * self.inner.channels() as f64;
+
+        Duration::from_secs_f64(self.num_sample as f64 / samples_per_second)
+    }
+}
+
+#[cfg(feature = "audio")]
+impl<S> Iterator for EnvelopeSource<S>
+where
+    S: Source<Item = f32>,
+{
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<f32> {
+        let elapsed = self.elapsed();
+
+        if elapsed >= self.hold + self.envelope.release {
+            return None;
+        }
+
+        self.num_sample += 1;
+
+        self.inner.next().map(|v| v * self.envelope.level(elapsed, self.hold))
+    }
+}
+
+#[cfg(feature = "audio")]
+impl<S> Source for EnvelopeSource<S>
+where
+    S: Source<Item = f32>,
+{
+    #[inline]
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    #[inline]
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        Some(self.hold + self.envelope.release)
+    }
+}
+
+/// Builds the (delayed, enveloped, and amplified) [`Source`] for a single tone of a [`Playable`].
+///
+/// The tone is released at `length`, so the source lasts for `length` plus the envelope's release.
+#[cfg(feature = "audio")]
+pub(crate) fn enveloped_tone_source(waveform: Waveform, frequency: f32, delay: Duration, length: Duration, envelope: Envelope) -> impl Source<Item = f32> + Send {
+    EnvelopeSource::new(WaveformSource::new(waveform, frequency), envelope, length - delay)
+        .delay(delay)
+        .amplify(waveform.gain())
+}
+
+/// Builds the (delayed, faded, and amplified) [`Source`] for a single tone of a [`Playable`].
+#[cfg(feature = "audio")]
+pub(crate) fn tone_source(waveform: Waveform, frequency: f32, delay: Duration, length: Duration, fade_in: Duration) -> impl Source<Item = f32> + Send {
+    WaveformSource::new(waveform, frequency)
+        .take_duration(length - delay)
+        .buffered()
+        .delay(delay)
+        .fade_in(fade_in)
+        .amplify(waveform.gain())
+}
+
 /// A struct for holding the types for a [`Playable`].
 #[cfg(feature = "audio")]
 pub struct PlaybackHandle {
-    _stream: OutputStream,
-    _stream_handle: OutputStreamHandle,
-    _sinks: Vec<Sink>,
+    _stream: Option<OutputStream>,
+    _stream_handle: Option<OutputStreamHandle>,
+    sinks: Vec<Sink>,
 }
 
 #[cfg(feature = "audio")]
@@ -63,11 +342,31 @@ impl PlaybackHandle {
     /// Creates a new [`PlayableResult`].
     pub fn new(stream: OutputStream, stream_handle: OutputStreamHandle, sinks: Vec<Sink>) -> Self {
         Self {
-            _stream: stream,
-            _stream_handle: stream_handle,
-            _sinks: sinks,
+            _stream: Some(stream),
+            _stream_handle: Some(stream_handle),
+            sinks,
         }
     }
+
+    /// Stops the playback (it cannot be resumed).
+    pub fn stop(&self) {
+        self.sinks.iter().for_each(Sink::stop);
+    }
+
+    /// Pauses the playback (it can be resumed with [`PlaybackHandle::play`]).
+    pub fn pause(&self) {
+        self.sinks.iter().for_each(Sink::pause);
+    }
+
+    /// Resumes the playback after a [`PlaybackHandle::pause`].
+    pub fn play(&self) {
+        self.sinks.iter().for_each(Sink::play);
+    }
+
+    /// Sets the volume of the playback (where `1.0` is the original volume).
+    pub fn set_volume(&self, volume: f32) {
+        self.sinks.iter().for_each(|s| s.set_volume(volume));
+    }
 }
 
 /// A trait for types that can be "played" via the system's audio output.
@@ -86,7 +385,83 @@ impl PlaybackHandle {
 /// ```
 #[cfg(feature = "audio")]
 pub trait Playable {
-    /// Plays the [`Playable`].
+    /// Plays the [`Playable`] as a sine wave.
+    #[must_use = "Dropping the PlayableResult will stop the playback."]
+    fn play(&self, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle> {
+        self.play_with(Waveform::Sine, delay, length, fade_in)
+    }
+
+    /// Plays the [`Playable`] with the given [`Waveform`].
     #[must_use = "Dropping the PlayableResult will stop the playback."]
-    fn play(&self, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle>;
+    fn play_with(&self, waveform: Waveform, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle>;
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_envelope() {
+        let envelope = Envelope::new(Duration::from_millis(100), Duration::from_millis(100), 0.5, Duration::from_millis(200));
+        let hold = Duration::from_secs(1);
+
+        assert_eq!(envelope.level(Duration::ZERO, hold), 0.0);
+        assert!((envelope.level(Duration::from_millis(50), hold) - 0.5).abs() < 1e-6);
+        assert!((envelope.level(Duration::from_millis(100), hold) - 1.0).abs() < 1e-6);
+        assert!((envelope.level(Duration::from_millis(150), hold) - 0.75).abs() < 1e-6);
+        assert_eq!(envelope.level(Duration::from_millis(500), hold), 0.5);
+        assert!((envelope.level(Duration::from_millis(1100), hold) - 0.25).abs() < 1e-6);
+        assert_eq!(envelope.level(Duration::from_millis(1200), hold), 0.0);
+
+        assert_eq!(Envelope::default().level(Duration::ZERO, hold), 1.0);
+        assert_eq!(Envelope::default().level(hold, hold), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "audio")]
+    fn test_enveloped_tone_source() {
+        let envelope = Envelope::new(Duration::from_millis(10), Duration::from_millis(10), 0.5, Duration::from_millis(250));
+        let delay = Duration::from_millis(100);
+        let length = Duration::from_secs(1);
+
+        let source = enveloped_tone_source(Waveform::Sine, 440.0, delay, length, envelope);
+        assert_eq!(source.total_duration(), Some(length + envelope.release));
+
+        // The source includes the release tail after the length (at 48kHz).
+        let samples = source.collect::<Vec<_>>();
+        assert_eq!(samples.len(), 48_000 + 12_000);
+
+        // The tail fades out to silence.
+        assert!(samples[samples.len() - 10..].iter().all(|s| s.abs() < 0.001));
+        assert!(samples.iter().all(|s| s.abs() <= Waveform::Sine.gain() + f32::EPSILON));
+    }
+
+    #[test]
+    #[cfg(feature = "audio")]
+    fn test_playback_handle() {
+        // Use idle sinks (with no output stream), so that this can run headless.
+        let (sink, _output) = Sink::new_idle();
+        sink.append(WaveformSource::new(Waveform::Sine, 440.0).take_duration(Duration::from_secs(1)));
+
+        let handle = PlaybackHandle {
+            _stream: None,
+            _stream_handle: None,
+            sinks: vec![sink],
+        };
+
+        handle.pause();
+        assert!(handle.sinks[0].is_paused());
+
+        handle.play();
+        assert!(!handle.sinks[0].is_paused());
+
+        handle.set_volume(0.5);
+        assert_eq!(handle.sinks[0].volume(), 0.5);
+
+        // Stopping is only observable on the output side (which does not exist here), so it just must not panic.
+        handle.stop();
+    }
 }
@@ -1,22 +1,33 @@
 //! A module that contains the [`Chord`] struct and related traits.
 
-use std::{cmp::Ordering, collections::HashSet, fmt::Display, time::Duration};
+use std::{
+    cmp::Ordering,
+    collections::HashSet,
+    fmt::{Debug, Display},
+    time::Duration,
+};
+
+#[cfg(feature = "audio")]
+use std::path::Path;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use once_cell::sync::OnceCell;
 use pest::Parser;
 
 use crate::core::{
     base::{HasDescription, HasName, HasPreciseName, HasStaticName, Parsable, Res},
+    helpers::write_smf,
     interval::Interval,
-    known_chord::{HasRelativeChord, HasRelativeScale, KnownChord},
-    modifier::{known_modifier_sets, likely_extension_sets, one_off_modifier_sets, Degree, Extension, HasIsDominant, Modifier},
-    named_pitch::HasNamedPitch,
-    note::{CZero, Note, NoteRecreator},
-    octave::{HasOctave, Octave},
-    parser::{note_str_to_note, octave_str_to_octave, ChordParser, Rule},
-    pitch::HasFrequency,
+    known_chord::{HasRelativeChord, HasRelativeScale, HasScaleNames, KnownChord},
+    modifier::{known_modifier_sets, likely_extension_sets, one_off_modifier_sets, Degree, Extension, HasIsDominant, Modifier, Omission},
+    named_pitch::{Accidental, HasLetter, HasNamedPitch, NamedPitch},
+    note::{CZero, Note, NoteRecreator, C},
+    octave::{HasOctave, Octave, ALL_OCTAVES},
+    parser::{note_str_to_note, octave_str_to_octave, rule_hint, ChordParser, ParseError, Rule},
+    pitch::{HasFrequency, HasPitch, Pitch},
+    tuning::Tuning,
 };
 
 // Traits.
@@ -45,6 +56,12 @@ pub trait HasExtensions {
     fn extensions(&self) -> &HashSet<Extension>;
 }
 
+/// A trait that represents a type that has omissions.
+pub trait HasOmissions {
+    /// Returns the omissions of the implementor (most likely a [`Chord`]).
+    fn omissions(&self) -> &HashSet<Omission>;
+}
+
 /// A trait that represents a type that has an inversion.
 pub trait HasInversion {
     /// Returns the inversion of the implementor (most likely a [`Chord`]).
@@ -89,10 +106,28 @@ pub trait Chordable {
     fn with_extension(self, extension: Extension) -> Chord;
     /// Adds extensions to the implementor (most likely a [`Chord`]), and returns a new chord.
     fn with_extensions(self, extensions: &[Extension]) -> Chord;
+    /// Adds an omission to the implementor (most likely a [`Chord`]), and returns a new chord.
+    fn with_omission(self, omission: Omission) -> Chord;
+    /// Adds omissions to the implementor (most likely a [`Chord`]), and returns a new chord.
+    fn with_omissions(self, omissions: &[Omission]) -> Chord;
+    /// Removes a modifier from the implementor (most likely a [`Chord`]), if present, and returns a new chord.
+    fn without_modifier(self, modifier: Modifier) -> Chord;
+    /// Removes an extension from the implementor (most likely a [`Chord`]), if present, and returns a new chord.
+    fn without_extension(self, extension: Extension) -> Chord;
+    /// Removes all of the extensions from the implementor (most likely a [`Chord`]), and returns a new chord.
+    fn clear_extensions(self) -> Chord;
     /// Sets the inversion number of the implementor (most likely a [`Chord`]), and returns a new chord.
     fn with_inversion(self, inversion: u8) -> Chord;
     /// Sets the slash note of the implementor (most likely a [`Chord`]), and returns a new chord.
     fn with_slash(self, slash: Note) -> Chord;
+    /// Sets the slash note of the implementor (most likely a [`Chord`]), keeping its octave (rather than placing it just
+    /// below the chord), and returns a new chord.
+    fn with_pinned_slash(self, slash: Note) -> Chord;
+    /// Places the given note in the bass of the implementor (most likely a [`Chord`]), and returns a new chord.
+    ///
+    /// If the note is a chord tone, then this is the inversion that puts it in the bass, with a [name](HasName::name) that uses
+    /// the bass note (e.g., `C/G` for `C^2`); otherwise, this is a (true) slash chord (e.g., `C/F`), as with [`Chordable::with_slash`].
+    fn with_bass(self, bass: Note) -> Chord;
     /// Sets the octave of the implementor (most likely the root note of a chord), and returns a new chord.
     fn with_octave(self, octave: Octave) -> Chord;
     /// Sets whether or not the implementor (most likely a [`Chord`]) is crunchy.
@@ -226,6 +261,19 @@ pub trait Chordable {
     fn add13(self) -> Chord;
     /// Returns a new chord with an add13 extension on the implementor (most likely a [`Chord`]).
     fn add_thirteen(self) -> Chord;
+
+    // Omissions.
+
+    /// Returns a new chord with no third on the implementor (most likely a [`Chord`]).
+    fn no3(self) -> Chord;
+    /// Returns a new chord with no third on the implementor (most likely a [`Chord`]).
+    fn no_three(self) -> Chord;
+    /// Returns a new chord with no fifth on the implementor (most likely a [`Chord`]).
+    fn no5(self) -> Chord;
+    /// Returns a new chord with no fifth on the implementor (most likely a [`Chord`]).
+    fn no_five(self) -> Chord;
+    /// Returns a new power chord (i.e., the root and fifth, with no third) on the implementor (most likely a [`Chord`]).
+    fn power(self) -> Chord;
 }
 
 /// A trait for types that have a dominant degree; i.e., 7, 9, 11, 13.
@@ -238,7 +286,7 @@ pub trait HasDomninantDegree {
 
 /// The primary chord struct.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct Chord {
     /// The root note of the chord.
     root: Note,
@@ -248,14 +296,37 @@ pub struct Chord {
     modifiers: HashSet<Modifier>,
     /// The extensions of the chord.
     extensions: HashSet<Extension>,
+    /// The omissions of the chord.
+    #[cfg_attr(feature = "serde", serde(default))]
+    omissions: HashSet<Omission>,
     /// The inversion of the chord.
     inversion: u8,
     /// Whether or not this chord is "crunchy".
     ///
     /// Crunchy chords take extensions down an octave, which gives the chord some "crunch".
     is_crunchy: bool,
+    /// Whether or not the slash note keeps its octave (e.g., `C/E2`), rather than being placed just below the chord.
+    #[cfg_attr(feature = "serde", serde(default))]
+    is_slash_pinned: bool,
+    /// Whether or not the inversion is named by its bass note (e.g., `C/G`, rather than `C^2`) in the [name](HasName::name).
+    ///
+    /// This only changes the name, so it is ignored for equality (and the [precise name](HasPreciseName::precise_name) always
+    /// uses the inversion number, so that it parses back to the same chord).
+    #[cfg_attr(feature = "serde", serde(default))]
+    is_inversion_by_bass: bool,
+    /// The [`KnownChord`] classification of the modifiers, computed on first use.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    known_chord: KnownChordCache,
 }
 
+/// A lazily computed [`KnownChord`], so that repeated [`HasKnownChord::known_chord`] calls (e.g., from
+/// [`HasScale::scale`], [`HasChord::chord`], and [`HasDescription::description`]) do not redo the classification.
+///
+/// The cache is derived from the modifiers (it is reset by [`Chord::modifiers_mut`] whenever they change), so it is ignored for
+/// equality.
+#[derive(Clone, Default)]
+struct KnownChordCache(OnceCell<KnownChord>);
+
 // Impls.
 
 impl Ord for Chord {
@@ -302,14 +373,7 @@ impl Ord for Chord {
             }
         };
 
-        // Give a slight preference to chords without slashes and inversions.
-        let a_inversion_exists = u8::from(a_inversion != 0);
-        let b_inversion_exists = u8::from(b_inversion != 0);
-
-        let a_all_changes_len = a_extensions_len + a_modifiers_len + 2 * a_slashes + 2 * a_inversion_exists;
-        let b_all_changes_len = b_extensions_len + b_modifiers_len + 2 * b_slashes + 2 * b_inversion_exists;
-
-        let cmp_all_changes = a_all_changes_len.cmp(&b_all_changes_len);
+        let cmp_all_changes = self.complexity_score().cmp(&other.complexity_score());
 
         let a_root = self.root;
         let b_root = other.root;
@@ -331,6 +395,35 @@ impl PartialOrd for Chord {
     }
 }
 
+impl PartialEq for Chord {
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root
+            && self.slash == other.slash
+            && self.modifiers == other.modifiers
+            && self.extensions == other.extensions
+            && self.omissions == other.omissions
+            && self.inversion == other.inversion
+            && self.is_crunchy == other.is_crunchy
+            && self.is_slash_pinned == other.is_slash_pinned
+    }
+}
+
+impl Eq for Chord {}
+
+impl PartialEq for KnownChordCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for KnownChordCache {}
+
+impl Debug for KnownChordCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.get().fmt(f)
+    }
+}
+
 impl Chord {
     /// Returns a new chord with the given root.
     pub fn new(root: Note) -> Self {
@@ -339,9 +432,130 @@ impl Chord {
             slash: None,
             modifiers: HashSet::new(),
             extensions: HashSet::new(),
+            omissions: HashSet::new(),
             inversion: 0,
             is_crunchy: false,
+            is_slash_pinned: false,
+            is_inversion_by_bass: false,
+            known_chord: KnownChordCache::default(),
+        }
+    }
+
+    /// Returns one representative chord for each [`KnownChord`] (excluding [`KnownChord::Unknown`]) rooted at the given note.
+    ///
+    /// The variants that carry a [`Degree`] are represented by their seventh chord (e.g., `C7`, `Cm7`, `Cm7♭5`).
+    pub fn all_known_qualities(root: Note) -> Vec<Chord> {
+        let chord = Chord::new(root);
+
+        vec![
+            chord.clone(),
+            chord.clone().minor(),
+            chord.clone().maj7(),
+            chord.clone().seven(),
+            chord.clone().minor().maj7(),
+            chord.clone().minor().seven(),
+            chord.clone().seven().sharp11(),
+            chord.clone().augmented(),
+            chord.clone().augmented().maj7(),
+            chord.clone().augmented().seven(),
+            chord.clone().half_dim(),
+            chord.clone().dim(),
+            chord.clone().seven().flat9(),
+            chord.seven().sharp9(),
+        ]
+    }
+
+    /// Returns the complexity score of the chord (lower is simpler).
+    ///
+    /// This is the weighted sum of the extensions, modifiers, slashes (×2), and inversions (×2) that
+    /// primarily determines the [`Ord`] implementation (and, therefore, the ranking of guessed chords).
+    pub fn complexity_score(&self) -> u32 {
+        // Give a slight preference to chords without slashes and inversions.
+        let slashes = u32::from(self.slash.is_some());
+        let inversions = u32::from(self.inversion != 0);
+
+        self.extensions.len() as u32 + self.modifiers.len() as u32 + 2 * slashes + 2 * inversions
+    }
+
+    /// Returns the simplest chord that produces the same [tones](HasChord::chord) as this chord (e.g., `C9add9` simplifies to `C9`).
+    ///
+    /// This removes the modifiers and extensions that are expressed elsewhere in the chord (as [`Chord::try_from_notes`] does
+    /// for its candidates), and then any remaining ones that do not change the tones.  The root, slash, inversion, and
+    /// crunchiness are kept.
+    pub fn simplify(&self) -> Chord {
+        let tones = self.chord();
+
+        let mut result = self.clone();
+        remove_redundant_modifiers(&mut result);
+
+        if result.chord() != tones {
+            result = self.clone();
+        }
+
+        let mut modifiers = result.modifiers.iter().copied().collect::<Vec<_>>();
+        modifiers.sort();
+
+        for modifier in modifiers {
+            let candidate = result.clone().without_modifier(modifier);
+
+            if candidate.chord() == tones {
+                result = candidate;
+            }
+        }
+
+        let mut extensions = result.extensions.iter().copied().collect::<Vec<_>>();
+        extensions.sort();
+
+        for extension in extensions {
+            let candidate = result.clone().without_extension(extension);
+
+            if candidate.chord() == tones {
+                result = candidate;
+            }
         }
+
+        result
+    }
+
+    /// Returns the modifiers for mutation, resetting the [`KnownChord`] cache (which is derived from them).
+    ///
+    /// All changes to the modifiers must go through this, so that the cache cannot go stale.
+    fn modifiers_mut(&mut self) -> &mut HashSet<Modifier> {
+        self.known_chord = KnownChordCache::default();
+
+        &mut self.modifiers
+    }
+}
+
+impl Chord {
+    /// Parses a whitespace and/or comma separated list of chord symbols (e.g., `"Cm7, F7 Bbmaj7"`), preserving order.
+    ///
+    /// Any `|length` suffix on a token (e.g., `Cm7|32`) is ignored; use [`Chord::parse_many_timed`] to retain it.
+    pub fn parse_many(input: &str) -> Res<Vec<Self>> {
+        Ok(Self::parse_many_timed(input)?.into_iter().map(|(chord, _)| chord).collect())
+    }
+
+    /// Parses a whitespace and/or comma separated list of chord symbols, each with an optional `|length`
+    /// suffix (e.g., `"Cm7|32 F7|16, Bbmaj7"`), preserving order.
+    pub fn parse_many_timed(input: &str) -> Res<Vec<(Self, Option<u16>)>> {
+        input
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .map(|token| {
+                let (symbol, length) = match token.split_once('|') {
+                    Some((symbol, length)) => {
+                        let length = length.parse::<u16>().map_err(|_| anyhow::Error::msg(format!("Invalid chord length in `{}`.", token)))?;
+
+                        (symbol, Some(length))
+                    }
+                    None => (token, None),
+                };
+
+                let chord = Chord::parse(symbol).map_err(|e| anyhow::Error::msg(format!("Invalid chord `{}`: {}", token, e)))?;
+
+                Ok((chord, length))
+            })
+            .collect()
     }
 }
 
@@ -357,89 +571,89 @@ impl Chord {
 
         let mut result = Vec::new();
 
+        // A candidate can only match if its pitch classes are exactly those of the notes (inversions, crunchiness, and octaves
+        // do not change the pitch classes), so compute the pitch classes of every candidate family once, relative to the root,
+        // and skip the families that cannot match before building (and voicing) any candidates.
+        let input_mask = pitch_class_mask(notes.iter().map(|n| n.pitch()));
+        let families = known_modifier_sets()
+            .iter()
+            .flat_map(|mod_set| {
+                one_off_modifier_sets()
+                    .iter()
+                    .flat_map(move |mod_set2| likely_extension_sets().iter().map(move |ext_set| (mod_set, mod_set2, ext_set)))
+            })
+            .map(|(mod_set, mod_set2, ext_set)| {
+                let intervals = Chord::new(C).with_modifiers(mod_set).with_modifiers(mod_set2).with_extensions(ext_set).relative_chord();
+
+                (mod_set, mod_set2, ext_set, pitch_class_mask(intervals.iter().map(|i| Pitch::try_from(i.semitones() % 12).unwrap())))
+            })
+            .collect::<Vec<_>>();
+
         // Iterate through all known chords (and some likely extensions) and find the longest match.
         for inversion in 0..3 {
-            let proper_root = if inversion == 0 {
-                notes[0]
+            // An inverted root is an octave below its note, which is impossible for a note in the lowest octave.
+            let inverted_root = if inversion == 0 {
+                None
             } else {
                 let note = notes[notes.len() - inversion];
 
-                note.with_octave(note.octave() - 1)
+                match note.octave().checked_sub(1) {
+                    Some(octave) => Some(note.with_octave(octave)),
+                    None => continue,
+                }
             };
 
-            let proper_root_slash = if inversion == 0 {
-                notes[1]
-            } else {
-                let note = notes[notes.len() - inversion];
+            let proper_root = inverted_root.unwrap_or(notes[0]);
+            let proper_root_slash = inverted_root.unwrap_or(notes[1]);
 
-                note.with_octave(note.octave() - 1)
-            };
+            for (mod_set, mod_set2, ext_set, family_mask) in &families {
+                let root_may_match = rotate_pitch_class_mask(*family_mask, proper_root.pitch()) == input_mask;
+                let slash_may_match = rotate_pitch_class_mask(*family_mask, proper_root_slash.pitch()) | pitch_class_mask([notes[0].pitch()]) == input_mask;
 
-            for mod_set in known_modifier_sets() {
-                for mod_set2 in one_off_modifier_sets() {
-                    for ext_set in likely_extension_sets() {
-                        for is_crunchy in [false, true] {
-                            // Check using the first note as the root.
-                            let candidate_chord_root = Chord::new(proper_root)
-                                .with_modifiers(mod_set)
-                                .with_modifiers(mod_set2)
-                                .with_extensions(ext_set)
-                                .with_inversion(inversion as u8)
-                                .with_crunchy(is_crunchy);
-                            let candidate_chord_root_notes = candidate_chord_root.chord();
-
-                            if notes.len() == candidate_chord_root_notes.len() && notes.iter().zip(&candidate_chord_root.chord()).all(|(a, b)| a.frequency() == b.frequency()) {
-                                result.push(candidate_chord_root);
-                            }
+                if !root_may_match && !slash_may_match {
+                    continue;
+                }
 
-                            // Check using the first note as a slash.
-                            let candidate_chord_slash = Chord::new(proper_root_slash)
-                                .with_slash(notes[0])
-                                .with_modifiers(mod_set)
-                                .with_modifiers(mod_set2)
-                                .with_extensions(ext_set)
-                                .with_inversion(inversion as u8)
-                                .with_crunchy(is_crunchy);
-                            let candidate_chord_slash_notes = candidate_chord_slash.chord();
-
-                            if notes.len() == candidate_chord_slash_notes.len() && notes.iter().zip(&candidate_chord_slash.chord()).all(|(a, b)| a.frequency() == b.frequency()) {
-                                result.push(candidate_chord_slash);
-                            }
+                for is_crunchy in [false, true] {
+                    // Check using the first note as the root.
+                    if root_may_match {
+                        let candidate_chord_root = Chord::new(proper_root)
+                            .with_modifiers(mod_set)
+                            .with_modifiers(mod_set2)
+                            .with_extensions(ext_set)
+                            .with_inversion(inversion as u8)
+                            .with_crunchy(is_crunchy);
+                        let candidate_chord_root_notes = candidate_chord_root.chord();
+                        count_candidate_evaluation();
+
+                        if notes.len() == candidate_chord_root_notes.len() && notes.iter().zip(&candidate_chord_root_notes).all(|(a, b)| a.is_enharmonic_with(b)) {
+                            result.push(candidate_chord_root);
                         }
                     }
-                }
-            }
-        }
-
-        // Remove extensions and modifiers that are expressed elsewhere in the chord.
-        for c in &mut result {
-            let dominant_degree = c.dominant_degree();
 
-            if let Some(degree) = dominant_degree {
-                match degree {
-                    Degree::Nine => {
-                        c.extensions.remove(&Extension::Add9);
-                    }
-                    Degree::Eleven => {
-                        c.extensions.remove(&Extension::Add9);
-                        c.extensions.remove(&Extension::Add11);
-                    }
-                    Degree::Thirteen => {
-                        c.extensions.remove(&Extension::Add9);
-                        c.extensions.remove(&Extension::Add11);
-                        c.extensions.remove(&Extension::Add13);
+                    // Check using the first note as a slash.
+                    if slash_may_match {
+                        let candidate_chord_slash = Chord::new(proper_root_slash)
+                            .with_slash(notes[0])
+                            .with_modifiers(mod_set)
+                            .with_modifiers(mod_set2)
+                            .with_extensions(ext_set)
+                            .with_inversion(inversion as u8)
+                            .with_crunchy(is_crunchy);
+                        let candidate_chord_slash_notes = candidate_chord_slash.chord();
+                        count_candidate_evaluation();
+
+                        if notes.len() == candidate_chord_slash_notes.len() && notes.iter().zip(&candidate_chord_slash_notes).all(|(a, b)| a.is_enharmonic_with(b)) {
+                            result.push(candidate_chord_slash);
+                        }
                     }
-                    Degree::Seven => {}
                 }
             }
-
-            if c.modifiers.contains(&Modifier::Diminished) {
-                c.modifiers.remove(&Modifier::Minor);
-                c.modifiers.remove(&Modifier::Flat5);
-                c.modifiers.remove(&Modifier::Augmented5);
-            }
         }
 
+        // Remove extensions and modifiers that are expressed elsewhere in the chord.
+        result.iter_mut().for_each(remove_redundant_modifiers);
+
         // Order the candidates by "simplicity" (i.e., least slashes, least extensions, least modifiers, and least inversion).
         result.sort();
 
@@ -448,1038 +662,3158 @@ impl Chord {
 
         Ok(result)
     }
-}
 
-impl HasName for Chord {
-    fn name(&self) -> String {
-        let known_name = self.known_chord().name();
-        let known_name = known_name.as_str();
-        let mut name = String::new();
+    /// Attempts to guess the chord from the notes, attaching a confidence (from `0` to `1`) to each candidate.
+    ///
+    /// The confidence is the overlap between the pitch classes of the candidate's tones and the notes (penalizing extra
+    /// guessed tones and missing notes), scaled down by the candidate's [complexity](Chord::complexity_score) (penalizing
+    /// slashes, inversions, and extra modifiers).  The candidates are ordered by descending confidence.
+    pub fn try_from_notes_ranked(notes: &[Note]) -> Res<Vec<(Self, f32)>> {
+        let mut input_pitches = notes.iter().map(|n| n.pitch()).collect::<Vec<_>>();
+        input_pitches.sort();
+        input_pitches.dedup();
+
+        let mut result = Self::try_from_notes(notes)?
+            .into_iter()
+            .map(|chord| {
+                let mut chord_pitches = chord.chord().iter().map(|n| n.pitch()).collect::<Vec<_>>();
+                chord_pitches.sort();
+                chord_pitches.dedup();
+
+                let shared = chord_pitches.iter().filter(|p| input_pitches.contains(p)).count() as f32;
+                let total = (chord_pitches.len() + input_pitches.len()) as f32 - shared;
+
+                let tone_match = if total == 0.0 { 0.0 } else { shared / total };
+                let confidence = (tone_match * simplicity(&chord)).clamp(0.0, 1.0);
+
+                (chord, confidence)
+            })
+            .collect::<Vec<_>>();
+
+        // The sort is stable, so ties retain the "simplicity" ordering.
+        result.sort_by(|a, b| b.1.total_cmp(&a.1));
 
-        name.push_str(self.root.static_name());
+        Ok(result)
+    }
 
-        name.push_str(known_name);
+    /// Attempts to guess the chord from a partial (or incomplete) set of notes (e.g., a dyad, a shell voicing, or a chord missing its fifth).
+    ///
+    /// Unlike [`Chord::try_from_notes`], a candidate only needs to contain the pitch classes of the notes (rather than match them
+    /// exactly), and every note is tried as the root (with the lowest note as a slash, if it is not the root).  The candidates are
+    /// ordered by the fraction of their tones covered by the notes, scaled down by their [complexity](Chord::complexity_score)
+    /// (as in [`Chord::try_from_notes_ranked`]).  Fewer notes are more ambiguous, so they yield more candidates.
+    pub fn try_from_notes_partial(notes: &[Note]) -> Res<Vec<Self>> {
+        if notes.is_empty() {
+            return Err(anyhow::Error::msg("Must have at least one note to guess a chord."));
+        }
 
-        // Add special modifiers that are true modifiers when not part of their "special case".
+        let mut notes = notes.to_vec();
+        notes.sort();
 
-        if self.modifiers.contains(&Modifier::Flat5) && !known_name.contains("(♭5)") {
-            name.push_str("(♭5)");
-        }
+        let bass = notes[0];
 
-        if self.modifiers.contains(&Modifier::Augmented5) && !known_name.contains('+') {
-            name.push_str("(♯5)");
-        }
+        let mut input_pitches = notes.iter().map(|n| n.pitch()).collect::<Vec<_>>();
+        input_pitches.sort();
+        input_pitches.dedup();
 
-        if self.modifiers.contains(&Modifier::Flat9) && !known_name.contains("(♭9)") {
-            name.push_str("(♭9)");
-        }
+        let mut result: Vec<(Self, f32)> = Vec::new();
 
-        if self.modifiers.contains(&Modifier::Sharp9) && !known_name.contains("(♯9)") {
-            name.push_str("(♯9)");
-        }
+        for root in &notes {
+            for mod_set in known_modifier_sets() {
+                for mod_set2 in one_off_modifier_sets() {
+                    for ext_set in likely_extension_sets() {
+                        let mut candidate = Chord::new(*root).with_modifiers(mod_set).with_modifiers(mod_set2).with_extensions(ext_set);
 
-        if self.modifiers.contains(&Modifier::Sharp11) && !known_name.contains("(♯11)") {
-            name.push_str("(♯11)");
-        }
+                        if bass.pitch() != root.pitch() {
+                            candidate = candidate.with_slash(bass);
+                        }
+
+                        let mut candidate_pitches = candidate.chord().iter().map(|n| n.pitch()).collect::<Vec<_>>();
+                        candidate_pitches.sort();
+                        candidate_pitches.dedup();
 
-        // Add extensions.
-        if !self.extensions.is_empty() {
-            for e in &self.extensions {
-                name.push_str(&format!("({})", e.static_name()));
+                        if !input_pitches.iter().all(|p| candidate_pitches.contains(p)) {
+                            continue;
+                        }
+
+                        remove_redundant_modifiers(&mut candidate);
+
+                        if result.iter().any(|(c, _)| *c == candidate) {
+                            continue;
+                        }
+
+                        let coverage = input_pitches.len() as f32 / candidate_pitches.len() as f32;
+                        let score = coverage * simplicity(&candidate);
+
+                        result.push((candidate, score));
+                    }
+                }
             }
         }
 
-        // Add slash note.
-        if let Some(slash) = self.slash {
-            name.push_str(&format!("/{}", slash.static_name()));
-        }
+        // Order the candidates by "simplicity", and then (stably) by score.
+        result.sort_by(|a, b| a.0.cmp(&b.0));
+        result.sort_by(|a, b| b.1.total_cmp(&a.1));
 
-        // Add special information about the chord.
+        Ok(result.into_iter().map(|(c, _)| c).collect())
+    }
 
-        name
+    /// Attempts to guess the chord from the pitch classes (i.e., without octaves), trying each pitch as the lowest tone.
+    ///
+    /// The pitches are stacked in close position (starting in the fourth octave) for each rotation, and the candidates from every
+    /// rotation are ordered by "simplicity".
+    pub fn try_from_pitches(pitches: &[Pitch]) -> Res<Vec<Self>> {
+        Self::guess_from_pitches(pitches, None)
     }
-}
 
-impl HasPreciseName for Chord {
-    fn precise_name(&self) -> String {
-        let mut name = String::new();
+    /// Attempts to guess the chord from the pitch classes, using the given bass note (e.g., the lowest detected note) as a hint.
+    ///
+    /// Without a hint, this is the same as [`Chord::try_from_pitches`].  With a hint, only candidates with the bass note as their
+    /// lowest tone are kept, the search is limited to voicings above the bass, and slash interpretations are preferred (e.g., the
+    /// pitches of `C` with a bass of `E3` are guessed as `C/E`, rather than `C^1`).
+    pub fn guess_from_pitches(pitches: &[Pitch], bass: Option<Note>) -> Res<Vec<Self>> {
+        let mut unique = pitches.to_vec();
+        if let Some(bass) = bass {
+            unique.push(bass.pitch());
+        }
+        unique.sort();
+        unique.dedup();
 
-        name.push_str(&self.name());
+        if unique.len() < 3 {
+            return Err(anyhow::Error::msg("Must have at least three pitches to guess a chord."));
+        }
 
-        // Add octave modifier.
-        if self.root.octave() != Octave::Four {
-            name.push_str(&format!("@{}", self.root.octave().static_name()));
+        let rotations = (0..unique.len()).map(|k| unique[k..].iter().chain(&unique[..k]).copied().collect::<Vec<_>>()).collect::<Vec<_>>();
+
+        let mut result = Vec::new();
+
+        match bass {
+            None => {
+                for rotation in &rotations {
+                    result.extend(Self::try_from_notes(&stack_pitches(rotation, C.to_midi() - 1))?);
+                }
+            }
+            Some(bass) => {
+                let bass_midi = bass.to_midi();
+
+                // The bass as the lowest tone of the chord itself (i.e., root position, or an inversion).
+                let upper = unique.iter().filter(|p| **p != bass.pitch()).copied().collect::<Vec<_>>();
+                let mut notes = vec![bass];
+                notes.extend(stack_pitches(&upper, bass_midi));
+                result.extend(Self::try_from_notes(&notes)?);
+
+                // The bass below a complete chord (i.e., a slash chord).
+                for rotation in &rotations {
+                    let mut notes = vec![bass];
+                    notes.extend(stack_pitches(rotation, bass_midi));
+                    result.extend(Self::try_from_notes(&notes)?);
+                }
+
+                result.retain(|c| c.chord().first().map(|n| n.pitch()) == Some(bass.pitch()) && c.slash.map(|s| s.pitch()) != Some(c.root.pitch()));
+            }
         }
 
-        // Add inversion modifier.
-        if self.inversion != 0 {
-            name.push_str(&format!("^{}", self.inversion));
+        result.sort();
+
+        // Remove duplicates from different rotations: pitches carry no voicing, so octaves, inversions, and crunchy are ignored
+        // (the sort above keeps the simplest of each).
+        let mut unique: Vec<Self> = Vec::with_capacity(result.len());
+        for c in result {
+            if !unique.iter().any(|u| {
+                u.root.named_pitch() == c.root.named_pitch() && u.slash.map(|s| s.named_pitch()) == c.slash.map(|s| s.named_pitch()) && u.modifiers == c.modifiers && u.extensions == c.extensions
+            }) {
+                unique.push(c);
+            }
         }
+        let mut result = unique;
 
-        // Add crunchy modifier.
-        if self.is_crunchy {
-            name.push('!');
+        // When the bass is known, prefer chords that are rooted on it, or that name it as a slash (the sort is stable, so ties retain the "simplicity" ordering).
+        if let Some(bass) = bass {
+            result.sort_by_key(|c| c.slash.is_none() && c.root.pitch() != bass.pitch());
         }
 
-        name
+        Ok(result)
     }
-}
 
-impl HasRoot for Chord {
-    fn root(&self) -> Note {
-        self.root
+    /// Attempts to recognize the chord built from the given interval stack above the root (e.g., `[P1, M3, P5, m7]` above `C` is `C7`).
+    ///
+    /// The intervals are applied to the root, and the best match from [`Chord::try_from_notes`] is returned.
+    pub fn from_intervals(root: Note, intervals: &[Interval]) -> Res<Self> {
+        let notes = intervals.iter().map(|i| root + *i).collect::<Vec<_>>();
+
+        Self::try_from_notes(&notes)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::Error::msg(format!("No known chord matches the intervals {:?} above `{}`.", intervals, root)))
     }
 }
 
-impl HasSlash for Chord {
-    fn slash(&self) -> Note {
-        self.slash.unwrap_or(self.root)
+impl Chord {
+    /// Returns a new chord with the root (and slash, if any) transposed up by the given interval.
+    ///
+    /// Returns an error if either note would be out of the octave range (i.e., above `B15`).
+    pub fn transpose(&self, interval: Interval) -> Res<Chord> {
+        self.transpose_with(|n| n.checked_add(interval)).ok_or_else(|| self.transposition_error())
     }
-}
 
-impl HasModifiers for Chord {
-    fn modifiers(&self) -> &HashSet<Modifier> {
-        &self.modifiers
+    /// Returns a new chord with the root (and slash, if any) transposed down by the given interval.
+    ///
+    /// Returns an error if either note would be out of the octave range (i.e., below `C0`).
+    pub fn transpose_down(&self, interval: Interval) -> Res<Chord> {
+        self.transpose_with(|n| n.checked_sub(interval)).ok_or_else(|| self.transposition_error())
     }
-}
 
-impl HasExtensions for Chord {
-    fn extensions(&self) -> &HashSet<Extension> {
-        &self.extensions
+    /// Returns a new chord with the root (and slash, if any) transposed by the given number of semitones (up, if positive, or down, if negative).
+    ///
+    /// The transposed notes are spelled with the most common intervals, and then [respelled](Note::respell) to avoid
+    /// double accidentals (e.g., `D♭` up three semitones is `E`, rather than `F♭`).  Returns an error if either note would be
+    /// out of the octave range.
+    pub fn transpose_semitones(&self, semitones: i8) -> Res<Chord> {
+        self.transpose_with(|n| transpose_note_semitones(n, semitones)).ok_or_else(|| self.transposition_error())
     }
-}
 
-impl HasInversion for Chord {
-    fn inversion(&self) -> u8 {
-        self.inversion
+    /// Returns a new chord transposed so that the root is the given note (spelled as given, in the octave of the current root),
+    /// and the slash (if any) is moved by the same number of semitones.
+    ///
+    /// Returns an error if the slash would be out of the octave range.
+    pub fn transpose_to(&self, root: Note) -> Res<Chord> {
+        let root = root.with_octave(self.root.octave());
+        let semitones = root.to_midi() as i16 - self.root.to_midi() as i16;
+
+        let slash = match self.slash {
+            Some(slash) => Some(transpose_note_semitones(slash, semitones as i8).ok_or_else(|| self.transposition_error())?),
+            None => None,
+        };
+
+        Ok(Chord { root, slash, ..self.clone() })
     }
-}
 
-impl HasIsCrunchy for Chord {
-    fn is_crunchy(&self) -> bool {
-        self.is_crunchy
+    /// Returns the error for a transposition that would move the chord out of the octave range.
+    fn transposition_error(&self) -> anyhow::Error {
+        anyhow::Error::msg(format!("Unable to transpose `{}` beyond the octave range (`C0` through `B15`).", self.precise_name()))
     }
-}
 
-impl Display for Chord {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let scale = self.scale().iter().map(HasStaticName::static_name).collect::<Vec<_>>().join(", ");
-        let chord = self.chord().iter().map(HasStaticName::static_name).collect::<Vec<_>>().join(", ");
+    /// Returns a new chord with the root (and slash, if any) transposed by the given function, or `None` if it cannot transpose
+    /// either of them (e.g., because the octave would be out of range).
+    fn transpose_with(&self, transpose_note: impl Fn(Note) -> Option<Note>) -> Option<Chord> {
+        let root = transpose_note(self.root)?;
+        let slash = match self.slash {
+            Some(slash) => Some(transpose_note(slash)?),
+            None => None,
+        };
 
-        write!(f, "{}\n   {}\n   {}\n   {}", self.precise_name(), self.description(), scale, chord)
+        Some(Chord { root, slash, ..self.clone() })
     }
-}
 
-impl Chordable for Chord {
-    fn with_modifier(mut self, modifier: Modifier) -> Chord {
-        // Augmented modifiers trump b5 and dim modifiers.
-        if modifier == Modifier::Augmented5 {
-            self.modifiers.remove(&Modifier::Flat5);
-            self.modifiers.remove(&Modifier::Diminished);
+    /// Returns the chord with its inversion (if any) named by its bass note (e.g., `C/E`, rather than `C^1`), as with
+    /// [`Chordable::with_bass`].
+    ///
+    /// This only changes the [name](HasName::name) of the chord (the inversion, and, therefore, the tones, are unchanged), so it is
+    /// useful for displaying the candidates of [`Chord::try_from_notes`] as lead sheet symbols.  The [precise name](HasPreciseName::precise_name)
+    /// keeps the inversion number (so that it still parses back to the same chord), and slash chords are unchanged.
+    pub fn named_by_bass(&self) -> Chord {
+        Chord {
+            is_inversion_by_bass: self.inversion != 0,
+            ..self.clone()
         }
+    }
 
-        if (modifier == Modifier::Diminished || modifier == Modifier::Flat5) && self.modifiers.contains(&Modifier::Augmented5) {
-            return self;
+    /// Returns the bass note of an inversion that is named by its bass note (see [`Chordable::with_bass`]).
+    fn inversion_bass(&self) -> Option<Note> {
+        if !self.is_inversion_by_bass || self.slash.is_some() {
+            return None;
         }
 
-        self.modifiers.insert(modifier);
+        self.relative_chord().get(self.inversion as usize).map(|i| self.root + *i)
+    }
 
-        self
+    /// Returns the chord at every inversion, from root position (inversion `0`) through inversion `n - 1`,
+    /// where `n` is the number of chord tones (including extensions).
+    ///
+    /// Slash chords keep their slash note in the bass, so their inversions only rearrange the upper structure.
+    pub fn all_inversions(&self) -> Vec<Chord> {
+        let tone_count = self.relative_chord().len();
+
+        (0..tone_count).map(|k| self.clone().with_inversion(k as u8)).collect()
     }
 
-    fn with_modifiers(self, modifiers: &[Modifier]) -> Chord {
-        let mut chord = self;
+    /// Returns the chromatic approach chords a half step above (or below) the chord.
+    ///
+    /// The candidates are the dominant seventh a half step away (e.g., `D♭7` approaching `Cmaj7` from above),
+    /// the same chord quality a half step away (i.e., parallel approach), and, when approaching from below,
+    /// the diminished seventh on the leading tone.
+    ///
+    /// The candidates share a root, so none are returned if it would fall out of the octave range (e.g., below `C0`).
+    pub fn approach_chords(&self, from_below: bool) -> Vec<Chord> {
+        let parallel = if from_below {
+            self.transpose_with(|n| n.checked_sub(Interval::MinorSecond))
+        } else {
+            self.transpose_with(|n| n.checked_add(Interval::MinorSecond))
+        };
 
-        for m in modifiers {
-            chord = chord.with_modifier(*m);
+        let Some(parallel) = parallel else {
+            return Vec::new();
+        };
+
+        let root = parallel.root;
+
+        let mut result = vec![Chord::new(root).seven(), parallel];
+
+        if from_below {
+            result.push(Chord::new(root).dim());
         }
 
-        chord
+        result.dedup();
+
+        result
     }
+}
 
-    fn with_extension(mut self, extension: Extension) -> Chord {
-        self.extensions.insert(extension);
+impl Chord {
+    /// Returns the tritone substitution of the chord (e.g., `D♭7` for `G7`), if the chord is a dominant chord (and the
+    /// substitution is within the octave range).
+    pub fn tritone_substitution(&self) -> Option<Chord> {
+        match self.known_chord() {
+            KnownChord::Dominant(_) | KnownChord::DominantSharp11(_) | KnownChord::DominantFlat9(_) | KnownChord::DominantSharp9(_) | KnownChord::AugmentedDominant(_) => {
+                self.transpose_with(|n| n.checked_sub(Interval::AugmentedFourth))
+            }
+            _ => None,
+        }
+    }
 
-        self
+    /// Returns the relative substitution of the chord (e.g., `Am7` for `Cmaj7`, and `C` for `Am`), if the chord is
+    /// a major or minor triad, or a major or minor seventh chord (and its root is within the octave range).
+    pub fn relative_substitution(&self) -> Option<Chord> {
+        match self.known_chord() {
+            KnownChord::Major => Some(Chord::new(self.root.checked_sub(Interval::MinorThird)?).minor()),
+            KnownChord::Major7 => Some(Chord::new(self.root.checked_sub(Interval::MinorThird)?).minor().seven()),
+            KnownChord::Minor => Some(Chord::new(self.root.checked_add(Interval::MinorThird)?)),
+            KnownChord::MinorDominant(Degree::Seven) => Some(Chord::new(self.root.checked_add(Interval::MinorThird)?).maj7()),
+            _ => None,
+        }
     }
 
-    fn with_extensions(self, extensions: &[Extension]) -> Chord {
-        let mut chord = self;
+    /// Returns whether or not the two chords sound the same tones (i.e., the same pitches in the same octaves), ignoring
+    /// spelling and structure (e.g., `D♭` and `C♯`, or `Am7^1` and `C6@5`).
+    ///
+    /// Unlike `==`, which compares the roots, modifiers, and extensions exactly, this compares the [chord tones](HasChord::chord).
+    pub fn is_enharmonic(&self, other: &Chord) -> bool {
+        let mut a = self.chord_midi();
+        let mut b = other.chord_midi();
 
-        for e in extensions {
-            chord = chord.with_extension(*e);
-        }
+        a.sort();
+        a.dedup();
+        b.sort();
+        b.dedup();
 
-        chord
+        a == b
     }
 
-    fn with_inversion(mut self, inversion: u8) -> Chord {
-        self.inversion = inversion;
+    /// Returns whether or not the exact note (i.e., spelling and octave) is one of the chord tones.
+    pub fn contains_note(&self, note: &Note) -> bool {
+        self.chord().contains(note)
+    }
 
-        self
+    /// Returns whether or not the pitch class is one of the chord tones (ignoring spelling and octaves).
+    pub fn contains_pitch(&self, pitch: Pitch) -> bool {
+        self.chord().iter().any(|n| n.pitch() == pitch)
     }
 
-    fn with_slash(mut self, slash: Note) -> Chord {
-        self.slash = Some(slash);
+    /// Returns the chord tones of this chord whose pitch classes also appear in the other chord (ignoring octaves).
+    pub fn common_tones(&self, other: &Chord) -> Vec<Note> {
+        let other_pitches = other.chord().iter().map(|n| n.pitch()).collect::<Vec<_>>();
 
-        self
-    }
-
-    fn with_octave(self, octave: Octave) -> Self {
-        let root = Note::new(self.root.named_pitch(), octave);
+        let mut result: Vec<Note> = Vec::new();
 
-        Chord { root, ..self }
-    }
+        for note in self.chord() {
+            if other_pitches.contains(&note.pitch()) && !result.iter().any(|n| n.pitch() == note.pitch()) {
+                result.push(note);
+            }
+        }
 
-    fn with_crunchy(self, is_crunchy: bool) -> Chord {
-        Chord { is_crunchy, ..self }
+        result
     }
 
-    // Modifiers.
-
-    fn minor(self) -> Chord {
-        self.with_modifier(Modifier::Minor)
+    /// Returns whether or not this chord is the relative of the other chord (e.g., `Am` and `C`, or `Am7` and `Cmaj7`),
+    /// ignoring octaves.
+    pub fn is_relative_of(&self, other: &Chord) -> bool {
+        match self.relative_substitution() {
+            Some(relative) => relative.root.pitch() == other.root.pitch() && relative.known_chord() == other.known_chord(),
+            None => false,
+        }
     }
 
-    fn flat5(self) -> Chord {
-        self.with_modifier(Modifier::Flat5)
+    /// Returns the voice leading distance from this chord to the other chord.
+    ///
+    /// This is the sum, over every chord tone of the other chord, of the smallest number of semitones to any of the
+    /// chord tones of this chord (ignoring octaves); i.e., smaller distances imply "smoother" voice leading.
+    pub fn voice_leading_distance(&self, other: &Chord) -> u32 {
+        let from = self.chord().iter().map(|n| n.pitch() as u8).collect::<Vec<_>>();
+
+        other
+            .chord()
+            .iter()
+            .map(|n| {
+                let to = n.pitch() as u8;
+
+                from.iter()
+                    .map(|f| {
+                        let distance = (to + 12 - f) % 12;
+                        distance.min(12 - distance) as u32
+                    })
+                    .min()
+                    .unwrap_or_default()
+            })
+            .sum()
     }
+}
 
-    fn flat_five(self) -> Chord {
-        self.flat5()
+impl Chord {
+    /// Returns the MIDI note numbers of the chord tones.
+    pub fn chord_midi(&self) -> Vec<u8> {
+        self.chord().iter().map(Note::to_midi).collect()
     }
 
-    fn augmented(self) -> Chord {
-        self.with_modifier(Modifier::Augmented5)
+    /// Returns the piano key of each chord tone (in [`HasChord::chord`] order) as its spelled [`NamedPitch`] and [`Octave`],
+    /// along with whether it is a black key.
+    ///
+    /// The key color is determined by the sounding pitch, so `B♯`, `F♭`, and `C𝄪` are all white keys.
+    pub fn piano_keys(&self) -> Vec<(NamedPitch, Octave, bool)> {
+        self.chord().iter().map(|n| (n.named_pitch(), n.octave(), n.pitch().is_black_key())).collect()
     }
 
-    fn aug(self) -> Chord {
-        self.augmented()
-    }
+    /// Returns the chord tones as scale degree labels relative to the root, in ascending order (e.g., `1`, `3`, `5`, `b7`, and `b9`
+    /// for `C7♭9`).
+    ///
+    /// See [`Interval::degree_label`] for how altered degrees are labeled.  The slash note and inversion are not considered.
+    pub fn scale_degrees(&self) -> Vec<&'static str> {
+        let mut intervals = self.relative_chord();
+        intervals.sort_by_key(Interval::semitones);
 
-    fn major7(self) -> Chord {
-        self.with_modifier(Modifier::Major7)
+        intervals.iter().map(Interval::degree_label).collect()
     }
 
-    fn major_seven(self) -> Chord {
-        self.major7()
-    }
+    /// Returns the notes of the [scale](HasScale::scale) of the chord repeated across octaves, in ascending order, from `low` to
+    /// `high` (inclusive); e.g., `C3` through `C5` for `C` from `C3` to `C5`.
+    ///
+    /// Each note keeps its spelling in every octave.  If `low` is above `high`, the result is empty.
+    pub fn scale_in_range(&self, low: Note, high: Note) -> Vec<Note> {
+        let range = low.to_midi()..=high.to_midi();
 
-    fn maj7(self) -> Chord {
-        self.major7()
-    }
+        let mut result = self
+            .scale()
+            .into_iter()
+            .flat_map(|note| ALL_OCTAVES.iter().map(move |octave| note.with_octave(*octave)))
+            .filter(|note| range.contains(&note.to_midi()))
+            .collect::<Vec<_>>();
 
-    fn dominant7(self) -> Chord {
-        self.with_modifier(Modifier::Dominant(Degree::Seven))
-    }
+        result.sort_by_key(Note::to_midi);
 
-    fn seven(self) -> Chord {
-        self.dominant7()
+        result
     }
 
-    fn dominant9(self) -> Chord {
-        self.with_modifier(Modifier::Dominant(Degree::Nine))
-    }
+    /// Returns the chord tones re-octaved to fit within the given (inclusive) range (e.g., an instrument's or a vocalist's range).
+    ///
+    /// Duplicate pitch classes are dropped, the lowest chord tone is placed as low as possible in the range, and every other tone
+    /// is stacked above the previous one, wrapping back to the bottom of the range when it would exceed the top.  Spellings are
+    /// preserved.  Returns an error if the chord cannot fit (i.e., some tone has no octave within the range).
+    pub fn voice_within(&self, low: Note, high: Note) -> Res<Vec<Note>> {
+        let (low_midi, high_midi) = (low.to_midi() as i16, high.to_midi() as i16);
+
+        let mut tones: Vec<Note> = Vec::new();
+        for note in self.chord() {
+            if !tones.iter().any(|n| n.pitch() == note.pitch()) {
+                tones.push(note);
+            }
+        }
 
-    fn nine(self) -> Chord {
-        self.dominant9()
-    }
+        if high_midi - low_midi + 1 < tones.len() as i16 {
+            return Err(anyhow::Error::msg(format!(
+                "The chord `{}` has {} distinct tones, which cannot fit within {} to {}.",
+                self,
+                tones.len(),
+                low,
+                high
+            )));
+        }
 
-    fn dominant11(self) -> Chord {
-        self.with_modifier(Modifier::Dominant(Degree::Eleven))
-    }
+        let mut result = Vec::with_capacity(tones.len());
+        let mut previous = low_midi - 1;
 
-    fn eleven(self) -> Chord {
-        self.dominant11()
-    }
+        for note in tones {
+            let midi = note.to_midi() as i16;
 
-    fn dominant13(self) -> Chord {
-        self.with_modifier(Modifier::Dominant(Degree::Thirteen))
-    }
+            // Find the lowest position of this tone above the previous tone, or wrap back to the bottom of the range.
+            let mut target = lowest_position_at_or_above(midi, previous + 1);
+            if target > high_midi {
+                target = lowest_position_at_or_above(midi, low_midi);
+            }
 
-    fn thirteen(self) -> Chord {
-        self.dominant13()
-    }
+            let octave = Octave::try_from_i8(note.octave().number() + ((target - midi) / 12) as i8);
 
-    fn dominant(self, dominant: Degree) -> Chord {
-        self.with_modifier(Modifier::Dominant(dominant))
-    }
+            match octave {
+                Some(octave) if target <= high_midi => result.push(note.with_octave(octave)),
+                _ => {
+                    return Err(anyhow::Error::msg(format!(
+                        "The chord tone `{}` of `{}` cannot fit within {} to {}.",
+                        note.named_pitch().static_name(),
+                        self,
+                        low,
+                        high
+                    )))
+                }
+            }
 
-    fn flat9(self) -> Chord {
-        self.with_modifier(Modifier::Flat9)
-    }
+            previous = target;
+        }
 
-    fn flat_nine(self) -> Chord {
-        self.flat9()
-    }
+        result.sort();
 
-    fn sharp9(self) -> Chord {
-        self.with_modifier(Modifier::Sharp9)
+        Ok(result)
     }
 
-    fn sharp_nine(self) -> Chord {
-        self.sharp9()
+    /// Returns the frequencies of the chord tones.
+    pub fn frequencies(&self) -> Vec<f32> {
+        self.chord().iter().map(|n| n.frequency()).collect()
     }
 
-    fn sharp11(self) -> Chord {
-        self.with_modifier(Modifier::Sharp11)
+    /// Returns the frequencies of the chord tones using the given [`Tuning`].
+    pub fn frequencies_with(&self, tuning: &Tuning) -> Vec<f32> {
+        self.chord().iter().map(|n| n.frequency_with(tuning)).collect()
     }
 
-    fn sharp_eleven(self) -> Chord {
-        self.sharp11()
+    /// Returns the frequency of the lowest chord tone.
+    pub fn fundamental_frequency(&self) -> f32 {
+        self.frequencies().into_iter().fold(f32::INFINITY, f32::min)
     }
 
-    // Special.
-
-    fn diminished(self) -> Chord {
-        self.with_modifier(Modifier::Diminished)
+    /// Returns a type-0 Standard MIDI File that sounds the chord for the given number of ticks.
+    ///
+    /// The file uses [`SMF_TICKS_PER_QUARTER_NOTE`](crate::core::helpers::SMF_TICKS_PER_QUARTER_NOTE) ticks per quarter note, and
+    /// the given velocity (clamped to `127`).
+    pub fn to_smf(&self, ticks: u32, velocity: u8) -> Vec<u8> {
+        write_smf(&[(self.chord_midi(), ticks)], velocity)
     }
+}
 
-    fn dim(self) -> Chord {
-        self.diminished()
-    }
+impl Chord {
+    /// Returns a minimal MusicXML fragment for the chord.
+    ///
+    /// The fragment consists of a `<harmony>` element (root, kind, inversion, and bass) followed by
+    /// the chord tones as stacked whole `<note>` elements (assuming one division per quarter note).
+    pub fn to_musicxml(&self) -> String {
+        let mut xml = String::new();
 
-    fn half_diminished(self) -> Chord {
-        self.minor().seven().flat5()
-    }
+        // Write the harmony.
 
-    fn half_dim(self) -> Chord {
-        self.half_diminished()
-    }
+        xml.push_str("<harmony>");
 
-    // Extensions.
+        let (root_step, root_alter) = musicxml_step_and_alter(self.root.named_pitch());
+        xml.push_str(&format!("<root><root-step>{}</root-step>", root_step));
+        if root_alter != 0 {
+            xml.push_str(&format!("<root-alter>{}</root-alter>", root_alter));
+        }
+        xml.push_str("</root>");
 
-    fn sus2(self) -> Chord {
-        self.with_extension(Extension::Sus2)
-    }
+        let name = self.name();
+        let text = name[self.root.static_name().len()..].split('/').next().unwrap_or_default();
+        xml.push_str(&format!("<kind text=\"{}\">{}</kind>", text, musicxml_kind(self.known_chord())));
 
-    fn sus_two(self) -> Chord {
-        self.sus2()
-    }
+        if self.inversion != 0 {
+            xml.push_str(&format!("<inversion>{}</inversion>", self.inversion));
+        }
 
-    fn sus4(self) -> Chord {
-        self.with_extension(Extension::Sus4)
-    }
+        if let Some(slash) = self.slash {
+            let (bass_step, bass_alter) = musicxml_step_and_alter(slash.named_pitch());
+            xml.push_str(&format!("<bass><bass-step>{}</bass-step>", bass_step));
+            if bass_alter != 0 {
+                xml.push_str(&format!("<bass-alter>{}</bass-alter>", bass_alter));
+            }
+            xml.push_str("</bass>");
+        }
 
-    fn sus_four(self) -> Chord {
-        self.sus4()
-    }
+        xml.push_str("</harmony>");
 
-    fn sustain(self) -> Chord {
-        self.sus4()
-    }
+        // Write the chord tones.
 
-    fn sus(self) -> Chord {
-        self.sustain()
-    }
+        for (k, note) in self.chord().into_iter().enumerate() {
+            let (step, alter) = musicxml_step_and_alter(note.named_pitch());
 
-    fn flat11(self) -> Chord {
-        self.with_extension(Extension::Flat11)
-    }
+            xml.push_str("<note>");
+            if k != 0 {
+                xml.push_str("<chord/>");
+            }
+            xml.push_str(&format!("<pitch><step>{}</step>", step));
+            if alter != 0 {
+                xml.push_str(&format!("<alter>{}</alter>", alter));
+            }
+            xml.push_str(&format!("<octave>{}</octave></pitch>", note.octave().static_name()));
+            xml.push_str("<duration>4</duration><type>whole</type></note>");
+        }
 
-    fn flat_eleven(self) -> Chord {
-        self.flat11()
+        xml
     }
+}
 
-    fn flat13(self) -> Chord {
-        self.with_extension(Extension::Flat13)
+impl Chord {
+    /// Returns the chord tones as a LilyPond chord (e.g., `<c' e' g'>` for `C`), without a duration.
+    ///
+    /// Each tone is spelled by [`Note::to_lilypond`], so the octave marks are absolute.
+    pub fn to_lilypond(&self) -> String {
+        format!("<{}>", lilypond_sequence(&self.chord()))
     }
 
-    fn flat_thirteen(self) -> Chord {
-        self.flat13()
+    /// Returns the scale of the chord as a sequence of LilyPond notes (e.g., `c' d' e' f' g' a' b'` for `C`), without durations.
+    ///
+    /// Each note is spelled by [`Note::to_lilypond`], so the octave marks are absolute.
+    pub fn scale_to_lilypond(&self) -> String {
+        lilypond_sequence(&self.scale())
     }
+}
 
-    fn sharp13(self) -> Chord {
-        self.with_extension(Extension::Sharp13)
+impl Chord {
+    /// Returns the chord tones as an ABC notation chord (e.g., `[CEG]` for `C`), without a length.
+    ///
+    /// Each tone is spelled by [`Note::to_abc`], so `C` is middle C (`C4`).
+    pub fn to_abc(&self) -> String {
+        format!("[{}]", self.chord().iter().map(Note::to_abc).collect::<String>())
     }
 
-    fn sharp_thirteen(self) -> Chord {
-        self.sharp13()
+    /// Returns the scale of the chord as a sequence of ABC notation notes (e.g., `C D E F G A B` for `C`), without lengths.
+    ///
+    /// Each note is spelled by [`Note::to_abc`], so `C` is middle C (`C4`).
+    pub fn scale_to_abc(&self) -> String {
+        self.scale().iter().map(Note::to_abc).collect::<Vec<_>>().join(" ")
     }
+}
 
-    fn add2(self) -> Chord {
-        self.with_extension(Extension::Add2)
+impl Chord {
+    /// Returns the single canonical symbol of the chord, regardless of how the chord was built or spelled
+    /// (e.g., `Cm7b5`, `Cø`, and `C-7(b5)` are all `Cm7(♭5)`), which is useful as a key for deduplication.
+    ///
+    /// The canonical symbol is the [name](HasName::name) of the chord, which only depends on the root, the modifiers and extensions
+    /// (rendered in a fixed order), and the slash (or bass) note.
+    pub fn canonical_name(&self) -> String {
+        self.name()
     }
 
-    fn add_two(self) -> Chord {
-        self.add2()
-    }
+    /// Returns the alternate symbols that parse to the same [canonical name](Chord::canonical_name) (e.g., `Cø`, `Cm7b5`,
+    /// and `C-7♭5` for `Cm7(♭5)`), excluding the canonical name itself.
+    pub fn aliases(&self) -> Vec<String> {
+        let canonical_name = self.canonical_name();
+        let known_chord = self.known_chord();
 
-    fn add4(self) -> Chord {
-        self.with_extension(Extension::Add4)
-    }
+        let root = self.root.static_name();
+        let quality = known_chord.name();
+        let rest = canonical_name[root.len()..].strip_prefix(quality.as_str()).unwrap_or(&canonical_name[root.len()..]);
 
-    fn add_four(self) -> Chord {
-        self.add4()
-    }
+        // Collect the alternate spellings of the chord quality.
+        let mut qualities = vec![quality.clone()];
 
-    fn add6(self) -> Chord {
-        self.with_extension(Extension::Add6)
-    }
+        match known_chord {
+            KnownChord::HalfDiminished(degree) => {
+                qualities.push(format!("ø{}", degree.static_name()));
 
-    fn add_six(self) -> Chord {
-        self.add6()
-    }
+                if degree == Degree::Seven {
+                    qualities.push("ø".to_owned());
+                }
+            }
+            KnownChord::Diminished => qualities.push("o".to_owned()),
+            _ => {}
+        }
 
-    fn add9(self) -> Chord {
-        self.with_extension(Extension::Add9)
-    }
+        if matches!(known_chord, KnownChord::Minor | KnownChord::MinorDominant(_) | KnownChord::MinorMajor7 | KnownChord::HalfDiminished(_)) {
+            qualities.push(format!("-{}", &quality[1..]));
+        }
 
-    fn add_nine(self) -> Chord {
-        self.add9()
-    }
+        // Combine the qualities with the rest of the symbol, with and without parentheses, and with ASCII accidentals.
+        let mut aliases = Vec::new();
 
-    fn add11(self) -> Chord {
-        self.with_extension(Extension::Add11)
-    }
+        for quality in qualities {
+            let symbol = format!("{}{}{}", root, quality, rest);
+            let bare = symbol.replace(['(', ')'], "");
 
-    fn add_eleven(self) -> Chord {
-        self.add11()
-    }
+            for alias in [symbol.clone(), bare.clone(), symbol.replace('♭', "b").replace('♯', "#"), bare.replace('♭', "b").replace('♯', "#")] {
+                if alias == canonical_name || aliases.contains(&alias) {
+                    continue;
+                }
 
-    fn add13(self) -> Chord {
-        self.with_extension(Extension::Add13)
-    }
+                // Some forms are ambiguous (e.g., `C♭9` is a `C♭` chord), so only keep the forms that parse back to this chord.
+                if Chord::parse(&alias).is_ok_and(|c| c.canonical_name() == canonical_name) {
+                    aliases.push(alias);
+                }
+            }
+        }
 
-    fn add_thirteen(self) -> Chord {
-        self.add13()
+        aliases
     }
 }
 
-impl HasKnownChord for Chord {
-    fn known_chord(&self) -> KnownChord {
-        let modifiers = &self.modifiers;
-        let degree = self.dominant_degree();
+impl HasName for Chord {
+    fn name(&self) -> String {
+        let known_name = self.known_chord().name();
+        let known_name = known_name.as_str();
+        let mut name = String::new();
+
+        name.push_str(self.root.static_name());
 
-        let contains_dominant = degree.is_some();
-        let degree = degree.unwrap_or(Degree::Seven);
+        // A major chord with no third (and nothing else) is a power chord.
+        if self.modifiers.is_empty() && self.extensions.is_empty() && self.omissions.len() == 1 && self.omissions.contains(&Omission::No3) {
+            name.push('5');
 
-        if modifiers.contains(&Modifier::Diminished) {
-            KnownChord::Diminished
-        } else if modifiers.contains(&Modifier::Minor) {
-            if modifiers.contains(&Modifier::Major7) {
-                return KnownChord::MinorMajor7;
+            if let Some(slash) = self.slash {
+                name.push_str(&format!("/{}", slash.static_name()));
             }
 
-            if contains_dominant {
-                if modifiers.contains(&Modifier::Flat5) {
-                    return KnownChord::HalfDiminished(degree);
-                }
+            return name;
+        }
 
-                return KnownChord::MinorDominant(degree);
-            }
+        name.push_str(known_name);
 
-            return KnownChord::Minor;
-        } else {
-            if modifiers.contains(&Modifier::Augmented5) {
-                if modifiers.contains(&Modifier::Major7) {
-                    return KnownChord::AugmentedMajor7;
-                }
+        // Add special modifiers that are true modifiers when not part of their "special case".
 
-                if contains_dominant {
-                    return KnownChord::AugmentedDominant(degree);
-                }
+        if self.modifiers.contains(&Modifier::Flat5) && !known_name.contains("(♭5)") {
+            name.push_str("(♭5)");
+        }
 
-                return KnownChord::Augmented;
-            }
+        if self.modifiers.contains(&Modifier::Augmented5) && !known_name.contains('+') {
+            name.push_str("(♯5)");
+        }
 
-            if self.modifiers.contains(&Modifier::Major7) {
-                return KnownChord::Major7;
-            }
+        if self.modifiers.contains(&Modifier::Flat9) && !known_name.contains("(♭9)") {
+            name.push_str("(♭9)");
+        }
 
-            if contains_dominant {
-                if modifiers.contains(&Modifier::Flat9) {
-                    return KnownChord::DominantFlat9(degree);
-                }
+        if self.modifiers.contains(&Modifier::Sharp9) && !known_name.contains("(♯9)") {
+            name.push_str("(♯9)");
+        }
 
-                if modifiers.contains(&Modifier::Sharp9) {
-                    return KnownChord::DominantSharp9(degree);
-                }
+        if self.modifiers.contains(&Modifier::Sharp11) && !known_name.contains("(♯11)") {
+            name.push_str("(♯11)");
+        }
 
-                if modifiers.contains(&Modifier::Sharp11) {
-                    return KnownChord::DominantSharp11(degree);
-                }
+        // Add extensions (in order, so that the name is deterministic).
+        let mut extensions = self.extensions.iter().collect::<Vec<_>>();
+        extensions.sort();
 
-                return KnownChord::Dominant(degree);
-            }
+        for e in extensions {
+            name.push_str(&format!("({})", e.static_name()));
+        }
+
+        // Add omissions (in order, since there are only a few).
+        let mut omissions = self.omissions.iter().collect::<Vec<_>>();
+        omissions.sort();
 
-            return KnownChord::Major;
+        for o in omissions {
+            name.push_str(&format!("({})", o.static_name()));
         }
+
+        // Add slash note (or the bass note of an inversion named by it).
+        if let Some(slash) = self.slash {
+            name.push_str(&format!("/{}", slash.static_name()));
+        } else if let Some(bass) = self.inversion_bass() {
+            name.push_str(&format!("/{}", bass.static_name()));
+        }
+
+        // Add special information about the chord.
+
+        name
     }
 }
 
-impl HasDescription for Chord {
-    fn description(&self) -> &'static str {
-        self.known_chord().description()
+impl HasPreciseName for Chord {
+    fn precise_name(&self) -> String {
+        let mut name = String::new();
+
+        // The inversion is always given by its number (rather than by its bass note), so that the precise name parses back to the same chord.
+        let symbol = Chord {
+            is_inversion_by_bass: false,
+            ..self.clone()
+        }
+        .name();
+
+        name.push_str(&symbol);
+
+        // Add the octave of a pinned slash note (the slash note is the end of the name).
+        if let (Some(slash), true) = (self.slash, self.is_slash_pinned) {
+            name.push_str(slash.octave().static_name());
+        }
+
+        // Add octave modifier.
+        if self.root.octave() != Octave::Four {
+            name.push_str(&format!("@{}", self.root.octave().static_name()));
+        }
+
+        // Add inversion modifier.
+        if self.inversion != 0 {
+            name.push_str(&format!("^{}", self.inversion));
+        }
+
+        // Add crunchy modifier.
+        if self.is_crunchy {
+            name.push('!');
+        }
+
+        name
     }
 }
 
-impl HasRelativeScale for Chord {
-    fn relative_scale(&self) -> Vec<Interval> {
-        self.known_chord().relative_scale()
+impl HasRoot for Chord {
+    fn root(&self) -> Note {
+        self.root
     }
 }
 
-impl HasRelativeChord for Chord {
-    fn relative_chord(&self) -> Vec<Interval> {
-        let mut result = self.known_chord().relative_chord();
-        let modifiers = &self.modifiers;
-        let extensions = &self.extensions;
+impl HasSlash for Chord {
+    fn slash(&self) -> Note {
+        self.slash.unwrap_or(self.root)
+    }
+}
 
-        // Dominant extensions.
+impl HasModifiers for Chord {
+    fn modifiers(&self) -> &HashSet<Modifier> {
+        &self.modifiers
+    }
+}
 
-        if modifiers.contains(&Modifier::Dominant(Degree::Nine)) {
-            result.push(Interval::MajorNinth);
-        } else if modifiers.contains(&Modifier::Dominant(Degree::Eleven)) {
-            result.push(Interval::MajorNinth);
-            result.push(Interval::PerfectEleventh);
-        } else if modifiers.contains(&Modifier::Dominant(Degree::Thirteen)) {
-            result.push(Interval::MajorNinth);
-            result.push(Interval::PerfectEleventh);
-            result.push(Interval::MajorThirteenth);
-        }
+impl HasExtensions for Chord {
+    fn extensions(&self) -> &HashSet<Extension> {
+        &self.extensions
+    }
+}
 
-        // Special modifiers that can also be extensions.
+impl HasOmissions for Chord {
+    fn omissions(&self) -> &HashSet<Omission> {
+        &self.omissions
+    }
+}
 
-        if modifiers.contains(&Modifier::Flat5) {
-            result.remove(2);
-            result.push(Interval::DiminishedFifth);
-        }
+impl HasInversion for Chord {
+    fn inversion(&self) -> u8 {
+        self.inversion
+    }
+}
 
-        if modifiers.contains(&Modifier::Augmented5) {
-            result.remove(2);
-            result.push(Interval::AugmentedFifth);
-        }
+impl HasIsCrunchy for Chord {
+    fn is_crunchy(&self) -> bool {
+        self.is_crunchy
+    }
+}
 
-        if modifiers.contains(&Modifier::Flat9) {
-            result.push(Interval::MinorNinth);
-        }
+impl Display for Chord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let scale = self.scale().iter().map(HasStaticName::static_name).collect::<Vec<_>>().join(", ");
+        let chord = self.chord().iter().map(HasStaticName::static_name).collect::<Vec<_>>().join(", ");
 
-        if modifiers.contains(&Modifier::Sharp9) {
-            result.push(Interval::AugmentedNinth);
+        write!(f, "{}\n   {}\n   {}\n   {}", self.precise_name(), self.description(), scale, chord)
+    }
+}
+
+impl Chordable for Chord {
+    fn with_modifier(mut self, modifier: Modifier) -> Chord {
+        // Augmented modifiers trump b5 and dim modifiers.
+        if modifier == Modifier::Augmented5 {
+            self.modifiers_mut().remove(&Modifier::Flat5);
+            self.modifiers_mut().remove(&Modifier::Diminished);
         }
 
-        if modifiers.contains(&Modifier::Sharp11) {
-            result.push(Interval::AugmentedEleventh);
+        if (modifier == Modifier::Diminished || modifier == Modifier::Flat5) && self.modifiers.contains(&Modifier::Augmented5) {
+            return self;
         }
 
-        // Extensions.
+        self.modifiers_mut().insert(modifier);
 
-        if extensions.contains(&Extension::Sus2) {
-            result.remove(1);
-            result.push(Interval::MajorSecond);
-        }
+        self
+    }
 
-        if extensions.contains(&Extension::Sus4) {
-            result.remove(1);
-            result.push(Interval::PerfectFourth);
-        }
+    fn with_modifiers(self, modifiers: &[Modifier]) -> Chord {
+        let mut chord = self;
 
-        if extensions.contains(&Extension::Flat11) {
-            result.push(Interval::DiminishedEleventh);
+        for m in modifiers {
+            chord = chord.with_modifier(*m);
         }
 
-        if extensions.contains(&Extension::Flat13) {
-            result.push(Interval::MinorThirteenth);
-        }
+        chord
+    }
 
-        if extensions.contains(&Extension::Sharp13) {
-            result.push(Interval::AugmentedThirteenth);
-        }
+    fn with_extension(mut self, extension: Extension) -> Chord {
+        self.extensions.insert(extension);
 
-        if extensions.contains(&Extension::Add2) {
-            result.push(Interval::MajorSecond);
-        }
+        self
+    }
 
-        if extensions.contains(&Extension::Add4) {
-            result.push(Interval::PerfectFourth);
-        }
+    fn with_extensions(self, extensions: &[Extension]) -> Chord {
+        let mut chord = self;
 
-        if extensions.contains(&Extension::Add6) {
-            result.push(Interval::MajorSixth);
+        for e in extensions {
+            chord = chord.with_extension(*e);
         }
 
-        if extensions.contains(&Extension::Add9) {
-            result.push(Interval::MajorNinth);
-        }
+        chord
+    }
 
-        if extensions.contains(&Extension::Add11) {
-            result.push(Interval::PerfectEleventh);
-        }
+    fn with_omission(mut self, omission: Omission) -> Chord {
+        self.omissions.insert(omission);
 
-        if extensions.contains(&Extension::Add13) {
-            result.push(Interval::MajorThirteenth);
+        self
+    }
+
+    fn with_omissions(self, omissions: &[Omission]) -> Chord {
+        let mut chord = self;
+
+        for o in omissions {
+            chord = chord.with_omission(*o);
         }
 
-        // Keep everything in order.
-        result.sort();
-        result.dedup();
+        chord
+    }
 
-        result
+    fn without_modifier(mut self, modifier: Modifier) -> Chord {
+        self.modifiers_mut().remove(&modifier);
+
+        self
     }
-}
 
-impl HasScale for Chord {
-    fn scale(&self) -> Vec<Note> {
-        self.relative_scale().into_iter().map(|i| self.root + i).collect()
+    fn without_extension(mut self, extension: Extension) -> Chord {
+        self.extensions.remove(&extension);
+
+        self
     }
-}
 
-impl HasChord for Chord {
-    fn chord(&self) -> Vec<Note> {
-        let mut result: Vec<_> = self.relative_chord().into_iter().map(|i| self.root + i).collect();
+    fn clear_extensions(mut self) -> Chord {
+        self.extensions.clear();
 
-        // Perform inversions.
-        for _ in 0..self.inversion {
-            let mut note = result.remove(0);
+        self
+    }
 
-            while note < *result.last().unwrap_or(&CZero) {
-                note += Interval::PerfectOctave;
-            }
+    fn with_inversion(mut self, inversion: u8) -> Chord {
+        self.inversion = inversion;
+        self.is_inversion_by_bass = false;
 
-            result.push(note);
-        }
+        self
+    }
 
-        // If this chord is crunchy, bring all "octave" intervals down to the first octave frame.
-        if self.is_crunchy {
-            let bottom = *result.first().unwrap_or(&CZero);
-            let top = bottom.with_octave(bottom.octave() + 1);
+    fn with_slash(mut self, slash: Note) -> Chord {
+        self.slash = Some(slash);
+        self.is_slash_pinned = false;
+        self.is_inversion_by_bass = false;
 
-            for note in &mut result {
-                while *note > top {
-                    *note = note.with_octave(note.octave() - 1);
-                }
-            }
-        }
+        self
+    }
 
-        // Add slash note.
-        if let Some(mut slash) = self.slash {
-            // Fix slash note (it should be less than, or equal to, one octave away from the bottom tone).
-            let bottom = *result.first().unwrap_or(&CZero);
-            let floor = Note::new(bottom.named_pitch(), bottom.octave() - 1);
+    fn with_bass(mut self, bass: Note) -> Chord {
+        // The inversion `k` puts the `k`th chord tone (in root position) in the bass.
+        let position = self.relative_chord().iter().position(|i| (self.root + *i).pitch() == bass.pitch());
 
-            slash = slash.with_octave(Octave::Zero);
-            while slash < floor {
-                slash += Interval::PerfectOctave;
-            }
+        match position {
+            Some(inversion) => {
+                self.slash = None;
+                self.is_slash_pinned = false;
+                self.inversion = inversion as u8;
+                self.is_inversion_by_bass = inversion != 0;
 
-            result.insert(0, slash);
+                self
+            }
+            None => self.with_slash(bass),
         }
+    }
 
-        // Crunchiness, etc. can introduce changes, so resort, and dedup.
-        result.sort();
-        result.dedup();
+    fn with_pinned_slash(mut self, slash: Note) -> Chord {
+        self.slash = Some(slash);
+        self.is_slash_pinned = true;
 
-        result
+        self
     }
-}
 
-impl HasDomninantDegree for Chord {
-    fn dominant_degree(&self) -> Option<Degree> {
-        let modifiers = &self.modifiers;
-        if !modifiers.contains(&Modifier::Dominant(Degree::Seven))
-            && !modifiers.contains(&Modifier::Dominant(Degree::Nine))
-            && !modifiers.contains(&Modifier::Dominant(Degree::Eleven))
-            && !modifiers.contains(&Modifier::Dominant(Degree::Thirteen))
-        {
-            return None;
-        }
+    fn with_octave(self, octave: Octave) -> Self {
+        let root = Note::new(self.root.named_pitch(), octave);
 
-        Some(match modifiers.iter().find(|m| m.is_dominant()) {
-            Some(Modifier::Dominant(d)) => *d,
-            _ => Degree::Seven,
-        })
+        Chord { root, ..self }
     }
-}
 
-impl Parsable for Chord {
-    fn parse(input: &str) -> Res<Self>
-    where
-        Self: Sized,
-    {
-        let root = ChordParser::parse(Rule::chord, input)?.next().unwrap();
+    fn with_crunchy(self, is_crunchy: bool) -> Chord {
+        Chord { is_crunchy, ..self }
+    }
 
-        assert_eq!(Rule::chord, root.as_rule());
+    // Modifiers.
 
-        let mut components = root.into_inner();
+    fn minor(self) -> Chord {
+        self.with_modifier(Modifier::Minor)
+    }
 
-        let note = components.next().unwrap();
+    fn flat5(self) -> Chord {
+        self.with_modifier(Modifier::Flat5)
+    }
 
-        assert_eq!(Rule::note, note.as_rule());
+    fn flat_five(self) -> Chord {
+        self.flat5()
+    }
+
+    fn augmented(self) -> Chord {
+        self.with_modifier(Modifier::Augmented5)
+    }
+
+    fn aug(self) -> Chord {
+        self.augmented()
+    }
+
+    fn major7(self) -> Chord {
+        self.with_modifier(Modifier::Major7)
+    }
+
+    fn major_seven(self) -> Chord {
+        self.major7()
+    }
+
+    fn maj7(self) -> Chord {
+        self.major7()
+    }
+
+    fn dominant7(self) -> Chord {
+        self.with_modifier(Modifier::Dominant(Degree::Seven))
+    }
+
+    fn seven(self) -> Chord {
+        self.dominant7()
+    }
+
+    fn dominant9(self) -> Chord {
+        self.with_modifier(Modifier::Dominant(Degree::Nine))
+    }
+
+    fn nine(self) -> Chord {
+        self.dominant9()
+    }
+
+    fn dominant11(self) -> Chord {
+        self.with_modifier(Modifier::Dominant(Degree::Eleven))
+    }
+
+    fn eleven(self) -> Chord {
+        self.dominant11()
+    }
+
+    fn dominant13(self) -> Chord {
+        self.with_modifier(Modifier::Dominant(Degree::Thirteen))
+    }
+
+    fn thirteen(self) -> Chord {
+        self.dominant13()
+    }
+
+    fn dominant(self, dominant: Degree) -> Chord {
+        self.with_modifier(Modifier::Dominant(dominant))
+    }
+
+    fn flat9(self) -> Chord {
+        self.with_modifier(Modifier::Flat9)
+    }
+
+    fn flat_nine(self) -> Chord {
+        self.flat9()
+    }
+
+    fn sharp9(self) -> Chord {
+        self.with_modifier(Modifier::Sharp9)
+    }
+
+    fn sharp_nine(self) -> Chord {
+        self.sharp9()
+    }
+
+    fn sharp11(self) -> Chord {
+        self.with_modifier(Modifier::Sharp11)
+    }
+
+    fn sharp_eleven(self) -> Chord {
+        self.sharp11()
+    }
+
+    // Special.
+
+    fn diminished(self) -> Chord {
+        self.with_modifier(Modifier::Diminished)
+    }
+
+    fn dim(self) -> Chord {
+        self.diminished()
+    }
+
+    fn half_diminished(self) -> Chord {
+        self.minor().seven().flat5()
+    }
+
+    fn half_dim(self) -> Chord {
+        self.half_diminished()
+    }
+
+    // Extensions.
+
+    fn sus2(self) -> Chord {
+        self.with_extension(Extension::Sus2)
+    }
+
+    fn sus_two(self) -> Chord {
+        self.sus2()
+    }
+
+    fn sus4(self) -> Chord {
+        self.with_extension(Extension::Sus4)
+    }
+
+    fn sus_four(self) -> Chord {
+        self.sus4()
+    }
+
+    fn sustain(self) -> Chord {
+        self.sus4()
+    }
+
+    fn sus(self) -> Chord {
+        self.sustain()
+    }
+
+    fn flat11(self) -> Chord {
+        self.with_extension(Extension::Flat11)
+    }
+
+    fn flat_eleven(self) -> Chord {
+        self.flat11()
+    }
+
+    fn flat13(self) -> Chord {
+        self.with_extension(Extension::Flat13)
+    }
+
+    fn flat_thirteen(self) -> Chord {
+        self.flat13()
+    }
+
+    fn sharp13(self) -> Chord {
+        self.with_extension(Extension::Sharp13)
+    }
+
+    fn sharp_thirteen(self) -> Chord {
+        self.sharp13()
+    }
+
+    fn add2(self) -> Chord {
+        self.with_extension(Extension::Add2)
+    }
+
+    fn add_two(self) -> Chord {
+        self.add2()
+    }
+
+    fn add4(self) -> Chord {
+        self.with_extension(Extension::Add4)
+    }
+
+    fn add_four(self) -> Chord {
+        self.add4()
+    }
+
+    fn add6(self) -> Chord {
+        self.with_extension(Extension::Add6)
+    }
+
+    fn add_six(self) -> Chord {
+        self.add6()
+    }
+
+    fn add9(self) -> Chord {
+        self.with_extension(Extension::Add9)
+    }
+
+    fn add_nine(self) -> Chord {
+        self.add9()
+    }
+
+    fn add11(self) -> Chord {
+        self.with_extension(Extension::Add11)
+    }
+
+    fn add_eleven(self) -> Chord {
+        self.add11()
+    }
+
+    fn add13(self) -> Chord {
+        self.with_extension(Extension::Add13)
+    }
+
+    fn add_thirteen(self) -> Chord {
+        self.add13()
+    }
+
+    fn no3(self) -> Chord {
+        self.with_omission(Omission::No3)
+    }
+
+    fn no_three(self) -> Chord {
+        self.no3()
+    }
+
+    fn no5(self) -> Chord {
+        self.with_omission(Omission::No5)
+    }
+
+    fn no_five(self) -> Chord {
+        self.no5()
+    }
+
+    fn power(self) -> Chord {
+        self.no3()
+    }
+}
+
+impl HasKnownChord for Chord {
+    fn known_chord(&self) -> KnownChord {
+        *self.known_chord.0.get_or_init(|| classify_known_chord(self))
+    }
+}
+
+impl HasDescription for Chord {
+    fn description(&self) -> &'static str {
+        self.known_chord().description()
+    }
+}
+
+impl HasRelativeScale for Chord {
+    fn relative_scale(&self) -> Vec<Interval> {
+        self.known_chord().relative_scale()
+    }
+}
+
+impl HasScaleNames for Chord {
+    fn scale_names(&self) -> &'static [&'static str] {
+        self.known_chord().scale_names()
+    }
+}
+
+impl HasRelativeChord for Chord {
+    fn relative_chord(&self) -> Vec<Interval> {
+        let mut result = self.known_chord().relative_chord();
+        let modifiers = &self.modifiers;
+        let extensions = &self.extensions;
+
+        // Dominant extensions.
+
+        if modifiers.contains(&Modifier::Dominant(Degree::Nine)) {
+            result.push(Interval::MajorNinth);
+        } else if modifiers.contains(&Modifier::Dominant(Degree::Eleven)) {
+            result.push(Interval::MajorNinth);
+            result.push(Interval::PerfectEleventh);
+        } else if modifiers.contains(&Modifier::Dominant(Degree::Thirteen)) {
+            result.push(Interval::MajorNinth);
+            result.push(Interval::PerfectEleventh);
+            result.push(Interval::MajorThirteenth);
+        }
+
+        // Special modifiers that can also be extensions.
+
+        if modifiers.contains(&Modifier::Flat5) {
+            result.remove(2);
+            result.push(Interval::DiminishedFifth);
+        }
+
+        if modifiers.contains(&Modifier::Augmented5) {
+            result.remove(2);
+            result.push(Interval::AugmentedFifth);
+        }
+
+        if modifiers.contains(&Modifier::Flat9) {
+            result.push(Interval::MinorNinth);
+        }
+
+        if modifiers.contains(&Modifier::Sharp9) {
+            result.push(Interval::AugmentedNinth);
+        }
+
+        if modifiers.contains(&Modifier::Sharp11) {
+            result.push(Interval::AugmentedEleventh);
+        }
+
+        // Extensions.
+
+        if extensions.contains(&Extension::Sus2) {
+            result.remove(1);
+            result.push(Interval::MajorSecond);
+        }
+
+        if extensions.contains(&Extension::Sus4) {
+            result.remove(1);
+            result.push(Interval::PerfectFourth);
+        }
+
+        if extensions.contains(&Extension::Flat11) {
+            result.push(Interval::DiminishedEleventh);
+        }
+
+        if extensions.contains(&Extension::Flat13) {
+            result.push(Interval::MinorThirteenth);
+        }
+
+        if extensions.contains(&Extension::Sharp13) {
+            result.push(Interval::AugmentedThirteenth);
+        }
+
+        if extensions.contains(&Extension::Add2) {
+            result.push(Interval::MajorSecond);
+        }
+
+        if extensions.contains(&Extension::Add4) {
+            result.push(Interval::PerfectFourth);
+        }
+
+        if extensions.contains(&Extension::Add6) {
+            result.push(Interval::MajorSixth);
+        }
+
+        if extensions.contains(&Extension::Add9) {
+            result.push(Interval::MajorNinth);
+        }
+
+        if extensions.contains(&Extension::Add11) {
+            result.push(Interval::PerfectEleventh);
+        }
+
+        if extensions.contains(&Extension::Add13) {
+            result.push(Interval::MajorThirteenth);
+        }
+
+        // Omissions.
+
+        if self.omissions.contains(&Omission::No3) {
+            result.retain(|i| !matches!(i, Interval::MinorThird | Interval::MajorThird));
+        }
+
+        if self.omissions.contains(&Omission::No5) {
+            result.retain(|i| !matches!(i, Interval::DiminishedFifth | Interval::PerfectFifth | Interval::AugmentedFifth));
+        }
+
+        // Keep everything in order.
+        result.sort();
+        result.dedup();
+
+        result
+    }
+}
+
+impl HasScale for Chord {
+    fn scale(&self) -> Vec<Note> {
+        self.relative_scale().into_iter().map(|i| self.root + i).collect()
+    }
+}
+
+impl HasChord for Chord {
+    fn chord(&self) -> Vec<Note> {
+        let mut result: Vec<_> = self.relative_chord().into_iter().map(|i| self.root + i).collect();
+
+        // Perform inversions (notes that would move above the highest octave stay in it).
+        for _ in 0..self.inversion {
+            let mut note = result.remove(0);
+
+            while note < *result.last().unwrap_or(&CZero) {
+                let Some(octave) = note.octave().checked_add(1) else {
+                    break;
+                };
+
+                note = note.with_octave(octave);
+            }
+
+            result.push(note);
+        }
+
+        // If this chord is crunchy, bring all "octave" intervals down to the first octave frame.
+        if self.is_crunchy {
+            let bottom = *result.first().unwrap_or(&CZero);
+            let top = bottom.with_octave(bottom.octave().saturating_add(1));
+
+            for note in &mut result {
+                while *note > top {
+                    let Some(octave) = note.octave().checked_sub(1) else {
+                        break;
+                    };
+
+                    *note = note.with_octave(octave);
+                }
+            }
+        }
+
+        // Add slash note.
+        if let Some(mut slash) = self.slash {
+            // Fix slash note (it should be less than, or equal to, one octave away from the bottom tone, without going below the lowest octave),
+            // unless its octave is pinned.
+            if !self.is_slash_pinned {
+                let bottom = *result.first().unwrap_or(&CZero);
+                let floor = Note::new(bottom.named_pitch(), bottom.octave().saturating_sub(1));
+
+                slash = slash.with_octave(Octave::Zero);
+                while slash < floor {
+                    slash += Interval::PerfectOctave;
+                }
+            }
+
+            result.insert(0, slash);
+        }
+
+        // Crunchiness, etc. can introduce changes, so resort, and dedup.
+        result.sort();
+        result.dedup();
+
+        result
+    }
+}
+
+impl HasDomninantDegree for Chord {
+    fn dominant_degree(&self) -> Option<Degree> {
+        let modifiers = &self.modifiers;
+        if !modifiers.contains(&Modifier::Dominant(Degree::Seven))
+            && !modifiers.contains(&Modifier::Dominant(Degree::Nine))
+            && !modifiers.contains(&Modifier::Dominant(Degree::Eleven))
+            && !modifiers.contains(&Modifier::Dominant(Degree::Thirteen))
+        {
+            return None;
+        }
+
+        Some(match modifiers.iter().find(|m| m.is_dominant()) {
+            Some(Modifier::Dominant(d)) => *d,
+            _ => Degree::Seven,
+        })
+    }
+}
+
+impl Parsable for Chord {
+    fn parse(input: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let root = ChordParser::parse(Rule::chord, input).map_err(|e| ParseError::from_pest(input, e))?.next().unwrap();
+
+        assert_eq!(Rule::chord, root.as_rule());
+
+        let mut components = root.into_inner();
+
+        let note = components.next().unwrap();
+
+        assert_eq!(Rule::note, note.as_rule());
+
+        let root_note = note_str_to_note(note.as_str()).map_err(|e| ParseError::new(input, note.as_span().start(), e.to_string()))?;
+
+        let mut result = Chord::new(root_note);
+
+        while let Some(component) = components.next() {
+            match component.as_rule() {
+                Rule::maj7_modifier => {
+                    result = result.major7();
+                }
+                Rule::minor => {
+                    result = result.minor();
+                }
+                Rule::augmented => {
+                    result = result.augmented();
+                }
+                Rule::diminished => {
+                    result = result.diminished();
+                }
+                Rule::half_diminished => {
+                    result = result.half_diminished();
+                }
+                Rule::power => {
+                    result = result.power();
+                }
+                Rule::dominant_modifier => match component.as_str() {
+                    "7" => {
+                        result = result.seven();
+                    }
+                    "9" => {
+                        result = result.nine();
+                    }
+                    "11" => {
+                        result = result.eleven();
+                    }
+                    "13" => {
+                        result = result.thirteen();
+                    }
+                    _ => {
+                        return Err(ParseError::new(input, component.as_span().start(), rule_hint(Rule::dominant_modifier)).into());
+                    }
+                },
+                Rule::modifier => match component.as_str() {
+                    "sus2" => {
+                        result = result.sus2();
+                    }
+                    "sus4" => {
+                        result = result.sus4();
+                    }
+                    "add2" => {
+                        result = result.add2();
+                    }
+                    "add4" => {
+                        result = result.add4();
+                    }
+                    "add6" | "6" => {
+                        result = result.add6();
+                    }
+                    "b5" | "♭5" => {
+                        result = result.flat5();
+                    }
+                    "#5" | "♯5" => {
+                        result = result.augmented();
+                    }
+                    "add9" => {
+                        result = result.add9();
+                    }
+                    "b9" | "♭9" => {
+                        result = result.flat9();
+                    }
+                    "#9" | "♯9" => {
+                        result = result.sharp9();
+                    }
+                    "add11" => {
+                        result = result.add11();
+                    }
+                    "b11" | "♭11" => {
+                        result = result.flat11();
+                    }
+                    "#11" | "♯11" => {
+                        result = result.sharp11();
+                    }
+                    "add13" => {
+                        result = result.add13();
+                    }
+                    "b13" | "♭13" => {
+                        result = result.flat13();
+                    }
+                    "#13" | "♯13" => {
+                        result = result.sharp13();
+                    }
+                    "no3" => {
+                        result = result.no3();
+                    }
+                    "no5" => {
+                        result = result.no5();
+                    }
+                    _ => {
+                        return Err(ParseError::new(input, component.as_span().start(), rule_hint(Rule::modifier)).into());
+                    }
+                },
+                Rule::slash => {
+                    let mut note_with_octave = components.next().unwrap().into_inner();
+
+                    let note = note_with_octave.next().unwrap();
+                    let note = note_str_to_note(note.as_str()).map_err(|e| ParseError::new(input, note.as_span().start(), e.to_string()))?;
+
+                    // An explicit octave pins the slash note (e.g., `C/E2`).
+                    result = match note_with_octave.next() {
+                        Some(octave) => {
+                            let octave = octave_str_to_octave(octave.as_str()).map_err(|e| ParseError::new(input, octave.as_span().start(), e.to_string()))?;
+
+                            result.with_pinned_slash(note.with_octave(octave))
+                        }
+                        None => result.with_slash(note),
+                    };
+                }
+                Rule::at => {
+                    let octave = components.next().unwrap();
+                    let octave = octave_str_to_octave(octave.as_str()).map_err(|e| ParseError::new(input, octave.as_span().start(), e.to_string()))?;
+
+                    result = result.with_octave(octave);
+                }
+                Rule::hat => {
+                    let inversion = components.next().unwrap();
+                    let inversion = inversion
+                        .as_str()
+                        .parse::<u8>()
+                        .map_err(|_| ParseError::new(input, inversion.as_span().start(), "an inversion from 0 to 255"))?;
+
+                    result = result.with_inversion(inversion);
+                }
+                Rule::bang => {
+                    result = result.with_crunchy(true);
+                }
+                Rule::EOI => {}
+                _ => {
+                    unreachable!();
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "audio")]
+use super::{
+    base::{enveloped_tone_source, tone_source, Envelope, Playable, PlaybackHandle, StrumDirection, Void, Waveform, WaveformSource},
+    helpers::{next_random, write_wav},
+};
+
+#[cfg(feature = "audio")]
+impl Playable for Chord {
+    #[coverage(off)]
+    fn play_with(&self, waveform: Waveform, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle> {
+        let chord_tones = self.chord();
+
+        if length.as_secs_f32() <= chord_tones.len() as f32 * delay.as_secs_f32() {
+            return Err(anyhow::Error::msg(
+                "The delay is too long for the length of play (i.e., the number of chord tones times the delay is longer than the length).",
+            ));
+        }
+
+        let delays = self.strum_delays(StrumDirection::Up, delay, Duration::ZERO, Some(0));
+
+        play_with_delays(&self.frequencies(), &delays, |f, d| tone_source(waveform, f, d, length, fade_in))
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Chord {
+    /// Returns the delay before each chord tone (in [`HasChord::chord`] order) when the chord is strummed.
+    ///
+    /// Consecutive tones are `delay` apart, starting from the lowest tone for an up-strum, or the highest tone for a down-strum.
+    /// Each delay is then "humanized" by a random amount between zero and `jitter`, which is reproducible when a `seed` is given.
+    pub fn strum_delays(&self, direction: StrumDirection, delay: Duration, jitter: Duration, seed: Option<u64>) -> Vec<Duration> {
+        use std::hash::{BuildHasher, Hasher};
+
+        let count = self.chord().len() as u32;
+        let mut state = seed.unwrap_or_else(|| std::collections::hash_map::RandomState::new().build_hasher().finish());
+
+        (0..count)
+            .map(|k| {
+                let position = match direction {
+                    StrumDirection::Up => k,
+                    StrumDirection::Down => count - 1 - k,
+                };
+
+                delay * position + jitter.mul_f32(next_random(&mut state))
+            })
+            .collect()
+    }
+
+    /// Plays the chord (as a sine wave) strummed in the given direction, with each tone delayed per [`Chord::strum_delays`].
+    #[coverage(off)]
+    #[must_use = "Dropping the PlayableResult will stop the playback."]
+    pub fn play_strummed(&self, direction: StrumDirection, delay: Duration, length: Duration, fade_in: Duration, jitter: Duration, seed: Option<u64>) -> Res<PlaybackHandle> {
+        let delays = self.strum_delays(direction, delay, jitter, seed);
+
+        if delays.iter().any(|d| *d >= length) {
+            return Err(anyhow::Error::msg(
+                "The strum is too long for the length of play (i.e., the delay, plus the jitter, of some chord tone is longer than the length).",
+            ));
+        }
+
+        play_with_delays(&self.frequencies(), &delays, |f, d| tone_source(Waveform::Sine, f, d, length, fade_in))
+    }
+
+    /// Plays the chord with the given [`Waveform`], shaping each tone with the given [`Envelope`] (instead of a linear fade in).
+    ///
+    /// Each tone is released at `length`, so the playback continues for the envelope's release after `length`.
+    #[coverage(off)]
+    #[must_use = "Dropping the PlayableResult will stop the playback."]
+    pub fn play_with_envelope(&self, waveform: Waveform, delay: Duration, length: Duration, envelope: Envelope) -> Res<PlaybackHandle> {
+        let chord_tones = self.chord();
+
+        if length.as_secs_f32() <= chord_tones.len() as f32 * delay.as_secs_f32() {
+            return Err(anyhow::Error::msg(
+                "The delay is too long for the length of play (i.e., the number of chord tones times the delay is longer than the length).",
+            ));
+        }
+
+        let delays = self.strum_delays(StrumDirection::Up, delay, Duration::ZERO, Some(0));
+
+        play_with_delays(&self.frequencies(), &delays, |f, d| enveloped_tone_source(waveform, f, d, length, envelope))
+    }
+
+    /// Plays the chord (as a sine wave) with the frequencies of the given [`Tuning`] (e.g., `A4` at 432 Hz), as with [`Playable::play`].
+    #[coverage(off)]
+    #[must_use = "Dropping the PlayableResult will stop the playback."]
+    pub fn play_with_tuning(&self, tuning: &Tuning, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle> {
+        let (frequencies, delays) = self.tuned_tones(tuning, delay, length)?;
+
+        play_with_delays(&frequencies, &delays, |f, d| tone_source(Waveform::Sine, f, d, length, fade_in))
+    }
+
+    /// Returns the frequencies (in the given [`Tuning`]) and delays of the tones that [`Chord::play_with_tuning`] feeds to its sources.
+    fn tuned_tones(&self, tuning: &Tuning, delay: Duration, length: Duration) -> Res<(Vec<f32>, Vec<Duration>)> {
+        let frequencies = self.frequencies_with(tuning);
+
+        if length.as_secs_f32() <= frequencies.len() as f32 * delay.as_secs_f32() {
+            return Err(anyhow::Error::msg(
+                "The delay is too long for the length of play (i.e., the number of chord tones times the delay is longer than the length).",
+            ));
+        }
+
+        let delays = self.strum_delays(StrumDirection::Up, delay, Duration::ZERO, Some(0));
+
+        Ok((frequencies, delays))
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Chord {
+    /// Renders the chord (as sine waves) to a mono 16-bit PCM WAV file.
+    pub fn render_wav(&self, path: &Path, length: Duration, sample_rate: u32) -> Void {
+        self.render_wav_with(path, Waveform::Sine, length, Duration::ZERO, sample_rate)
+    }
+
+    /// Renders the chord to a mono 16-bit PCM WAV file with the given [`Waveform`].
+    ///
+    /// The `fade` is applied to both the start and the end of the rendered audio to avoid clicks.
+    pub fn render_wav_with(&self, path: &Path, waveform: Waveform, length: Duration, fade: Duration, sample_rate: u32) -> Void {
+        use rodio::Source;
+
+        if sample_rate == 0 {
+            return Err(anyhow::Error::msg("The sample rate must be greater than zero."));
+        }
+
+        if length.as_secs_f32() < 2.0 * fade.as_secs_f32() {
+            return Err(anyhow::Error::msg(
+                "The fade is too long for the length of the rendering (i.e., twice the fade is longer than the length).",
+            ));
+        }
+
+        let frame_count = (length.as_secs_f64() * sample_rate as f64).round() as usize;
+        let fade_frames = (fade.as_secs_f64() * sample_rate as f64).round() as usize;
+
+        // Each tone uses the same source (and gain) as playback.
+        let tones = self
+            .frequencies()
+            .into_iter()
+            .map(|f| {
+                WaveformSource::with_sample_rate(waveform, f, sample_rate)
+                    .amplify(waveform.gain())
+                    .take(frame_count)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let samples = (0..frame_count)
+            .map(|k| {
+                // Mix the tones, scaled by the number of tones, so that the mix cannot clip.
+                let value = tones.iter().map(|t| t[k]).sum::<f32>() / tones.len().max(1) as f32;
+
+                let envelope = if k < fade_frames {
+                    k as f32 / fade_frames as f32
+                } else if frame_count - k <= fade_frames {
+                    (frame_count - k - 1) as f32 / fade_frames as f32
+                } else {
+                    1.0
+                };
+
+                value * envelope
+            })
+            .collect::<Vec<_>>();
+
+        write_wav(path, &samples, sample_rate)
+    }
+}
+
+// Helpers.
+
+/// Plays the given chord tones, each delayed by the corresponding delay, using the source built by `tone` (from the frequency and delay).
+#[cfg(feature = "audio")]
+#[coverage(off)]
+fn play_with_delays<F, S>(frequencies: &[f32], delays: &[Duration], tone: F) -> Res<PlaybackHandle>
+where
+    F: Fn(f32, Duration) -> S,
+    S: rodio::Source<Item = f32> + Send + 'static,
+{
+    use rodio::{OutputStream, Sink};
+
+    let (stream, stream_handle) = OutputStream::try_default()?;
+
+    let mut sinks = vec![];
+
+    for (f, d) in frequencies.iter().zip(delays) {
+        let sink = Sink::try_new(&stream_handle)?;
+
+        let source = tone(*f, *d);
+
+        sink.append(source);
+
+        sinks.push(sink);
+    }
+
+    Ok(PlaybackHandle::new(stream, stream_handle, sinks))
+}
+
+/// Classifies the chord's modifiers as a [`KnownChord`] (see [`HasKnownChord::known_chord`], which caches the result).
+fn classify_known_chord(chord: &Chord) -> KnownChord {
+    let modifiers = &chord.modifiers;
+    let degree = chord.dominant_degree();
+
+    let contains_dominant = degree.is_some();
+    let degree = degree.unwrap_or(Degree::Seven);
+
+    if modifiers.contains(&Modifier::Diminished) {
+        KnownChord::Diminished
+    } else if modifiers.contains(&Modifier::Minor) {
+        if modifiers.contains(&Modifier::Major7) {
+            return KnownChord::MinorMajor7;
+        }
+
+        if contains_dominant {
+            if modifiers.contains(&Modifier::Flat5) {
+                return KnownChord::HalfDiminished(degree);
+            }
+
+            return KnownChord::MinorDominant(degree);
+        }
+
+        return KnownChord::Minor;
+    } else {
+        if modifiers.contains(&Modifier::Augmented5) {
+            if modifiers.contains(&Modifier::Major7) {
+                return KnownChord::AugmentedMajor7;
+            }
+
+            if contains_dominant {
+                return KnownChord::AugmentedDominant(degree);
+            }
+
+            return KnownChord::Augmented;
+        }
+
+        if chord.modifiers.contains(&Modifier::Major7) {
+            return KnownChord::Major7;
+        }
+
+        if contains_dominant {
+            if modifiers.contains(&Modifier::Flat9) {
+                return KnownChord::DominantFlat9(degree);
+            }
+
+            if modifiers.contains(&Modifier::Sharp9) {
+                return KnownChord::DominantSharp9(degree);
+            }
+
+            if modifiers.contains(&Modifier::Sharp11) {
+                return KnownChord::DominantSharp11(degree);
+            }
+
+            return KnownChord::Dominant(degree);
+        }
+
+        return KnownChord::Major;
+    }
+}
+
+/// Removes the extensions and modifiers that are expressed elsewhere in the chord (e.g., `add9` on a dominant 9 chord).
+fn remove_redundant_modifiers(c: &mut Chord) {
+    let dominant_degree = c.dominant_degree();
+
+    if let Some(degree) = dominant_degree {
+        match degree {
+            Degree::Nine => {
+                c.extensions.remove(&Extension::Add9);
+            }
+            Degree::Eleven => {
+                c.extensions.remove(&Extension::Add9);
+                c.extensions.remove(&Extension::Add11);
+            }
+            Degree::Thirteen => {
+                c.extensions.remove(&Extension::Add9);
+                c.extensions.remove(&Extension::Add11);
+                c.extensions.remove(&Extension::Add13);
+            }
+            Degree::Seven => {}
+        }
+    }
+
+    if c.modifiers.contains(&Modifier::Diminished) {
+        let modifiers = c.modifiers_mut();
+
+        modifiers.remove(&Modifier::Minor);
+        modifiers.remove(&Modifier::Flat5);
+        modifiers.remove(&Modifier::Augmented5);
+    }
+}
+
+/// Returns the factor (in `(0, 1]`) that scales down the score of a guessed chord by its [complexity](Chord::complexity_score),
+/// as used by [`Chord::try_from_notes_ranked`] and [`Chord::try_from_notes_partial`].
+fn simplicity(chord: &Chord) -> f32 {
+    1.0 / (1.0 + 0.15 * chord.complexity_score() as f32)
+}
+
+/// Returns a bitmask of the pitch classes (bit `k` is set for the pitch `k` semitones above C).
+fn pitch_class_mask(pitches: impl IntoIterator<Item = Pitch>) -> u16 {
+    pitches.into_iter().fold(0, |mask, pitch| mask | 1 << pitch as u8)
+}
+
+/// Transposes a [pitch class mask](pitch_class_mask) relative to C so that it is relative to `root`.
+fn rotate_pitch_class_mask(mask: u16, root: Pitch) -> u16 {
+    let shift = root as u8 as u32;
+
+    ((mask << shift) | (mask >> ((12 - shift) % 12))) & 0xFFF
+}
+
+/// Counts the candidates that [`Chord::try_from_notes`] builds and voices (only in tests, to verify the pruning).
+#[cfg(test)]
+fn count_candidate_evaluation() {
+    CANDIDATE_EVALUATIONS.with(|c| c.set(c.get() + 1));
+}
+
+#[cfg(not(test))]
+fn count_candidate_evaluation() {}
+
+/// Returns the pitches stacked in close position (in order) above the given MIDI note number (spelled with flats).
+fn stack_pitches(pitches: &[Pitch], above: u8) -> Vec<Note> {
+    let mut previous = above as i16;
+
+    pitches
+        .iter()
+        .filter_map(|p| {
+            previous = lowest_position_at_or_above(*p as i16, previous + 1);
+
+            Octave::try_from_i8((previous / 12 - 1) as i8).map(|octave| Note::new(p.into(), octave))
+        })
+        .collect()
+}
+
+/// Returns the lowest MIDI note number, at or above the floor, that is in the same pitch class as the given MIDI note number.
+fn lowest_position_at_or_above(midi: i16, floor: i16) -> i16 {
+    floor + (midi - floor).rem_euclid(12)
+}
+
+/// Returns the note transposed by the given number of semitones (up, if positive, or down, if negative).
+///
+/// The note is moved by the most common intervals, and then [respelled](Note::respell) to avoid double accidentals.  Returns
+/// `None` if the note would be out of the octave range.
+fn transpose_note_semitones(note: Note, semitones: i8) -> Option<Note> {
+    let octaves = semitones.unsigned_abs() / 12;
+    let interval = Interval::from_semitones(semitones.unsigned_abs() % 12).unwrap();
+
+    let mut result = note;
+
+    for interval in std::iter::repeat(Interval::PerfectOctave).take(octaves as usize).chain(std::iter::once(interval)) {
+        result = if semitones >= 0 { result.checked_add(interval)? } else { result.checked_sub(interval)? };
+    }
+
+    Some(result.respell(Accidental::Natural))
+}
+
+/// Returns the MusicXML step (letter) and alter (number of semitones) for a named pitch.
+fn musicxml_step_and_alter(named_pitch: NamedPitch) -> (&'static str, i8) {
+    // Named pitches are ordered by the circle of fifths, in groups of seven per accidental (starting at triple flat).
+    let alter = (named_pitch as u8 / 7) as i8 - 3;
+
+    (named_pitch.letter(), alter)
+}
+
+/// Returns the space separated LilyPond spellings of the notes.
+fn lilypond_sequence(notes: &[Note]) -> String {
+    notes.iter().map(Note::to_lilypond).collect::<Vec<_>>().join(" ")
+}
+
+/// Returns the MusicXML harmony kind for a known chord.
+fn musicxml_kind(known_chord: KnownChord) -> &'static str {
+    match known_chord {
+        KnownChord::Unknown => "other",
+        KnownChord::Major => "major",
+        KnownChord::Minor => "minor",
+        KnownChord::Major7 => "major-seventh",
+        KnownChord::Dominant(Degree::Seven) => "dominant",
+        KnownChord::Dominant(Degree::Nine) => "dominant-ninth",
+        KnownChord::Dominant(Degree::Eleven) => "dominant-11th",
+        KnownChord::Dominant(Degree::Thirteen) => "dominant-13th",
+        KnownChord::MinorMajor7 => "major-minor",
+        KnownChord::MinorDominant(Degree::Seven) => "minor-seventh",
+        KnownChord::MinorDominant(Degree::Nine) => "minor-ninth",
+        KnownChord::MinorDominant(Degree::Eleven) => "minor-11th",
+        KnownChord::MinorDominant(Degree::Thirteen) => "minor-13th",
+        KnownChord::DominantSharp11(_) => "dominant",
+        KnownChord::Augmented => "augmented",
+        KnownChord::AugmentedMajor7 => "other",
+        KnownChord::AugmentedDominant(_) => "augmented-seventh",
+        KnownChord::HalfDiminished(_) => "half-diminished",
+        KnownChord::Diminished => "diminished",
+        KnownChord::DominantFlat9(_) => "dominant",
+        KnownChord::DominantSharp9(_) => "dominant",
+    }
+}
+
+impl Default for Chord {
+    fn default() -> Self {
+        Chord::new(super::note::C)
+    }
+}
+
+// Statics.
+
+#[cfg(test)]
+thread_local! {
+    static CANDIDATE_EVALUATIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+// Tests.
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::core::{note::*, octave::HasOctave};
+    use pretty_assertions::assert_eq;
+
+    /// Asserts that the given XML fragment is well-formed (i.e., every tag is properly closed and nested).
+    pub(crate) fn assert_well_formed_xml(xml: &str) {
+        let mut stack = Vec::new();
+        let mut rest = xml;
+
+        while let Some(start) = rest.find('<') {
+            assert!(!rest[..start].contains('>'), "Stray `>` in text.");
+
+            let end = rest[start..].find('>').expect("Unclosed tag.") + start;
+            let tag = &rest[start + 1..end];
+
+            if tag.starts_with('?') || tag.ends_with('/') {
+                // Declarations and self-closing tags do not nest.
+            } else if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(stack.pop(), Some(name), "Mismatched closing tag.");
+            } else {
+                stack.push(tag.split(' ').next().unwrap());
+            }
+
+            rest = &rest[end + 1..];
+        }
+
+        assert!(!rest.contains('>'), "Stray `>` in text.");
+        assert!(stack.is_empty(), "Unclosed elements: {:?}.", stack);
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(Chord::new(C).flat9().sharp9().sharp11().add13().with_slash(E).name(), "C(♭9)(♯9)(♯11)(add13)/E");
+        assert_eq!(Chord::new(C).flat5().name(), "C(♭5)");
+        assert_eq!(Chord::new(C).minor().augmented().name(), "Cm(♯5)");
+        assert_eq!(Chord::new(C).with_octave(Octave::Six).precise_name(), "C@6");
+
+        assert_eq!(
+            format!("{}", Chord::new(C).minor().seven().flat_five()),
+            "Cm7(♭5)\n   half diminished, locrian, minor seven flat five, seventh mode of major scale, major scale starting one half step up\n   C, D, E♭, F, G♭, A♭, B♭\n   C, E♭, G♭, B♭"
+        );
+    }
+
+    #[test]
+    fn test_properties() {
+        assert_eq!(Chord::new(C).seven().flat9().root(), C);
+        assert_eq!(Chord::new(C).with_slash(E).slash(), E);
+        assert_eq!(Chord::new(C).slash(), C);
+        assert_eq!(Chord::new(C).flat9().add13().with_slash(E).modifiers(), &vec![Modifier::Flat9].into_iter().collect::<HashSet<_>>());
+        assert_eq!(Chord::new(C).flat9().add13().with_slash(E).extensions(), &vec![Extension::Add13].into_iter().collect::<HashSet<_>>());
+        assert_eq!(Chord::new(C).flat9().add13().with_slash(E).seven().dominant_degree(), Some(Degree::Seven));
+        assert_eq!(Chord::new(C).flat9().add13().with_slash(E).nine().dominant_degree(), Some(Degree::Nine));
+        assert_eq!(Chord::new(C).flat9().with_inversion(1).inversion(), 1);
+        assert_eq!(Chord::new(C).flat9().with_octave(Octave::Three).root().octave(), Octave::Three);
+    }
+
+    #[test]
+    fn test_known_chords() {
+        assert_eq!(Chord::new(C).known_chord(), KnownChord::Major);
+        assert_eq!(Chord::new(C).minor().known_chord(), KnownChord::Minor);
+        assert_eq!(Chord::new(C).major7().known_chord(), KnownChord::Major7);
+        assert_eq!(Chord::new(C).minor().major7().known_chord(), KnownChord::MinorMajor7);
+        assert_eq!(Chord::new(C).minor().dominant(Degree::Seven).known_chord(), KnownChord::MinorDominant(Degree::Seven));
+        assert_eq!(Chord::new(C).minor().eleven().known_chord(), KnownChord::MinorDominant(Degree::Eleven));
+        assert_eq!(Chord::new(C).seven().known_chord(), KnownChord::Dominant(Degree::Seven));
+        assert_eq!(Chord::new(C).eleven().known_chord(), KnownChord::Dominant(Degree::Eleven));
+        assert_eq!(Chord::new(C).thirteen().known_chord(), KnownChord::Dominant(Degree::Thirteen));
+        assert_eq!(Chord::new(C).diminished().known_chord(), KnownChord::Diminished);
+        assert_eq!(Chord::new(C).dim().known_chord(), KnownChord::Diminished);
+        assert_eq!(Chord::new(C).minor().seven().flat5().known_chord(), KnownChord::HalfDiminished(Degree::Seven));
+        assert_eq!(Chord::new(C).augmented().known_chord(), KnownChord::Augmented);
+        assert_eq!(Chord::new(C).aug().major7().known_chord(), KnownChord::AugmentedMajor7);
+        assert_eq!(Chord::new(C).augmented().seven().known_chord(), KnownChord::AugmentedDominant(Degree::Seven));
+        assert_eq!(Chord::new(C).seven().sharp11().known_chord(), KnownChord::DominantSharp11(Degree::Seven));
+        assert_eq!(Chord::new(C).seven().flat9().known_chord(), KnownChord::DominantFlat9(Degree::Seven));
+        assert_eq!(Chord::new(C).seven().sharp9().known_chord(), KnownChord::DominantSharp9(Degree::Seven));
+
+        assert_eq!(Chord::new(C).sus2().known_chord(), KnownChord::Major);
+        assert_eq!(Chord::new(C).sus4().known_chord(), KnownChord::Major);
+        assert_eq!(Chord::new(C).sustain().known_chord(), KnownChord::Major);
+        assert_eq!(Chord::new(C).seven().sus().known_chord(), KnownChord::Dominant(Degree::Seven));
+    }
+
+    #[test]
+    fn test_known_chord_cache() {
+        let chords = Chord::all_known_qualities(C)
+            .into_iter()
+            .chain(["Cm7b5", "C7#9", "Cmaj7#11", "C+7", "Cdim", "Cm9b5/E", "C7(b9)"].iter().map(|s| Chord::parse(s).unwrap()))
+            .chain(Chord::try_from_notes(&[C, EFlat, GFlat, A]).unwrap())
+            .collect::<Vec<_>>();
+
+        for chord in &chords {
+            let fresh = classify_known_chord(chord);
+
+            assert_eq!(chord.known_chord(), fresh);
+            assert_eq!(chord.known_chord(), fresh);
+            assert_eq!(chord.clone().known_chord(), fresh);
+
+            // The cache does not affect equality.
+            assert_eq!(
+                *chord,
+                Chord {
+                    known_chord: KnownChordCache::default(),
+                    ..chord.clone()
+                }
+            );
+        }
+
+        // Changing the modifiers resets the cache.
+        let chord = Chord::new(C).seven();
+
+        assert_eq!(chord.known_chord(), KnownChord::Dominant(Degree::Seven));
+        assert_eq!(chord.clone().minor().known_chord(), KnownChord::MinorDominant(Degree::Seven));
+        assert_eq!(chord.clone().augmented().known_chord(), KnownChord::AugmentedDominant(Degree::Seven));
+        assert_eq!(chord.flat9().known_chord(), KnownChord::DominantFlat9(Degree::Seven));
+
+        let mut chord = Chord::new(C).minor().seven().with_modifier(Modifier::Diminished);
+
+        assert_eq!(chord.known_chord(), classify_known_chord(&chord));
+        remove_redundant_modifiers(&mut chord);
+        assert_eq!(chord.known_chord(), classify_known_chord(&chord));
+    }
+
+    #[test]
+    fn test_scales() {
+        // Basic.
+
+        assert_eq!(Chord::new(C).scale(), vec![C, D, E, F, G, A, B]);
+        assert_eq!(Chord::new(C).minor().scale(), vec![C, D, EFlat, F, G, AFlat, BFlat]);
+        assert_eq!(Chord::new(C).major_seven().scale(), vec![C, D, E, F, G, A, B]);
+        assert_eq!(Chord::new(C).minor().maj7().scale(), vec![C, D, EFlat, F, G, A, B]);
+        assert_eq!(Chord::new(C).minor().seven().scale(), vec![C, D, EFlat, F, G, A, BFlat]);
+        assert_eq!(Chord::new(C).minor().eleven().scale(), vec![C, D, EFlat, F, G, A, BFlat]);
+        assert_eq!(Chord::new(C).seven().scale(), vec![C, D, E, F, G, A, BFlat]);
+        assert_eq!(Chord::new(C).eleven().scale(), vec![C, D, E, F, G, A, BFlat]);
+        assert_eq!(Chord::new(C).thirteen().scale(), vec![C, D, E, F, G, A, BFlat]);
+        assert_eq!(Chord::new(C).diminished().scale(), vec![C, D, EFlat, F, GFlat, AFlat, BDoubleFlat, B]);
+        assert_eq!(Chord::new(C).dim().scale(), vec![C, D, EFlat, F, GFlat, AFlat, BDoubleFlat, B]);
+        assert_eq!(Chord::new(C).minor().seven().flat5().scale(), vec![C, D, EFlat, F, GFlat, AFlat, BFlat]);
+        assert_eq!(Chord::new(C).augmented().scale(), vec![C, D, E, F, GSharp, A, B]);
+        assert_eq!(Chord::new(C).augmented().major7().scale(), vec![C, D, E, FSharp, GSharp, A, B]);
+        assert_eq!(Chord::new(C).augmented().seven().scale(), vec![C, D, E, FSharp, GSharp, ASharp]);
+        assert_eq!(Chord::new(C).seven().sharp_eleven().scale(), vec![C, D, E, FSharp, G, A, BFlat]);
+        assert_eq!(Chord::new(C).seven().flat_nine().scale(), vec![C, DFlat, EFlat, E, FSharp, G, A, BFlat]);
+        assert_eq!(Chord::new(C).seven().sharp_nine().scale(), vec![C, DFlat, EFlat, FFlat, GFlat, AFlat, BFlat]);
+
+        // Others.
+
+        assert_eq!(Chord::new(DFlat).scale(), vec![DFlat, EFlat, F, GFlat, AFlat, BFlat, CFive]);
+        assert_eq!(Chord::new(DFlat).seven().scale(), vec![DFlat, EFlat, F, GFlat, AFlat, BFlat, CFlatFive]);
+        assert_eq!(Chord::new(DFlat).dim().scale(), vec![DFlat, EFlat, FFlat, GFlat, ADoubleFlat, BDoubleFlat, CDoubleFlatFive, CFive]);
+    }
+
+    #[test]
+    fn test_chords() {
+        // Basic.
+
+        assert_eq!(Chord::new(C).chord(), vec![C, E, G]);
+        assert_eq!(Chord::new(C).minor().chord(), vec![C, EFlat, G]);
+        assert_eq!(Chord::new(C).major7().chord(), vec![C, E, G, B]);
+        assert_eq!(Chord::new(C).minor().major7().chord(), vec![C, EFlat, G, B]);
+        assert_eq!(Chord::new(C).minor().seven().chord(), vec![C, EFlat, G, BFlat]);
+        assert_eq!(Chord::new(C).minor().eleven().chord(), vec![C, EFlat, G, BFlat, DFive, FFive]);
+        assert_eq!(Chord::new(C).seven().chord(), vec![C, E, G, BFlat]);
+        assert_eq!(Chord::new(C).eleven().chord(), vec![C, E, G, BFlat, DFive, FFive]);
+        assert_eq!(Chord::new(C).thirteen().chord(), vec![C, E, G, BFlat, DFive, FFive, AFive]);
+        assert_eq!(Chord::new(C).diminished().chord(), vec![C, EFlat, GFlat, BDoubleFlat]);
+        assert_eq!(Chord::new(C).dim().chord(), vec![C, EFlat, GFlat, BDoubleFlat]);
+        assert_eq!(Chord::new(C).minor().seven().flat5().chord(), vec![C, EFlat, GFlat, BFlat]);
+        assert_eq!(Chord::new(C).half_diminished().chord(), vec![C, EFlat, GFlat, BFlat]);
+        assert_eq!(Chord::new(C).half_dim().chord(), vec![C, EFlat, GFlat, BFlat]);
+        assert_eq!(Chord::new(C).augmented().chord(), vec![C, E, GSharp]);
+        assert_eq!(Chord::new(C).augmented().major7().chord(), vec![C, E, GSharp, B]);
+        assert_eq!(Chord::new(C).augmented().seven().chord(), vec![C, E, GSharp, BFlat]);
+        assert_eq!(Chord::new(C).seven().sharp11().chord(), vec![C, E, G, BFlat, FSharpFive]);
+        assert_eq!(Chord::new(C).seven().flat_nine().chord(), vec![C, E, G, BFlat, DFlatFive]);
+        assert_eq!(Chord::new(C).seven().sharp_nine().chord(), vec![C, E, G, BFlat, DSharpFive]);
+
+        // Extensions.
+
+        assert_eq!(Chord::new(C).nine().sus2().chord(), vec![C, D, G, BFlat, DFive]);
+        assert_eq!(Chord::new(C).nine().sus_two().chord(), vec![C, D, G, BFlat, DFive]);
+        assert_eq!(Chord::new(C).nine().sus4().chord(), vec![C, F, G, BFlat, DFive]);
+        assert_eq!(Chord::new(C).nine().sus_four().chord(), vec![C, F, G, BFlat, DFive]);
+        assert_eq!(Chord::new(C).nine().sustain().chord(), vec![C, F, G, BFlat, DFive]);
+        assert_eq!(Chord::new(C).seven().sus().chord(), vec![C, F, G, BFlat]);
+        assert_eq!(Chord::new(C).seven().add2().chord(), vec![C, D, E, G, BFlat]);
+        assert_eq!(Chord::new(C).seven().add_two().chord(), vec![C, D, E, G, BFlat]);
+        assert_eq!(Chord::new(C).seven().add4().chord(), vec![C, E, F, G, BFlat]);
+        assert_eq!(Chord::new(C).seven().add_four().chord(), vec![C, E, F, G, BFlat]);
+        assert_eq!(Chord::new(C).add6().chord(), vec![C, E, G, A]);
+        assert_eq!(Chord::new(C).seven().add9().chord(), vec![C, E, G, BFlat, DFive]);
+        assert_eq!(Chord::new(C).seven().add_nine().chord(), vec![C, E, G, BFlat, DFive]);
+        assert_eq!(Chord::new(C).seven().add11().chord(), vec![C, E, G, BFlat, FFive]);
+        assert_eq!(Chord::new(C).seven().add_eleven().chord(), vec![C, E, G, BFlat, FFive]);
+        assert_eq!(Chord::new(C).seven().add13().chord(), vec![C, E, G, BFlat, AFive]);
+        assert_eq!(Chord::new(C).seven().add_thirteen().chord(), vec![C, E, G, BFlat, AFive]);
+        assert_eq!(Chord::new(C).seven().add2().add4().chord(), vec![C, D, E, F, G, BFlat]);
+        assert_eq!(Chord::new(C).seven().add6().chord(), vec![C, E, G, A, BFlat]);
+        assert_eq!(Chord::new(C).seven().add_six().chord(), vec![C, E, G, A, BFlat]);
+        assert_eq!(Chord::new(C).seven().flat11().chord(), vec![C, E, G, BFlat, FFlatFive]);
+        assert_eq!(Chord::new(C).seven().flat_eleven().chord(), vec![C, E, G, BFlat, FFlatFive]);
+        assert_eq!(Chord::new(C).seven().flat13().chord(), vec![C, E, G, BFlat, AFlatFive]);
+        assert_eq!(Chord::new(C).seven().flat_thirteen().chord(), vec![C, E, G, BFlat, AFlatFive]);
+        assert_eq!(Chord::new(C).seven().sharp13().chord(), vec![C, E, G, BFlat, ASharpFive]);
+        assert_eq!(Chord::new(C).seven().sharp_thirteen().chord(), vec![C, E, G, BFlat, ASharpFive]);
+
+        // Crunchy.
+
+        assert_eq!(Chord::new(C).seven().sharp9().with_crunchy(true).chord(), vec![C, DSharp, E, G, BFlat]);
+
+        // Slashes.
+
+        assert_eq!(Chord::new(C).with_slash(D).chord(), vec![DThree, C, E, G]);
+        assert_eq!(Chord::new(CFive).with_slash(D).chord(), vec![DFour, CFive, EFive, GFive]);
+
+        // Inversions.
+
+        assert_eq!(C.into_chord().with_inversion(1).chord(), vec![E, G, CFive]);
+        assert_eq!(C.into_chord().with_inversion(2).chord(), vec![G, CFive, EFive]);
+        assert_eq!(C.into_chord().maj7().with_inversion(3).chord(), vec![B, CFive, EFive, GFive]);
+        assert_eq!(BFlatThree.into_chord().seven().flat9().with_inversion(1).chord(), vec![D, F, AFlat, CFlatFive, BFlatFive]);
+
+        // Weird.
+        assert_eq!(C.into_chord().flat5().aug().chord(), vec![C, E, GSharp]);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Chord::parse("C").unwrap().chord(), vec![C, E, G]);
+        assert_eq!(Chord::parse("Cm").unwrap().chord(), vec![C, EFlat, G]);
+        assert_eq!(Chord::parse("Cm7").unwrap().chord(), vec![C, EFlat, G, BFlat]);
+        assert_eq!(Chord::parse("Cm7b5").unwrap().chord(), vec![C, EFlat, GFlat, BFlat]);
+        assert_eq!(Chord::parse("C7").unwrap().chord(), vec![C, E, G, BFlat]);
+        assert_eq!(Chord::parse("C7b9").unwrap().chord(), vec![C, E, G, BFlat, DFlatFive]);
+        assert_eq!(Chord::parse("C7b9#11").unwrap().chord(), vec![C, E, G, BFlat, DFlatFive, FSharpFive]);
+        assert_eq!(Chord::parse("C(add6)").unwrap().chord(), vec![C, E, G, A]);
+        assert_eq!(Chord::parse("Em(#5)").unwrap().chord(), vec![E, G, BSharp]);
+        assert_eq!(Chord::parse("D+11").unwrap().chord(), vec![D, FSharp, ASharp, CFive, EFive, GFive]);
+        assert_eq!(Chord::parse("Dm13b5").unwrap().chord(), vec![D, F, AFlat, CFive, EFive, GFive, BFive]);
+        assert_eq!(Chord::parse("Dsus2").unwrap().chord(), vec![D, E, A]);
+        assert_eq!(Chord::parse("Dsus4").unwrap().chord(), vec![D, G, A]);
+        assert_eq!(Chord::parse("Dadd2").unwrap().chord(), vec![D, E, FSharp, A]);
+        assert_eq!(Chord::parse("Dadd4").unwrap().chord(), vec![D, FSharp, G, A]);
+        assert_eq!(Chord::parse("Dadd9").unwrap().chord(), vec![D, FSharp, A, EFive]);
+        assert_eq!(Chord::parse("Dadd11").unwrap().chord(), vec![D, FSharp, A, GFive]);
+        assert_eq!(Chord::parse("Dadd13").unwrap().chord(), vec![D, FSharp, A, BFive]);
+        assert_eq!(Chord::parse("Dm#9").unwrap().chord(), vec![D, F, A, ESharpFive]);
+        assert_eq!(Chord::parse("Dmb11").unwrap().chord(), vec![D, F, A, GFlatFive]);
+        assert_eq!(Chord::parse("D(b13)").unwrap().chord(), vec![D, FSharp, A, BFlatFive]);
+        assert_eq!(Chord::parse("D(#13)").unwrap().chord(), vec![D, FSharp, A, BSharpFive]);
+    }
+
+    #[test]
+    fn test_omissions() {
+        // Power chords.
+        let power = Chord::parse("C5").unwrap();
+
+        assert_eq!(power.chord(), vec![C, G]);
+        assert_eq!(power, Chord::new(C).power());
+        assert_eq!(power.name(), "C5");
+        assert_eq!(power.known_chord(), KnownChord::Major);
+        assert_eq!(Chord::parse("C(no3)").unwrap(), power);
+        assert_eq!(Chord::parse("F#5/C#").unwrap().chord(), vec![CSharp, FSharp, CSharpFive]);
+
+        // Omitted tones.
+        let no5 = Chord::parse("C7(no5)").unwrap();
+
+        assert_eq!(no5.chord(), vec![C, E, BFlat]);
+        assert_eq!(no5.name(), "C7(no5)");
+        assert_eq!(no5.known_chord(), KnownChord::Dominant(Degree::Seven));
+        assert_eq!(no5.omissions(), &HashSet::from([Omission::No5]));
+
+        assert_eq!(Chord::parse("Cm7b5(no3)").unwrap().chord(), vec![C, GFlat, BFlat]);
+        assert_eq!(Chord::parse("Cm7(no3)(no5)").unwrap().chord(), vec![C, BFlat]);
+        assert_eq!(Chord::parse("Cm7(no3)(no5)").unwrap().name(), "Cm7(no3)(no5)");
+        assert_eq!(Chord::parse("C+(no3)").unwrap().chord(), vec![C, GSharp]);
+
+        for symbol in ["C5", "C7(no5)", "Cm9(no3)", "G5/D"] {
+            let chord = Chord::parse(symbol).unwrap();
+
+            assert_eq!(Chord::parse(&chord.name()).unwrap(), chord);
+        }
+    }
+
+    #[test]
+    fn test_named_by_bass() {
+        let candidates = Chord::try_from_notes(&[E, G, CFive]).unwrap();
+        let first_inversion = candidates.iter().find(|c| c.precise_name() == "C^1").unwrap();
+
+        let named = first_inversion.named_by_bass();
+        assert_eq!(named.name(), "C/E");
+        assert_eq!(named.precise_name(), "C^1");
+        assert_eq!(Chord::parse(&named.precise_name()).unwrap(), named);
+        assert_eq!(named.inversion(), 1);
+        assert_eq!(named.chord(), first_inversion.chord());
+        assert_eq!(named, Chord::new(C).with_bass(E));
+
+        // Root position and slash chords are unchanged.
+        assert_eq!(Chord::new(C).named_by_bass().precise_name(), "C");
+        assert_eq!(Chord::new(C).with_slash(F).with_inversion(1).named_by_bass().precise_name(), "C/F^1");
+    }
+
+    #[test]
+    fn test_with_bass() {
+        // A chord tone in the bass is an inversion.
+        let inversion = Chord::new(C).with_bass(GThree);
+
+        assert_eq!(inversion, Chord::new(C).with_inversion(2).with_bass(G));
+        assert_eq!(inversion.inversion(), 2);
+        assert_eq!(inversion.chord(), vec![G, CFive, EFive]);
+        assert_eq!(inversion.name(), "C/G");
+        assert_eq!(Chord::new(C).seven().with_bass(BFlat).name(), "C7/B♭");
+
+        // The precise name uses the inversion number, so that it round-trips.
+        assert_eq!(inversion.precise_name(), "C^2");
+        assert_eq!(Chord::new(C).seven().with_bass(BFlat).precise_name(), "C7^3");
+        assert_eq!(Chord::new(C).with_octave(Octave::Three).with_bass(E).with_crunchy(true).precise_name(), "C@3^1!");
+
+        for chord in [
+            inversion.clone(),
+            Chord::new(C).seven().with_bass(BFlat),
+            Chord::new(C).with_octave(Octave::Three).with_bass(E).with_crunchy(true),
+        ] {
+            assert_eq!(Chord::parse(&chord.precise_name()).unwrap(), chord);
+        }
+
+        // The same inversion, without the bass note, is named by its inversion number.
+        assert_eq!(Chord::new(C).with_inversion(2).name(), "C");
+        assert_eq!(Chord::new(C).with_inversion(2).precise_name(), "C^2");
+        assert_eq!(inversion.clone().with_inversion(2).precise_name(), "C^2");
+        assert_eq!(Chord::new(C).with_bass(C).precise_name(), "C");
+
+        // A non-chord tone in the bass is a (true) slash chord.
+        let slash = Chord::new(C).with_bass(F);
+
+        assert_eq!(slash, Chord::new(C).with_slash(F));
+        assert_eq!(slash.chord(), vec![FThree, C, E, G]);
+        assert_eq!(slash.name(), "C/F");
+
+        // A slash on a chord tone doubles it below the chord, rather than inverting the chord.
+        assert_ne!(Chord::new(C).with_slash(G).chord(), inversion.chord());
+    }
+
+    #[test]
+    fn test_parse_slash_octave() {
+        // Without an octave, the slash note is placed just below the chord.
+        let auto = Chord::parse("C/E").unwrap();
 
-        let mut result = Chord::new(note_str_to_note(note.into_inner().as_str())?);
+        assert_eq!(auto.chord(), vec![EThree, C, E, G]);
+        assert_eq!(auto.precise_name(), "C/E");
 
-        while let Some(component) = components.next() {
-            match component.as_rule() {
-                Rule::maj7_modifier => {
-                    result = result.major7();
-                }
-                Rule::minor => {
-                    result = result.minor();
-                }
-                Rule::augmented => {
-                    result = result.augmented();
-                }
-                Rule::diminished => {
-                    result = result.diminished();
-                }
-                Rule::half_diminished => {
-                    result = result.half_diminished();
-                }
-                Rule::dominant_modifier => match component.as_str() {
-                    "7" => {
-                        result = result.seven();
-                    }
-                    "9" => {
-                        result = result.nine();
-                    }
-                    "11" => {
-                        result = result.eleven();
-                    }
-                    "13" => {
-                        result = result.thirteen();
-                    }
-                    _ => {
-                        unreachable!();
-                    }
-                },
-                Rule::modifier => match component.as_str() {
-                    "sus2" => {
-                        result = result.sus2();
-                    }
-                    "sus4" => {
-                        result = result.sus4();
-                    }
-                    "add2" => {
-                        result = result.add2();
-                    }
-                    "add4" => {
-                        result = result.add4();
-                    }
-                    "add6" | "6" => {
-                        result = result.add6();
-                    }
-                    "b5" | "♭5" => {
-                        result = result.flat5();
-                    }
-                    "#5" | "♯5" => {
-                        result = result.augmented();
-                    }
-                    "add9" => {
-                        result = result.add9();
-                    }
-                    "b9" | "♭9" => {
-                        result = result.flat9();
-                    }
-                    "#9" | "♯9" => {
-                        result = result.sharp9();
-                    }
-                    "add11" => {
-                        result = result.add11();
-                    }
-                    "b11" | "♭11" => {
-                        result = result.flat11();
-                    }
-                    "#11" | "♯11" => {
-                        result = result.sharp11();
-                    }
-                    "add13" => {
-                        result = result.add13();
-                    }
-                    "b13" | "♭13" => {
-                        result = result.flat13();
-                    }
-                    "#13" | "♯13" => {
-                        result = result.sharp13();
-                    }
-                    _ => {
-                        unreachable!();
+        // With an octave, the slash note is pinned.
+        let pinned = Chord::parse("C/E2").unwrap();
+
+        assert_eq!(pinned.chord(), vec![ETwo, C, E, G]);
+        assert_eq!(pinned, Chord::new(C).with_pinned_slash(ETwo));
+        assert_ne!(pinned, auto);
+        assert_eq!(pinned.name(), "C/E");
+        assert_eq!(pinned.precise_name(), "C/E2");
+        assert_eq!(Chord::parse("Cm7/Bb1@3^1!").unwrap().precise_name(), "Cm7/B♭1@3^1!");
+
+        // The precise name round-trips.
+        for symbol in ["C/E", "C/E2", "Cm7/Bb1@3^1!", "F#m/C#5@5"] {
+            let chord = Chord::parse(symbol).unwrap();
+
+            assert_eq!(Chord::parse(&chord.precise_name()).unwrap(), chord);
+        }
+    }
+
+    #[test]
+    fn test_guess() {
+        assert_eq!(
+            Chord::try_from_notes(&[EThree, C, EFlat, FSharp, ASharp, DFive]).unwrap().first().unwrap().chord(),
+            Chord::parse("Cm9b5/E").unwrap().chord()
+        );
+        assert_eq!(Chord::try_from_notes(&[C, E, G]).unwrap().first().unwrap().chord(), Chord::parse("C").unwrap().chord());
+        assert_eq!(
+            Chord::try_from_notes(&[C, E, G, BFlat, DFive, FFive]).unwrap().first().unwrap().chord(),
+            Chord::parse("C11").unwrap().chord()
+        );
+        assert_eq!(
+            Chord::try_from_notes(&[C, E, G, BFlat, DFive, FFive, AFive]).unwrap().first().unwrap().chord(),
+            Chord::parse("C13").unwrap().chord()
+        );
+        assert_eq!(Chord::try_from_notes(&[C, EFlat, GFlat, A]).unwrap().first().unwrap().chord(), Chord::parse("Cdim").unwrap().chord());
+    }
+
+    #[test]
+    fn test_guess_enharmonic_spellings() {
+        // Candidates are matched by the sounding notes (not by spelling, or by exactly equal frequencies).
+        for notes in [[C, E, G], [C, FFlat, FDoubleSharp], [CSharp, F, AFlat], [CNine, ENine, GNine]] {
+            let candidates = Chord::try_from_notes(&notes).unwrap();
+            let first = candidates.first().unwrap();
+
+            assert_eq!(first.known_chord(), KnownChord::Major);
+            assert!(notes.iter().zip(first.chord()).all(|(a, b)| a.is_enharmonic_with(&b)));
+        }
+    }
+
+    #[test]
+    fn test_guess_pruning() {
+        // The guesser without the pruning pass (i.e., building and voicing every candidate).
+        fn try_from_notes_exhaustive(notes: &[Note]) -> (Vec<Chord>, usize) {
+            let mut notes = notes.to_vec();
+            notes.sort();
+
+            let mut result = Vec::new();
+            let mut evaluations = 0;
+
+            for inversion in 0..3 {
+                let proper_root = if inversion == 0 {
+                    notes[0]
+                } else {
+                    notes[notes.len() - inversion].with_octave(notes[notes.len() - inversion].octave() - 1)
+                };
+                let proper_root_slash = if inversion == 0 { notes[1] } else { proper_root };
+
+                for mod_set in known_modifier_sets() {
+                    for mod_set2 in one_off_modifier_sets() {
+                        for ext_set in likely_extension_sets() {
+                            for is_crunchy in [false, true] {
+                                for (root, slash) in [(proper_root, None), (proper_root_slash, Some(notes[0]))] {
+                                    let mut candidate = Chord::new(root);
+                                    if let Some(slash) = slash {
+                                        candidate = candidate.with_slash(slash);
+                                    }
+                                    let candidate = candidate
+                                        .with_modifiers(mod_set)
+                                        .with_modifiers(mod_set2)
+                                        .with_extensions(ext_set)
+                                        .with_inversion(inversion as u8)
+                                        .with_crunchy(is_crunchy);
+                                    let candidate_notes = candidate.chord();
+                                    evaluations += 1;
+
+                                    if notes.len() == candidate_notes.len() && notes.iter().zip(&candidate_notes).all(|(a, b)| a.frequency() == b.frequency()) {
+                                        result.push(candidate);
+                                    }
+                                }
+                            }
+                        }
                     }
-                },
-                Rule::slash => {
-                    let note = note_str_to_note(components.next().unwrap().as_str())?;
+                }
+            }
+
+            result.iter_mut().for_each(remove_redundant_modifiers);
+            result.sort();
+            result.dedup_by(|a, b| a.modifiers == b.modifiers && a.extensions == b.extensions && a.slash == b.slash && a.inversion == b.inversion);
+
+            (result, evaluations)
+        }
+
+        let inputs: [&[Note]; 7] = [
+            &[EThree, C, EFlat, FSharp, ASharp, DFive],
+            &[C, E, G],
+            &[C, E, G, BFlat, DFive, FFive],
+            &[C, E, G, BFlat, DFive, FFive, AFive],
+            &[C, EFlat, GFlat, A],
+            &[EThree, C, G],
+            &[C, E, G, A],
+        ];
+
+        for notes in inputs {
+            CANDIDATE_EVALUATIONS.with(|c| c.set(0));
+            let pruned = Chord::try_from_notes(notes).unwrap();
+            let pruned_evaluations = CANDIDATE_EVALUATIONS.with(|c| c.get());
+
+            let (exhaustive, exhaustive_evaluations) = try_from_notes_exhaustive(notes);
+
+            // Which of `C7` and `C7!` survives the dedup depends on hash ordering (in either guesser), so ignore crunchy.
+            let same = |a: &Chord, b: &Chord| a.root == b.root && a.slash == b.slash && a.modifiers == b.modifiers && a.extensions == b.extensions && a.inversion == b.inversion;
+
+            assert_eq!(pruned.len(), exhaustive.len());
+            assert!(pruned.iter().all(|a| exhaustive.iter().any(|b| same(a, b))));
+            assert!(pruned.iter().zip(&exhaustive).take(1).all(|(a, b)| same(a, b)));
+            assert!(pruned_evaluations * 10 < exhaustive_evaluations);
+        }
+    }
+
+    #[test]
+    fn test_guess_ranked() {
+        let exact = Chord::try_from_notes_ranked(&[C, E, G]).unwrap();
+
+        assert_eq!(exact[0].0.chord(), Chord::parse("C").unwrap().chord());
+        assert_eq!(exact[0].1, 1.0);
+
+        let ambiguous = Chord::try_from_notes_ranked(&[C, E, G, A]).unwrap();
+
+        assert!(ambiguous.len() > 1);
+        assert!(ambiguous[0].1 < exact[0].1);
+        assert!(ambiguous.iter().all(|(_, c)| (0.0..=1.0).contains(c)));
+        assert!(ambiguous.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        assert!(Chord::try_from_notes_ranked(&[C, E]).is_err());
+    }
+
+    #[test]
+    fn test_guess_from_pitches() {
+        let pitches = [Pitch::C, Pitch::E, Pitch::G];
+
+        assert_eq!(Chord::try_from_pitches(&pitches).unwrap()[0], Chord::parse("C").unwrap());
+        assert_eq!(Chord::guess_from_pitches(&pitches, None).unwrap().len(), Chord::try_from_pitches(&pitches).unwrap().len());
+
+        // The bass hint yields the slash chord.
+        let guesses = Chord::guess_from_pitches(&pitches, Some(EThree)).unwrap();
+
+        assert_eq!(guesses[0].name(), "C/E");
+        assert!(guesses.iter().all(|c| c.chord()[0].pitch() == Pitch::E));
+
+        // A bass hint on the root is just the root position chord.
+        assert_eq!(Chord::guess_from_pitches(&pitches, Some(CThree)).unwrap()[0].name(), "C");
+
+        assert!(Chord::try_from_pitches(&[Pitch::C, Pitch::E]).is_err());
+    }
+
+    #[test]
+    fn test_guess_partial() {
+        // A shell voicing (missing the fifth).
+        let candidates = Chord::try_from_notes_partial(&[C, E, BFlat]).unwrap();
+
+        assert_eq!(candidates[0], Chord::parse("C7").unwrap());
+        assert!(candidates.contains(&Chord::parse("C9").unwrap()));
+        assert!(candidates.contains(&Chord::parse("C13").unwrap()));
+        assert!(candidates.iter().all(|c| c.contains_pitch(Pitch::C) && c.contains_pitch(Pitch::E) && c.contains_pitch(Pitch::BFlat)));
+
+        // A dyad is ambiguous, but every candidate contains it.
+        let candidates = Chord::try_from_notes_partial(&[C, G]).unwrap();
+
+        assert!(candidates.len() > 10);
+        assert!(candidates.iter().all(|c| c.contains_pitch(Pitch::C) && c.contains_pitch(Pitch::G)));
+
+        // A complete chord is still recognized first.
+        assert_eq!(Chord::try_from_notes_partial(&[C, E, G]).unwrap()[0], Chord::parse("C").unwrap());
+
+        assert!(Chord::try_from_notes_partial(&[]).is_err());
+    }
+
+    #[test]
+    fn test_all_known_qualities() {
+        let chords = Chord::all_known_qualities(C);
+        let known = chords.iter().map(|c| c.known_chord()).collect::<Vec<_>>();
+
+        assert_eq!(chords.len(), 14);
+        assert!(chords.iter().all(|c| c.root() == C));
+        assert!(!known.contains(&KnownChord::Unknown));
+
+        for (k, expected) in known.iter().enumerate() {
+            assert!(!known[k + 1..].contains(expected));
+        }
+
+        for expected in [
+            KnownChord::Major,
+            KnownChord::Minor,
+            KnownChord::Major7,
+            KnownChord::Diminished,
+            KnownChord::Augmented,
+            KnownChord::Dominant(Degree::Seven),
+            KnownChord::DominantFlat9(Degree::Seven),
+            KnownChord::DominantSharp9(Degree::Seven),
+            KnownChord::DominantSharp11(Degree::Seven),
+        ] {
+            assert!(known.contains(&expected));
+        }
+    }
+
+    #[test]
+    fn test_from_intervals() {
+        assert_eq!(
+            Chord::from_intervals(C, &[Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth, Interval::MinorSeventh]).unwrap(),
+            Chord::parse("C7").unwrap()
+        );
+        assert_eq!(
+            Chord::from_intervals(D, &[Interval::PerfectUnison, Interval::MinorThird, Interval::PerfectFifth]).unwrap().chord(),
+            Chord::parse("Dm").unwrap().chord()
+        );
+
+        assert!(Chord::from_intervals(C, &[Interval::PerfectUnison, Interval::MinorSecond, Interval::MajorSecond]).is_err());
+        assert!(Chord::from_intervals(C, &[Interval::PerfectUnison, Interval::MajorThird]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Must have at least three notes to guess a chord.")]
+    fn test_chord_from_notes_failure() {
+        Chord::try_from_notes(&[C, E]).unwrap();
+    }
+
+    #[test]
+    fn test_all_inversions() {
+        let inversions = Chord::parse("Cmaj7").unwrap().all_inversions();
+
+        assert_eq!(inversions.len(), 4);
+        assert_eq!(inversions.iter().map(|c| c.inversion()).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(inversions.iter().map(|c| c.chord()[0]).collect::<Vec<_>>(), vec![C, E, G, B]);
+
+        assert_eq!(Chord::parse("C9").unwrap().all_inversions().len(), 5);
+
+        let slash = Chord::parse("C/G").unwrap().all_inversions();
+
+        assert_eq!(slash.len(), 3);
+        assert!(slash.iter().all(|c| c.chord()[0].named_pitch() == NamedPitch::G));
+    }
+
+    #[test]
+    fn test_parse_many() {
+        assert_eq!(
+            Chord::parse_many("Cm7, F7  Bbmaj7,Ebmaj7\tAm7b5").unwrap(),
+            vec![
+                Chord::new(C).minor().seven(),
+                Chord::new(F).seven(),
+                Chord::new(BFlat).maj7(),
+                Chord::new(EFlat).maj7(),
+                Chord::new(A).half_dim(),
+            ]
+        );
+
+        assert_eq!(
+            Chord::parse_many("Cm7|32 F7|16, Bb").unwrap(),
+            vec![Chord::new(C).minor().seven(), Chord::new(F).seven(), Chord::new(BFlat)]
+        );
+
+        assert_eq!(
+            Chord::parse_many_timed("Cm7|32 F7|16, Bb").unwrap(),
+            vec![(Chord::new(C).minor().seven(), Some(32)), (Chord::new(F).seven(), Some(16)), (Chord::new(BFlat), None)]
+        );
+
+        assert_eq!(Chord::parse_many(" , ").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_parse_many_failure() {
+        let error = Chord::parse_many("Cm7, F7, Xyz, Bbmaj7").unwrap_err().to_string();
+
+        assert!(error.contains("`Xyz`"));
+
+        let error = Chord::parse_many_timed("Cm7|32 F7|abc").unwrap_err().to_string();
+
+        assert!(error.contains("`F7|abc`"));
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(Chord::parse("Cmaj7").unwrap().transpose(Interval::PerfectFifth).unwrap(), Chord::parse("Gmaj7").unwrap());
+        assert_eq!(Chord::parse("Dm7/C").unwrap().transpose(Interval::MajorSecond).unwrap(), Chord::parse("Em7/D").unwrap());
+        assert_eq!(Chord::parse("Cmaj7").unwrap().transpose_down(Interval::MinorSecond).unwrap().root(), BThree);
+
+        assert_eq!(Chord::parse("C").unwrap().transpose_semitones(7).unwrap(), Chord::parse("G").unwrap());
+        assert_eq!(Chord::parse("C").unwrap().transpose_semitones(0).unwrap(), Chord::parse("C").unwrap());
+        assert_eq!(Chord::parse("Dbm7").unwrap().transpose_semitones(3).unwrap(), Chord::parse("Em7").unwrap());
+        assert_eq!(Chord::parse("C/E").unwrap().transpose_semitones(-2).unwrap(), Chord::new(BFlatThree).with_slash(D));
+        assert_eq!(Chord::parse("C7").unwrap().transpose_semitones(19).unwrap(), Chord::new(GFive).seven());
+        assert_eq!(Chord::parse("C7").unwrap().transpose_semitones(-12).unwrap(), Chord::new(CThree).seven());
+
+        assert_eq!(Chord::parse("C7").unwrap().transpose_to(EFlat).unwrap(), Chord::parse("Eb7").unwrap());
+        assert_eq!(Chord::parse("Cmaj7/E").unwrap().transpose_to(AFive).unwrap(), Chord::new(A).maj7().with_slash(CSharpFive));
+        assert_eq!(Chord::parse("Dm7@5").unwrap().transpose_to(C).unwrap(), Chord::parse("Cm7@5").unwrap());
+
+        // Transpositions beyond the octave range are errors (rather than panics).
+        assert!(Chord::new(CZero).transpose_semitones(-1).is_err());
+        assert!(Chord::parse("C").unwrap().transpose_semitones(-100).unwrap_err().to_string().contains("octave range"));
+        assert!(Chord::new(BFifteen).transpose(Interval::MinorSecond).is_err());
+        assert!(Chord::new(CZero).transpose_down(Interval::PerfectOctave).is_err());
+        assert!(Chord::new(COne).with_slash(CZero).transpose_to(CFlat).is_err());
+        assert_eq!(Chord::new(CZero).transpose_semitones(127).unwrap(), Chord::new(GTen));
+    }
+
+    #[test]
+    fn test_is_enharmonic() {
+        assert!(Chord::parse("Db").unwrap().is_enharmonic(&Chord::parse("C#").unwrap()));
+        assert_ne!(Chord::parse("Db").unwrap(), Chord::parse("C#").unwrap());
+        assert!(Chord::parse("Ebm7").unwrap().is_enharmonic(&Chord::parse("D#m7").unwrap()));
+        assert!(Chord::parse("Db/F").unwrap().is_enharmonic(&Chord::parse("C#/E#").unwrap()));
+        assert!(Chord::parse("Am7^1").unwrap().is_enharmonic(&Chord::parse("C6@5").unwrap()));
+
+        assert!(!Chord::parse("C").unwrap().is_enharmonic(&Chord::parse("Cm").unwrap()));
+        assert!(!Chord::parse("C").unwrap().is_enharmonic(&Chord::parse("C@5").unwrap()));
+    }
+
+    #[test]
+    fn test_extreme_octaves() {
+        // Deep inversions of low chords.
+        assert_eq!(Chord::parse("C@0^3").unwrap().chord(), vec![COne, EOne, GOne]);
+        assert_eq!(Chord::new(CZero).seven().with_inversion(3).chord(), vec![BFlatZero, COne, EOne, GOne]);
+        assert_eq!(Chord::new(CZero).with_slash(EZero).chord(), vec![CZero, EZero, GZero]);
+        assert_eq!(Chord::new(CZero).with_crunchy(true).chord(), vec![CZero, EZero, GZero]);
+
+        assert!(Chord::try_from_notes(&[CZero, EZero, GZero]).unwrap().contains(&Chord::new(CZero)));
+
+        // Inversions of high chords stay in the highest octave.
+        assert_eq!(Chord::new(CFifteen).with_inversion(2).chord(), vec![CFifteen, EFifteen, GFifteen]);
+        assert_eq!(Chord::new(CFifteen).seven().with_inversion(3).chord(), vec![CFifteen, EFifteen, GFifteen, BFlatFifteen]);
+
+        // Approach chords that would fall out of the octave range are skipped.
+        assert!(Chord::new(CZero).approach_chords(true).is_empty());
+        assert!(Chord::new(BFifteen).approach_chords(false).is_empty());
+        assert_eq!(Chord::new(CZero).approach_chords(false)[0], Chord::new(DFlatZero).seven());
+        assert_eq!(Chord::new(CZero).with_slash(EZero).approach_chords(false)[1], Chord::new(DFlatZero).with_slash(FZero));
+    }
+
+    #[test]
+    fn test_voice_within() {
+        let voicing = Chord::new(C).maj7().nine().voice_within(CThree, CFive).unwrap();
+
+        assert_eq!(voicing, vec![CThree, EThree, GThree, BThree, DFour]);
+
+        let voicing = Chord::parse("C13").unwrap().voice_within(EThree, EFour).unwrap();
+
+        assert_eq!(voicing.len(), 7);
+        assert!(voicing.iter().all(|n| (EThree.to_midi()..=EFour.to_midi()).contains(&n.to_midi())));
+        assert!(Chord::parse("C13").unwrap().chord().iter().all(|n| voicing.iter().any(|v| v.pitch() == n.pitch())));
+
+        let voicing = Chord::parse("Ebm/Gb").unwrap().voice_within(AFour, GSix).unwrap();
+
+        assert_eq!(voicing, vec![BFlatFour, GFlatFive, EFlatSix]);
+
+        assert!(Chord::parse("C7").unwrap().voice_within(C, E).is_err());
+        assert!(Chord::parse("C").unwrap().voice_within(C, D).is_err());
+    }
+
+    #[test]
+    fn test_frequencies() {
+        let frequencies = Chord::parse("C").unwrap().frequencies();
+
+        assert_eq!(frequencies.len(), 3);
+        assert!(frequencies.iter().zip([261.6, 329.6, 392.0]).all(|(a, b)| (a - b).abs() < 0.1));
+
+        assert!((Chord::parse("C").unwrap().fundamental_frequency() - 261.6).abs() < 0.1);
+        assert!((Chord::parse("C/E").unwrap().fundamental_frequency() - 164.8).abs() < 0.1);
+
+        // Other tunings scale the frequencies.
+        let chord = Chord::parse("Am7").unwrap();
+        assert_eq!(chord.frequencies_with(&Tuning::default()), chord.frequencies());
+        assert!(chord
+            .frequencies_with(&Tuning::equal(432.0))
+            .iter()
+            .zip(chord.frequencies())
+            .all(|(a, b)| (a / b - 432.0 / 440.0).abs() < 1e-5));
+    }
+
+    #[test]
+    fn test_substitutions() {
+        assert_eq!(Chord::parse("G7").unwrap().tritone_substitution(), Some(Chord::parse("Db7").unwrap()));
+        assert_eq!(Chord::parse("G7b9").unwrap().tritone_substitution(), Some(Chord::parse("Db7b9").unwrap()));
+        assert_eq!(Chord::parse("Cmaj7").unwrap().tritone_substitution(), None);
+
+        assert_eq!(Chord::parse("Cmaj7").unwrap().relative_substitution(), Some(Chord::new(AThree).minor().seven()));
+        assert_eq!(Chord::parse("C").unwrap().relative_substitution(), Some(Chord::new(AThree).minor()));
+        assert_eq!(Chord::parse("Am").unwrap().relative_substitution(), Some(Chord::new(CFive)));
+        assert_eq!(Chord::parse("Dm7").unwrap().relative_substitution(), Some(Chord::parse("Fmaj7").unwrap()));
+        assert_eq!(Chord::parse("G7").unwrap().relative_substitution(), None);
+    }
+
+    #[test]
+    fn test_complexity_score() {
+        let c = Chord::parse("C").unwrap();
+        let c7 = Chord::parse("C7").unwrap();
+        let c13 = Chord::parse("C13").unwrap();
+        let c_e = Chord::parse("C/E").unwrap();
+
+        assert_eq!(c.complexity_score(), 0);
+        assert_eq!(c7.complexity_score(), 1);
+        assert_eq!(c13.complexity_score(), 1);
+        assert_eq!(c_e.complexity_score(), 2);
+        assert_eq!(Chord::parse("Cm7b5^1").unwrap().complexity_score(), 5);
+        assert_eq!(Chord::parse("C7sus4(b9)/E").unwrap().complexity_score(), 5);
+
+        // The ordering is consistent with the score.
+        assert!(c < c7);
+        assert!(c13 < c_e);
+        assert!(c7 < c_e);
+    }
+
+    #[test]
+    fn test_simplify() {
+        assert_eq!(Chord::parse("C9add9").unwrap().simplify().name(), "C9");
+        assert_eq!(Chord::parse("C13add9add11").unwrap().simplify().name(), "C13");
+        assert_eq!(Chord::parse("Cdim").unwrap().simplify().name(), "Cdim");
+        assert_eq!(Chord::parse("Cm7b5").unwrap().simplify().name(), Chord::parse("Cm7b5").unwrap().name());
+        assert_eq!(Chord::parse("C7/E").unwrap().simplify(), Chord::parse("C7/E").unwrap());
+
+        for symbol in ["C9add9", "C13add9add11", "Cdim", "Cm7b5", "Cmaj7(#11)/E", "C7(b9)^1"] {
+            let chord = Chord::parse(symbol).unwrap();
+            let simplified = chord.simplify();
+
+            assert_eq!(simplified.chord(), chord.chord());
+            assert!(simplified.complexity_score() <= chord.complexity_score());
+        }
+    }
+
+    #[test]
+    fn test_contains() {
+        let c7 = Chord::parse("C7").unwrap();
+
+        assert!(c7.contains_pitch(Pitch::BFlat));
+        assert!(c7.contains_pitch(Pitch::C));
+        assert!(!c7.contains_pitch(Pitch::B));
+
+        assert!(c7.contains_note(&BFlat));
+        assert!(!c7.contains_note(&B));
+        assert!(!c7.contains_note(&ASharp));
+        assert!(!c7.contains_note(&BFlatFive));
+
+        // Only chord tones count (not the scale).
+        assert!(!c7.contains_pitch(Pitch::D));
+        assert!(!c7.contains_note(&D));
+
+        let slash = Chord::parse("C/E").unwrap();
+
+        assert!(slash.contains_note(&EThree));
+        assert!(slash.contains_pitch(Pitch::E));
+    }
+
+    #[test]
+    fn test_common_tones() {
+        let c = Chord::parse("C").unwrap();
+        let am = Chord::parse("Am").unwrap();
+
+        assert_eq!(c.common_tones(&am), vec![C, E]);
+        assert_eq!(am.common_tones(&c), vec![CFive, EFive]);
+        assert_eq!(c.common_tones(&Chord::parse("F#").unwrap()), vec![]);
+        assert_eq!(c.common_tones(&Chord::parse("Em@2").unwrap()), vec![E, G]);
+        assert_eq!(Chord::parse("C/E").unwrap().common_tones(&am), vec![EThree, C]);
+
+        assert!(c.is_relative_of(&am));
+        assert!(am.is_relative_of(&c));
+        assert!(Chord::parse("Am7@2").unwrap().is_relative_of(&Chord::parse("Cmaj7").unwrap()));
+        assert!(!c.is_relative_of(&Chord::parse("Em").unwrap()));
+        assert!(!c.is_relative_of(&Chord::parse("A").unwrap()));
+        assert!(!Chord::parse("G7").unwrap().is_relative_of(&Chord::parse("Em").unwrap()));
+    }
+
+    #[test]
+    fn test_voice_leading_distance() {
+        let g7 = Chord::parse("G7").unwrap();
+
+        assert_eq!(g7.voice_leading_distance(&g7), 0);
+        assert_eq!(g7.voice_leading_distance(&Chord::parse("Cmaj7").unwrap()), 2);
+        assert_eq!(Chord::parse("C").unwrap().voice_leading_distance(&Chord::parse("F#").unwrap()), 4);
+    }
+
+    #[test]
+    fn test_approach_chords() {
+        let above = Chord::parse("Cmaj7").unwrap().approach_chords(false);
+
+        assert_eq!(above, vec![Chord::parse("Db7").unwrap(), Chord::parse("Dbmaj7").unwrap()]);
+        assert!(above.iter().all(|c| c.root() == DFlat));
+
+        let below = Chord::parse("Cmaj7").unwrap().approach_chords(true);
 
-                    result = result.with_slash(note);
-                }
-                Rule::at => {
-                    let octave = octave_str_to_octave(components.next().unwrap().as_str())?;
+        assert_eq!(below, vec![Chord::new(BThree).seven(), Chord::new(BThree).maj7(), Chord::new(BThree).dim()]);
 
-                    result = result.with_octave(octave);
-                }
-                Rule::hat => {
-                    let inversion = components.next().unwrap().as_str().parse::<u8>()?;
+        let dominant = Chord::parse("C7").unwrap().approach_chords(false);
 
-                    result = result.with_inversion(inversion);
+        assert_eq!(dominant, vec![Chord::parse("Db7").unwrap()]);
+    }
+
+    /// Decodes the note events of a type-0 Standard MIDI File into `(absolute tick, is note on, note, velocity)` tuples.
+    pub(crate) fn decode_smf_note_events(bytes: &[u8]) -> Vec<(u32, bool, u8, u8)> {
+        assert_eq!(&bytes[0..4], b"MThd");
+        assert_eq!(u16::from_be_bytes([bytes[8], bytes[9]]), 0);
+        assert_eq!(&bytes[14..18], b"MTrk");
+
+        let track_length = u32::from_be_bytes(bytes[18..22].try_into().unwrap()) as usize;
+        let track = &bytes[22..22 + track_length];
+        assert_eq!(bytes.len(), 22 + track_length);
+
+        let mut events = Vec::new();
+        let mut tick = 0u32;
+        let mut k = 0;
+
+        while k < track.len() {
+            let mut delta = 0u32;
+            loop {
+                delta = (delta << 7) | (track[k] & 0x7F) as u32;
+                k += 1;
+                if track[k - 1] & 0x80 == 0 {
+                    break;
                 }
-                Rule::bang => {
-                    result = result.with_crunchy(true);
+            }
+            tick += delta;
+
+            match track[k] {
+                0xFF => {
+                    let length = track[k + 2] as usize;
+                    k += 3 + length;
                 }
-                Rule::EOI => {}
-                _ => {
-                    unreachable!();
+                status => {
+                    events.push((tick, status == 0x90, track[k + 1], track[k + 2]));
+                    k += 3;
                 }
             }
         }
 
-        Ok(result)
+        events
     }
-}
-
-#[cfg(feature = "audio")]
-use super::base::{Playable, PlaybackHandle};
-
-#[cfg(feature = "audio")]
-impl Playable for Chord {
-    #[coverage(off)]
-    fn play(&self, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle> {
-        use rodio::{source::SineWave, OutputStream, Sink, Source};
 
-        let chord_tones = self.chord();
+    #[test]
+    fn test_smf() {
+        let chord = Chord::parse("Cmaj7").unwrap();
 
-        if length.as_secs_f32() <= chord_tones.len() as f32 * delay.as_secs_f32() {
-            return Err(anyhow::Error::msg(
-                "The delay is too long for the length of play (i.e., the number of chord tones times the delay is longer than the length).",
-            ));
-        }
+        assert_eq!(chord.chord_midi(), vec![60, 64, 67, 71]);
 
-        let (stream, stream_handle) = OutputStream::try_default()?;
+        let events = decode_smf_note_events(&chord.to_smf(960, 100));
 
-        let mut sinks = vec![];
+        assert_eq!(
+            events,
+            vec![
+                (0, true, 60, 100),
+                (0, true, 64, 100),
+                (0, true, 67, 100),
+                (0, true, 71, 100),
+                (960, false, 60, 0),
+                (960, false, 64, 0),
+                (960, false, 67, 0),
+                (960, false, 71, 0),
+            ]
+        );
 
-        for (k, n) in chord_tones.into_iter().enumerate() {
-            let sink = Sink::try_new(&stream_handle)?;
+        // Notes above the MIDI range are clamped (so the note-on data bytes stay valid).
+        let events = decode_smf_note_events(&Chord::new(CTen).to_smf(480, 200));
 
-            let d = delay * k as u32;
+        assert!(events.iter().all(|(_, _, note, velocity)| *note <= 127 && *velocity <= 127));
+        assert_eq!(events[0], (0, true, 127, 127));
+    }
 
-            let source = SineWave::new(n.frequency()).take_duration(length - d).buffered().delay(d).fade_in(fade_in).amplify(0.20);
+    #[test]
+    fn test_musicxml() {
+        let xml = Chord::parse("Cmaj7").unwrap().to_musicxml();
 
-            sink.append(source);
+        assert_well_formed_xml(&xml);
+        assert!(xml.contains("<root-step>C</root-step>"));
+        assert!(xml.contains("<kind text=\"maj7\">major-seventh</kind>"));
+        assert_eq!(xml.matches("<note>").count(), 4);
 
-            sinks.push(sink);
-        }
+        let xml = Chord::parse("Bbm7/Ab").unwrap().to_musicxml();
 
-        Ok(PlaybackHandle::new(stream, stream_handle, sinks))
+        assert_well_formed_xml(&xml);
+        assert!(xml.contains("<root-step>B</root-step><root-alter>-1</root-alter>"));
+        assert!(xml.contains("<kind text=\"m7\">minor-seventh</kind>"));
+        assert!(xml.contains("<bass><bass-step>A</bass-step><bass-alter>-1</bass-alter></bass>"));
     }
-}
 
-impl Default for Chord {
-    fn default() -> Self {
-        Chord::new(super::note::C)
+    #[test]
+    fn test_lilypond() {
+        assert_eq!(Chord::parse("C").unwrap().to_lilypond(), "<c' e' g'>");
+        assert_eq!(Chord::parse("Ab7").unwrap().to_lilypond(), "<aes' c'' ees'' ges''>");
+        assert_eq!(Chord::parse("C#m@2").unwrap().to_lilypond(), "<cis, e, gis,>");
+
+        assert_eq!(Chord::parse("C").unwrap().scale_to_lilypond(), "c' d' e' f' g' a' b'");
+        assert_eq!(Chord::parse("Ab7").unwrap().scale_to_lilypond(), "aes' bes' c'' des'' ees'' f'' ges''");
     }
-}
 
-// Tests.
+    #[test]
+    fn test_without() {
+        let chord = Chord::parse("C7add13").unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::core::{note::*, octave::HasOctave};
-    use pretty_assertions::assert_eq;
+        assert_eq!(chord.clone().without_extension(Extension::Add13), Chord::parse("C7").unwrap());
+        assert_eq!(chord.clone().without_extension(Extension::Add13).chord(), vec![C, E, G, BFlat]);
+        assert_eq!(chord.clone().without_extension(Extension::Add9), chord);
 
-    #[test]
-    fn test_text() {
-        assert_eq!(Chord::new(C).flat9().sharp9().sharp11().add13().with_slash(E).name(), "C(♭9)(♯9)(♯11)(add13)/E");
-        assert_eq!(Chord::new(C).flat5().name(), "C(♭5)");
-        assert_eq!(Chord::new(C).minor().augmented().name(), "Cm(♯5)");
-        assert_eq!(Chord::new(C).with_octave(Octave::Six).precise_name(), "C@6");
+        let chord = Chord::parse("Cm7(add9)(sus4)").unwrap();
 
+        assert_eq!(chord.clone().clear_extensions(), Chord::parse("Cm7").unwrap());
+        assert_eq!(chord.clone().without_modifier(Modifier::Minor).clear_extensions().known_chord(), KnownChord::Dominant(Degree::Seven));
         assert_eq!(
-            format!("{}", Chord::new(C).minor().seven().flat_five()),
-            "Cm7(♭5)\n   half diminished, locrian, minor seven flat five, seventh mode of major scale, major scale starting one half step up\n   C, D, E♭, F, G♭, A♭, B♭\n   C, E♭, G♭, B♭"
+            chord.without_modifier(Modifier::Dominant(Degree::Seven)).without_modifier(Modifier::Minor).clear_extensions().name(),
+            "C"
         );
     }
 
     #[test]
-    fn test_properties() {
-        assert_eq!(Chord::new(C).seven().flat9().root(), C);
-        assert_eq!(Chord::new(C).with_slash(E).slash(), E);
-        assert_eq!(Chord::new(C).slash(), C);
-        assert_eq!(Chord::new(C).flat9().add13().with_slash(E).modifiers(), &vec![Modifier::Flat9].into_iter().collect::<HashSet<_>>());
-        assert_eq!(Chord::new(C).flat9().add13().with_slash(E).extensions(), &vec![Extension::Add13].into_iter().collect::<HashSet<_>>());
-        assert_eq!(Chord::new(C).flat9().add13().with_slash(E).seven().dominant_degree(), Some(Degree::Seven));
-        assert_eq!(Chord::new(C).flat9().add13().with_slash(E).nine().dominant_degree(), Some(Degree::Nine));
-        assert_eq!(Chord::new(C).flat9().with_inversion(1).inversion(), 1);
-        assert_eq!(Chord::new(C).flat9().with_octave(Octave::Three).root().octave(), Octave::Three);
+    fn test_scale_degrees() {
+        assert_eq!(Chord::parse("C7b9").unwrap().scale_degrees(), vec!["1", "3", "5", "b7", "b9"]);
+        assert_eq!(Chord::parse("Cm7b5").unwrap().scale_degrees(), vec!["1", "b3", "b5", "b7"]);
+        assert_eq!(Chord::parse("Cdim").unwrap().scale_degrees(), vec!["1", "b3", "b5", "bb7"]);
+        assert_eq!(Chord::parse("D9(#11)").unwrap().scale_degrees(), vec!["1", "3", "5", "b7", "9", "#11"]);
+        assert_eq!(Chord::parse("Gsus4/A").unwrap().scale_degrees(), vec!["1", "4", "5"]);
     }
 
     #[test]
-    fn test_known_chords() {
-        assert_eq!(Chord::new(C).known_chord(), KnownChord::Major);
-        assert_eq!(Chord::new(C).minor().known_chord(), KnownChord::Minor);
-        assert_eq!(Chord::new(C).major7().known_chord(), KnownChord::Major7);
-        assert_eq!(Chord::new(C).minor().major7().known_chord(), KnownChord::MinorMajor7);
-        assert_eq!(Chord::new(C).minor().dominant(Degree::Seven).known_chord(), KnownChord::MinorDominant(Degree::Seven));
-        assert_eq!(Chord::new(C).minor().eleven().known_chord(), KnownChord::MinorDominant(Degree::Eleven));
-        assert_eq!(Chord::new(C).seven().known_chord(), KnownChord::Dominant(Degree::Seven));
-        assert_eq!(Chord::new(C).eleven().known_chord(), KnownChord::Dominant(Degree::Eleven));
-        assert_eq!(Chord::new(C).thirteen().known_chord(), KnownChord::Dominant(Degree::Thirteen));
-        assert_eq!(Chord::new(C).diminished().known_chord(), KnownChord::Diminished);
-        assert_eq!(Chord::new(C).dim().known_chord(), KnownChord::Diminished);
-        assert_eq!(Chord::new(C).minor().seven().flat5().known_chord(), KnownChord::HalfDiminished(Degree::Seven));
-        assert_eq!(Chord::new(C).augmented().known_chord(), KnownChord::Augmented);
-        assert_eq!(Chord::new(C).aug().major7().known_chord(), KnownChord::AugmentedMajor7);
-        assert_eq!(Chord::new(C).augmented().seven().known_chord(), KnownChord::AugmentedDominant(Degree::Seven));
-        assert_eq!(Chord::new(C).seven().sharp11().known_chord(), KnownChord::DominantSharp11(Degree::Seven));
-        assert_eq!(Chord::new(C).seven().flat9().known_chord(), KnownChord::DominantFlat9(Degree::Seven));
-        assert_eq!(Chord::new(C).seven().sharp9().known_chord(), KnownChord::DominantSharp9(Degree::Seven));
+    fn test_scale_in_range() {
+        let notes = Chord::parse("C").unwrap().scale_in_range(CThree, CFive);
 
-        assert_eq!(Chord::new(C).sus2().known_chord(), KnownChord::Major);
-        assert_eq!(Chord::new(C).sus4().known_chord(), KnownChord::Major);
-        assert_eq!(Chord::new(C).sustain().known_chord(), KnownChord::Major);
-        assert_eq!(Chord::new(C).seven().sus().known_chord(), KnownChord::Dominant(Degree::Seven));
+        assert_eq!(notes.len(), 15);
+        assert_eq!(notes.first(), Some(&CThree));
+        assert_eq!(notes.last(), Some(&CFive));
+        assert_eq!(notes[7], C);
+
+        // Spelling is preserved, and the range need not start on the root.
+        assert_eq!(Chord::parse("Ab7").unwrap().scale_in_range(FThree, CFour), vec![FThree, GFlatThree, AFlatThree, BFlatThree, CFour]);
+
+        assert!(Chord::parse("C").unwrap().scale_in_range(CFive, CThree).is_empty());
     }
 
     #[test]
-    fn test_scales() {
-        // Basic.
+    fn test_piano_keys() {
+        assert_eq!(
+            Chord::parse("Cmaj7").unwrap().piano_keys(),
+            vec![
+                (NamedPitch::C, Octave::Four, false),
+                (NamedPitch::E, Octave::Four, false),
+                (NamedPitch::G, Octave::Four, false),
+                (NamedPitch::B, Octave::Four, false),
+            ]
+        );
+        assert_eq!(
+            Chord::parse("D").unwrap().piano_keys(),
+            vec![(NamedPitch::D, Octave::Four, false), (NamedPitch::FSharp, Octave::Four, true), (NamedPitch::A, Octave::Four, false)]
+        );
+        assert_eq!(
+            Chord::parse("C#7(#9)").unwrap().piano_keys().iter().map(|(_, _, black)| *black).collect::<Vec<_>>(),
+            vec![true, false, true, false, false]
+        );
+    }
 
-        assert_eq!(Chord::new(C).scale(), vec![C, D, E, F, G, A, B]);
-        assert_eq!(Chord::new(C).minor().scale(), vec![C, D, EFlat, F, G, AFlat, BFlat]);
-        assert_eq!(Chord::new(C).major_seven().scale(), vec![C, D, E, F, G, A, B]);
-        assert_eq!(Chord::new(C).minor().maj7().scale(), vec![C, D, EFlat, F, G, A, B]);
-        assert_eq!(Chord::new(C).minor().seven().scale(), vec![C, D, EFlat, F, G, A, BFlat]);
-        assert_eq!(Chord::new(C).minor().eleven().scale(), vec![C, D, EFlat, F, G, A, BFlat]);
-        assert_eq!(Chord::new(C).seven().scale(), vec![C, D, E, F, G, A, BFlat]);
-        assert_eq!(Chord::new(C).eleven().scale(), vec![C, D, E, F, G, A, BFlat]);
-        assert_eq!(Chord::new(C).thirteen().scale(), vec![C, D, E, F, G, A, BFlat]);
-        assert_eq!(Chord::new(C).diminished().scale(), vec![C, D, EFlat, F, GFlat, AFlat, BDoubleFlat, B]);
-        assert_eq!(Chord::new(C).dim().scale(), vec![C, D, EFlat, F, GFlat, AFlat, BDoubleFlat, B]);
-        assert_eq!(Chord::new(C).minor().seven().flat5().scale(), vec![C, D, EFlat, F, GFlat, AFlat, BFlat]);
-        assert_eq!(Chord::new(C).augmented().scale(), vec![C, D, E, F, GSharp, A, B]);
-        assert_eq!(Chord::new(C).augmented().major7().scale(), vec![C, D, E, FSharp, GSharp, A, B]);
-        assert_eq!(Chord::new(C).augmented().seven().scale(), vec![C, D, E, FSharp, GSharp, ASharp]);
-        assert_eq!(Chord::new(C).seven().sharp_eleven().scale(), vec![C, D, E, FSharp, G, A, BFlat]);
-        assert_eq!(Chord::new(C).seven().flat_nine().scale(), vec![C, DFlat, EFlat, E, FSharp, G, A, BFlat]);
-        assert_eq!(Chord::new(C).seven().sharp_nine().scale(), vec![C, DFlat, EFlat, FFlat, GFlat, AFlat, BFlat]);
+    #[test]
+    fn test_canonical_name() {
+        let half_diminished = [
+            Chord::parse("Cm7b5").unwrap(),
+            Chord::parse("Cø").unwrap(),
+            Chord::parse("Cø7").unwrap(),
+            Chord::parse("C-7(♭5)").unwrap(),
+            C.into_chord().half_dim(),
+            C.into_chord().flat5().seven().minor(),
+        ];
+
+        for chord in &half_diminished {
+            assert_eq!(chord.canonical_name(), "Cm7(♭5)");
+        }
 
-        // Others.
+        assert_eq!(Chord::parse("Co7").unwrap().canonical_name(), Chord::parse("Cdim").unwrap().canonical_name());
+        assert_eq!(Chord::parse("Cadd9sus4").unwrap().canonical_name(), Chord::parse("Csus4add9").unwrap().canonical_name());
+        assert_eq!(Chord::parse("Bbm7/Ab").unwrap().canonical_name(), "B♭m7/A♭");
 
-        assert_eq!(Chord::new(DFlat).scale(), vec![DFlat, EFlat, F, GFlat, AFlat, BFlat, CFive]);
-        assert_eq!(Chord::new(DFlat).seven().scale(), vec![DFlat, EFlat, F, GFlat, AFlat, BFlat, CFlatFive]);
-        assert_eq!(Chord::new(DFlat).dim().scale(), vec![DFlat, EFlat, FFlat, GFlat, ADoubleFlat, BDoubleFlat, CDoubleFlatFive, CFive]);
+        // Aliases.
+
+        let aliases = Chord::parse("Cm7b5").unwrap().aliases();
+
+        for alias in ["Cø", "Cø7", "Cm7b5", "C-7♭5", "C-7(b5)"] {
+            assert!(aliases.contains(&alias.to_owned()), "missing alias `{}` in {:?}", alias, aliases);
+        }
+
+        for chord in ["Cm7b5", "Bbm7/Ab", "C7(b9)(#11)", "Ebdim", "F#mmaj7", "G13(#11)", "Dsus4(add9)", "A5"] {
+            let chord = Chord::parse(chord).unwrap();
+
+            for alias in chord.aliases() {
+                assert_eq!(Chord::parse(&alias).unwrap().canonical_name(), chord.canonical_name());
+            }
+        }
+
+        assert_eq!(Chord::parse("C").unwrap().aliases(), Vec::<String>::new());
     }
 
     #[test]
-    fn test_chords() {
-        // Basic.
+    fn test_abc() {
+        assert_eq!(Chord::parse("C").unwrap().to_abc(), "[CEG]");
+        assert_eq!(Chord::parse("F#m").unwrap().to_abc(), "[^FA^c]");
+        assert_eq!(Chord::parse("Bb7@3").unwrap().to_abc(), "[_B,DF_A]");
+        assert_eq!(Chord::parse("Ebmaj7@5").unwrap().to_abc(), "[_eg_bd']");
 
-        assert_eq!(Chord::new(C).chord(), vec![C, E, G]);
-        assert_eq!(Chord::new(C).minor().chord(), vec![C, EFlat, G]);
-        assert_eq!(Chord::new(C).major7().chord(), vec![C, E, G, B]);
-        assert_eq!(Chord::new(C).minor().major7().chord(), vec![C, EFlat, G, B]);
-        assert_eq!(Chord::new(C).minor().seven().chord(), vec![C, EFlat, G, BFlat]);
-        assert_eq!(Chord::new(C).minor().eleven().chord(), vec![C, EFlat, G, BFlat, DFive, FFive]);
-        assert_eq!(Chord::new(C).seven().chord(), vec![C, E, G, BFlat]);
-        assert_eq!(Chord::new(C).eleven().chord(), vec![C, E, G, BFlat, DFive, FFive]);
-        assert_eq!(Chord::new(C).thirteen().chord(), vec![C, E, G, BFlat, DFive, FFive, AFive]);
-        assert_eq!(Chord::new(C).diminished().chord(), vec![C, EFlat, GFlat, BDoubleFlat]);
-        assert_eq!(Chord::new(C).dim().chord(), vec![C, EFlat, GFlat, BDoubleFlat]);
-        assert_eq!(Chord::new(C).minor().seven().flat5().chord(), vec![C, EFlat, GFlat, BFlat]);
-        assert_eq!(Chord::new(C).half_diminished().chord(), vec![C, EFlat, GFlat, BFlat]);
-        assert_eq!(Chord::new(C).half_dim().chord(), vec![C, EFlat, GFlat, BFlat]);
-        assert_eq!(Chord::new(C).augmented().chord(), vec![C, E, GSharp]);
-        assert_eq!(Chord::new(C).augmented().major7().chord(), vec![C, E, GSharp, B]);
-        assert_eq!(Chord::new(C).augmented().seven().chord(), vec![C, E, GSharp, BFlat]);
-        assert_eq!(Chord::new(C).seven().sharp11().chord(), vec![C, E, G, BFlat, FSharpFive]);
-        assert_eq!(Chord::new(C).seven().flat_nine().chord(), vec![C, E, G, BFlat, DFlatFive]);
-        assert_eq!(Chord::new(C).seven().sharp_nine().chord(), vec![C, E, G, BFlat, DSharpFive]);
+        assert_eq!(Chord::parse("C").unwrap().scale_to_abc(), "C D E F G A B");
+    }
 
-        // Extensions.
+    #[test]
+    #[cfg(feature = "audio")]
+    fn test_render_wav() {
+        let path = std::env::temp_dir().join("kord_test_render_wav.wav");
 
-        assert_eq!(Chord::new(C).nine().sus2().chord(), vec![C, D, G, BFlat, DFive]);
-        assert_eq!(Chord::new(C).nine().sus_two().chord(), vec![C, D, G, BFlat, DFive]);
-        assert_eq!(Chord::new(C).nine().sus4().chord(), vec![C, F, G, BFlat, DFive]);
-        assert_eq!(Chord::new(C).nine().sus_four().chord(), vec![C, F, G, BFlat, DFive]);
-        assert_eq!(Chord::new(C).nine().sustain().chord(), vec![C, F, G, BFlat, DFive]);
-        assert_eq!(Chord::new(C).seven().sus().chord(), vec![C, F, G, BFlat]);
-        assert_eq!(Chord::new(C).seven().add2().chord(), vec![C, D, E, G, BFlat]);
-        assert_eq!(Chord::new(C).seven().add_two().chord(), vec![C, D, E, G, BFlat]);
-        assert_eq!(Chord::new(C).seven().add4().chord(), vec![C, E, F, G, BFlat]);
-        assert_eq!(Chord::new(C).seven().add_four().chord(), vec![C, E, F, G, BFlat]);
-        assert_eq!(Chord::new(C).add6().chord(), vec![C, E, G, A]);
-        assert_eq!(Chord::new(C).seven().add9().chord(), vec![C, E, G, BFlat, DFive]);
-        assert_eq!(Chord::new(C).seven().add_nine().chord(), vec![C, E, G, BFlat, DFive]);
-        assert_eq!(Chord::new(C).seven().add11().chord(), vec![C, E, G, BFlat, FFive]);
-        assert_eq!(Chord::new(C).seven().add_eleven().chord(), vec![C, E, G, BFlat, FFive]);
-        assert_eq!(Chord::new(C).seven().add13().chord(), vec![C, E, G, BFlat, AFive]);
-        assert_eq!(Chord::new(C).seven().add_thirteen().chord(), vec![C, E, G, BFlat, AFive]);
-        assert_eq!(Chord::new(C).seven().add2().add4().chord(), vec![C, D, E, F, G, BFlat]);
-        assert_eq!(Chord::new(C).seven().add6().chord(), vec![C, E, G, A, BFlat]);
-        assert_eq!(Chord::new(C).seven().add_six().chord(), vec![C, E, G, A, BFlat]);
-        assert_eq!(Chord::new(C).seven().flat11().chord(), vec![C, E, G, BFlat, FFlatFive]);
-        assert_eq!(Chord::new(C).seven().flat_eleven().chord(), vec![C, E, G, BFlat, FFlatFive]);
-        assert_eq!(Chord::new(C).seven().flat13().chord(), vec![C, E, G, BFlat, AFlatFive]);
-        assert_eq!(Chord::new(C).seven().flat_thirteen().chord(), vec![C, E, G, BFlat, AFlatFive]);
-        assert_eq!(Chord::new(C).seven().sharp13().chord(), vec![C, E, G, BFlat, ASharpFive]);
-        assert_eq!(Chord::new(C).seven().sharp_thirteen().chord(), vec![C, E, G, BFlat, ASharpFive]);
+        Chord::parse("Cmaj7").unwrap().render_wav(&path, Duration::from_millis(500), 22050).unwrap();
 
-        // Crunchy.
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
 
-        assert_eq!(Chord::new(C).seven().sharp9().with_crunchy(true).chord(), vec![C, DSharp, E, G, BFlat]);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(&bytes[8..12], b"WAVE");
+        assert_eq!(u32::from_le_bytes(bytes[24..28].try_into().unwrap()), 22050);
 
-        // Slashes.
+        let data_size = u32::from_le_bytes(bytes[40..44].try_into().unwrap()) as usize;
+        assert_eq!(data_size / 2, 11025);
 
-        assert_eq!(Chord::new(C).with_slash(D).chord(), vec![DThree, C, E, G]);
-        assert_eq!(Chord::new(CFive).with_slash(D).chord(), vec![DFour, CFive, EFive, GFive]);
+        let samples = bytes[44..].chunks_exact(2).map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / i16::MAX as f32).collect::<Vec<_>>();
+        let rms = (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt();
 
-        // Inversions.
+        assert_eq!(samples.len(), 11025);
+        assert!(rms > 0.05);
 
-        assert_eq!(C.into_chord().with_inversion(1).chord(), vec![E, G, CFive]);
-        assert_eq!(C.into_chord().with_inversion(2).chord(), vec![G, CFive, EFive]);
-        assert_eq!(C.into_chord().maj7().with_inversion(3).chord(), vec![B, CFive, EFive, GFive]);
-        assert_eq!(BFlatThree.into_chord().seven().flat9().with_inversion(1).chord(), vec![D, F, AFlat, CFlatFive, BFlatFive]);
+        // Larger chords are mixed down (rather than clipped).
+        Chord::parse("C13")
+            .unwrap()
+            .render_wav_with(&path, Waveform::Square, Duration::from_millis(100), Duration::ZERO, 22050)
+            .unwrap();
 
-        // Weird.
-        assert_eq!(C.into_chord().flat5().aug().chord(), vec![C, E, GSharp]);
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let samples = bytes[44..].chunks_exact(2).map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / i16::MAX as f32).collect::<Vec<_>>();
+        assert!(samples.iter().all(|s| s.abs() <= Waveform::Square.gain() + 1e-3));
     }
 
     #[test]
-    fn test_parse() {
-        assert_eq!(Chord::parse("C").unwrap().chord(), vec![C, E, G]);
-        assert_eq!(Chord::parse("Cm").unwrap().chord(), vec![C, EFlat, G]);
-        assert_eq!(Chord::parse("Cm7").unwrap().chord(), vec![C, EFlat, G, BFlat]);
-        assert_eq!(Chord::parse("Cm7b5").unwrap().chord(), vec![C, EFlat, GFlat, BFlat]);
-        assert_eq!(Chord::parse("C7").unwrap().chord(), vec![C, E, G, BFlat]);
-        assert_eq!(Chord::parse("C7b9").unwrap().chord(), vec![C, E, G, BFlat, DFlatFive]);
-        assert_eq!(Chord::parse("C7b9#11").unwrap().chord(), vec![C, E, G, BFlat, DFlatFive, FSharpFive]);
-        assert_eq!(Chord::parse("C(add6)").unwrap().chord(), vec![C, E, G, A]);
-        assert_eq!(Chord::parse("Em(#5)").unwrap().chord(), vec![E, G, BSharp]);
-        assert_eq!(Chord::parse("D+11").unwrap().chord(), vec![D, FSharp, ASharp, CFive, EFive, GFive]);
-        assert_eq!(Chord::parse("Dm13b5").unwrap().chord(), vec![D, F, AFlat, CFive, EFive, GFive, BFive]);
-        assert_eq!(Chord::parse("Dsus2").unwrap().chord(), vec![D, E, A]);
-        assert_eq!(Chord::parse("Dsus4").unwrap().chord(), vec![D, G, A]);
-        assert_eq!(Chord::parse("Dadd2").unwrap().chord(), vec![D, E, FSharp, A]);
-        assert_eq!(Chord::parse("Dadd4").unwrap().chord(), vec![D, FSharp, G, A]);
-        assert_eq!(Chord::parse("Dadd9").unwrap().chord(), vec![D, FSharp, A, EFive]);
-        assert_eq!(Chord::parse("Dadd11").unwrap().chord(), vec![D, FSharp, A, GFive]);
-        assert_eq!(Chord::parse("Dadd13").unwrap().chord(), vec![D, FSharp, A, BFive]);
-        assert_eq!(Chord::parse("Dm#9").unwrap().chord(), vec![D, F, A, ESharpFive]);
-        assert_eq!(Chord::parse("Dmb11").unwrap().chord(), vec![D, F, A, GFlatFive]);
-        assert_eq!(Chord::parse("D(b13)").unwrap().chord(), vec![D, FSharp, A, BFlatFive]);
-        assert_eq!(Chord::parse("D(#13)").unwrap().chord(), vec![D, FSharp, A, BSharpFive]);
+    #[cfg(feature = "audio")]
+    fn test_waveforms() {
+        use crate::core::base::{tone_source, Waveform};
+
+        let chord = Chord::new(C);
+
+        for waveform in [Waveform::Sine, Waveform::Square, Waveform::Triangle, Waveform::Sawtooth] {
+            for (k, n) in chord.chord().into_iter().enumerate() {
+                let delay = Duration::from_millis(10) * k as u32;
+                let samples = tone_source(waveform, n.frequency(), delay, Duration::from_millis(100), Duration::ZERO).collect::<Vec<_>>();
+
+                assert!(!samples.is_empty());
+                assert!(samples.iter().all(|s| s.abs() <= waveform.gain() + f32::EPSILON));
+            }
+        }
     }
 
     #[test]
-    fn test_guess() {
-        assert_eq!(
-            Chord::try_from_notes(&[EThree, C, EFlat, FSharp, ASharp, DFive]).unwrap().first().unwrap().chord(),
-            Chord::parse("Cm9b5/E").unwrap().chord()
-        );
-        assert_eq!(Chord::try_from_notes(&[C, E, G]).unwrap().first().unwrap().chord(), Chord::parse("C").unwrap().chord());
-        assert_eq!(
-            Chord::try_from_notes(&[C, E, G, BFlat, DFive, FFive]).unwrap().first().unwrap().chord(),
-            Chord::parse("C11").unwrap().chord()
-        );
-        assert_eq!(
-            Chord::try_from_notes(&[C, E, G, BFlat, DFive, FFive, AFive]).unwrap().first().unwrap().chord(),
-            Chord::parse("C13").unwrap().chord()
-        );
-        assert_eq!(Chord::try_from_notes(&[C, EFlat, GFlat, A]).unwrap().first().unwrap().chord(), Chord::parse("Cdim").unwrap().chord());
+    #[cfg(feature = "audio")]
+    fn test_strum_delays() {
+        use crate::core::base::StrumDirection;
+
+        let chord = Chord::parse("C7").unwrap();
+        let delay = Duration::from_millis(20);
+
+        let up = chord.strum_delays(StrumDirection::Up, delay, Duration::ZERO, None);
+        let down = chord.strum_delays(StrumDirection::Down, delay, Duration::ZERO, None);
+
+        assert_eq!(up, vec![Duration::ZERO, delay, delay * 2, delay * 3]);
+        assert_eq!(down, up.iter().rev().copied().collect::<Vec<_>>());
+
+        let jitter = Duration::from_millis(5);
+        let humanized = chord.strum_delays(StrumDirection::Down, delay, jitter, Some(42));
+
+        assert_eq!(humanized, chord.strum_delays(StrumDirection::Down, delay, jitter, Some(42)));
+        assert_ne!(humanized, chord.strum_delays(StrumDirection::Down, delay, jitter, Some(7)));
+        assert!(humanized.iter().zip(&down).all(|(h, d)| *h >= *d && *h < *d + jitter));
     }
 
     #[test]
-    #[should_panic(expected = "Must have at least three notes to guess a chord.")]
-    fn test_chord_from_notes_failure() {
-        Chord::try_from_notes(&[C, E]).unwrap();
+    #[cfg(feature = "audio")]
+    fn test_tuned_tones() {
+        use crate::core::base::tone_source;
+        use rodio::Source;
+
+        let chord = Chord::parse("Am").unwrap();
+        let length = Duration::from_secs(1);
+
+        let (standard, _) = chord.tuned_tones(&Tuning::default(), Duration::ZERO, length).unwrap();
+        let (lower, delays) = chord.tuned_tones(&Tuning::equal(432.0), Duration::ZERO, length).unwrap();
+
+        // The sources are fed correspondingly lower frequencies (starting from `A4` at 432 Hz).
+        assert_eq!(lower.len(), delays.len());
+        assert!((lower[0] - 432.0).abs() < 1e-3);
+        assert!(lower.iter().zip(&standard).all(|(l, s)| (l / s - 432.0 / 440.0).abs() < 1e-5));
+
+        // The source for the root oscillates at the tuned frequency (i.e., it crosses zero twice per cycle).
+        let source = tone_source(Waveform::Sine, lower[0], delays[0], length, Duration::ZERO);
+        let sample_rate = source.sample_rate() as usize;
+        let samples = source.take(sample_rate).collect::<Vec<_>>();
+        let crossings = samples.windows(2).filter(|w| (w[0] < 0.0) != (w[1] < 0.0)).count();
+
+        assert!((crossings as f32 / 2.0 - 432.0).abs() <= 1.0);
+
+        assert!(chord.tuned_tones(&Tuning::default(), Duration::from_secs(1), length).is_err());
     }
 }
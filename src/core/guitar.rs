@@ -0,0 +1,168 @@
+//! A module for finding guitar (or other fretted instrument) voicings of chords.
+
+use crate::core::{
+    chord::{Chord, HasChord},
+    note::{ATwo, BThree, DThree, EFour, ETwo, GThree, Note},
+    pitch::HasPitch,
+};
+
+// Impls.
+
+impl Chord {
+    /// Returns the playable voicings of the chord on a fretted instrument with the given tuning (lowest string first),
+    /// considering frets `0` (open) through `frets`, ranked by hand span (then by position up the neck).
+    ///
+    /// Each voicing has one entry per string: the fret to play, or `None` if the string is muted.  A voicing is playable when
+    /// it covers every chord tone, its lowest sounding note is the bass of the chord, its sounded strings are adjacent, its
+    /// fretted notes are within [`MAX_FRET_SPAN`] frets of each other, and it needs at most four fingers (notes at the lowest
+    /// fretted position may be barred with one finger).
+    ///
+    /// Use [`STANDARD_GUITAR_TUNING`] for a guitar in standard tuning.
+    pub fn guitar_voicings(&self, tuning: &[Note], frets: u8) -> Vec<Vec<Option<u8>>> {
+        let chord = self.chord();
+
+        let Some(bass) = chord.first().map(|n| n.pitch() as u8) else {
+            return Vec::new();
+        };
+        let tones = chord.iter().fold(0u16, |mask, n| mask | 1 << n.pitch() as u8);
+
+        // Each string may be muted, or play any fret that yields a chord tone.
+        let options = tuning
+            .iter()
+            .map(|string| {
+                let mut options = vec![None];
+                options.extend((0..=frets).filter(|fret| tones & 1 << ((string.pitch() as u8 + fret) % 12) != 0).map(Some));
+                options
+            })
+            .collect::<Vec<_>>();
+
+        let mut voicings = Vec::new();
+        let mut current = Vec::with_capacity(tuning.len());
+        search_voicings(tuning, &options, &mut current, &mut voicings);
+
+        voicings.retain(|voicing| is_playable_voicing(tuning, voicing, tones, bass));
+        voicings.sort_by_key(|voicing| {
+            let (low, high) = fretted_range(voicing).unwrap_or((0, 0));
+
+            (high - low, low, voicing.iter().filter(|f| f.is_none()).count(), voicing.clone())
+        });
+
+        voicings
+    }
+}
+
+// Helpers.
+
+/// Recursively enumerates the voicings, pruning those whose fretted notes already exceed the maximum span.
+fn search_voicings(tuning: &[Note], options: &[Vec<Option<u8>>], current: &mut Vec<Option<u8>>, voicings: &mut Vec<Vec<Option<u8>>>) {
+    if current.len() == tuning.len() {
+        voicings.push(current.clone());
+        return;
+    }
+
+    for option in &options[current.len()] {
+        current.push(*option);
+
+        if fretted_range(current).map_or(true, |(low, high)| high - low <= MAX_FRET_SPAN) {
+            search_voicings(tuning, options, current, voicings);
+        }
+
+        current.pop();
+    }
+}
+
+/// Returns the lowest and highest fretted (i.e., neither open nor muted) frets of a voicing, if any.
+fn fretted_range(voicing: &[Option<u8>]) -> Option<(u8, u8)> {
+    let fretted = voicing.iter().flatten().filter(|f| **f != 0);
+
+    Some((*fretted.clone().min()?, *fretted.max()?))
+}
+
+/// Returns whether the (span constrained) voicing covers the chord tones with the bass as the lowest sounding note, using adjacent
+/// strings and at most four fingers.
+fn is_playable_voicing(tuning: &[Note], voicing: &[Option<u8>], tones: u16, bass: u8) -> bool {
+    let sounded = tuning.iter().zip(voicing).filter_map(|(string, fret)| fret.map(|f| string.to_midi() + f)).collect::<Vec<_>>();
+
+    let Some(lowest) = sounded.iter().min() else {
+        return false;
+    };
+
+    let covered = sounded.iter().fold(0u16, |mask, midi| mask | 1 << (midi % 12));
+    if covered != tones || lowest % 12 != bass {
+        return false;
+    }
+
+    let first = voicing.iter().position(Option::is_some).unwrap_or_default();
+    let last = voicing.iter().rposition(Option::is_some).unwrap_or_default();
+    if voicing[first..=last].iter().any(Option::is_none) {
+        return false;
+    }
+
+    // One finger (possibly barred) for the lowest fretted position, and one finger for each other fretted note.
+    let fingers = match fretted_range(voicing) {
+        Some((low, _)) => voicing.iter().flatten().filter(|f| **f != 0 && **f != low).count() + 1,
+        None => 0,
+    };
+
+    fingers <= 4
+}
+
+// Statics.
+
+/// The standard tuning of a six string guitar (`E2 A2 D3 G3 B3 E4`, lowest string first).
+pub const STANDARD_GUITAR_TUNING: [Note; 6] = [ETwo, ATwo, DThree, GThree, BThree, EFour];
+
+/// The maximum distance (in frets) between the lowest and highest fretted notes of a voicing.
+pub const MAX_FRET_SPAN: u8 = 3;
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{base::Parsable, note::*};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_guitar_voicings() {
+        let c = Chord::parse("C").unwrap().guitar_voicings(&STANDARD_GUITAR_TUNING, 12);
+        let e = Chord::parse("E").unwrap().guitar_voicings(&STANDARD_GUITAR_TUNING, 12);
+
+        // Open chords.
+        assert!(c.contains(&vec![None, Some(3), Some(2), Some(0), Some(1), Some(0)]));
+        assert!(e.contains(&vec![Some(0), Some(2), Some(2), Some(1), Some(0), Some(0)]));
+
+        // Barre chords.
+        assert!(c.contains(&vec![None, Some(3), Some(5), Some(5), Some(5), Some(3)]));
+        assert!(e.contains(&vec![None, Some(7), Some(9), Some(9), Some(9), Some(7)]));
+
+        for voicing in c.iter().chain(&e) {
+            assert_eq!(voicing.len(), 6);
+
+            let (low, high) = fretted_range(voicing).unwrap_or((0, 0));
+            assert!(high - low <= MAX_FRET_SPAN);
+            assert!(voicing.iter().flatten().all(|f| *f <= 12));
+        }
+
+        // Ranked by hand span.
+        let spans = c.iter().map(|v| fretted_range(v).map_or(0, |(low, high)| high - low)).collect::<Vec<_>>();
+        assert!(spans.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_guitar_voicings_slash() {
+        let voicings = Chord::parse("C/G").unwrap().guitar_voicings(&STANDARD_GUITAR_TUNING, 5);
+
+        assert!(voicings.contains(&vec![Some(3), Some(3), Some(2), Some(0), Some(1), Some(0)]));
+        assert!(voicings.iter().all(|v| v.first() == Some(&None) || v[0] == Some(3)));
+    }
+
+    #[test]
+    fn test_guitar_voicings_other_tuning() {
+        // A ukulele (re-entrant, so the lowest sounding note is not necessarily on the first string).
+        let ukulele = [GFour, C, E, AFour];
+        let voicings = Chord::parse("C").unwrap().guitar_voicings(&ukulele, 5);
+
+        assert!(voicings.contains(&vec![Some(0), Some(0), Some(0), Some(3)]));
+    }
+}
@@ -1,5 +1,9 @@
 //! Helper functions.
 
+use std::{fs::File, io::Write, path::Path};
+
+use crate::core::base::Void;
+
 /// Converts a frequency to a mel.
 pub fn mel(f: f32) -> f32 {
     2595f32 * (1f32 + f / 700f32).log10()
@@ -9,3 +13,131 @@ pub fn mel(f: f32) -> f32 {
 pub fn inv_mel(m: f32) -> f32 {
     700f32 * (10f32.powf(m / 2595f32) - 1f32)
 }
+
+/// Writes the given (mono) samples to a 16-bit PCM WAV file.
+///
+/// Samples are expected to be in the range `[-1, 1]`, and they are clamped to that range before being quantized.
+pub fn write_wav(path: &Path, samples: &[f32], sample_rate: u32) -> Void {
+    let channels = 1u16;
+    let bits_per_sample = 16u16;
+    let block_align = channels * bits_per_sample / 8;
+    let byte_rate = sample_rate * block_align as u32;
+    let data_size = samples.len() as u32 * block_align as u32;
+
+    let mut bytes = Vec::with_capacity(44 + data_size as usize);
+
+    // RIFF header.
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+
+    // Format chunk.
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&channels.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&byte_rate.to_le_bytes());
+    bytes.extend_from_slice(&block_align.to_le_bytes());
+    bytes.extend_from_slice(&bits_per_sample.to_le_bytes());
+
+    // Data chunk.
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_size.to_le_bytes());
+    for sample in samples {
+        let quantized = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        bytes.extend_from_slice(&quantized.to_le_bytes());
+    }
+
+    File::create(path)?.write_all(&bytes)?;
+
+    Ok(())
+}
+
+/// Returns the next pseudo-random value in the range `[0, 1)`, advancing the given state (using SplitMix64).
+///
+/// This is only meant for small, reproducible variations (e.g., humanizing playback), and not for anything statistical.
+#[cfg(feature = "audio")]
+pub(crate) fn next_random(state: &mut u64) -> f32 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+
+    (z >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// The number of ticks per quarter note used when writing Standard MIDI Files.
+pub const SMF_TICKS_PER_QUARTER_NOTE: u16 = 480;
+
+/// Writes a type-0 Standard MIDI File from the given sequence of (simultaneous) MIDI note numbers and their lengths in ticks.
+///
+/// Every group of notes is sounded together with the given velocity, and released after its length.
+pub fn write_smf(sequence: &[(Vec<u8>, u32)], velocity: u8) -> Vec<u8> {
+    let velocity = velocity.min(127);
+    let mut track = Vec::new();
+
+    // Set the tempo to 120 bpm (500,000 microseconds per quarter note).
+    track.extend_from_slice(&[0x00, 0xFF, 0x51, 0x03, 0x07, 0xA1, 0x20]);
+
+    // Carry the delta time forward so that empty groups (i.e., rests) are respected.
+    let mut delta = 0u32;
+
+    for (notes, ticks) in sequence {
+        let notes = notes.iter().map(|n| (*n).min(127)).collect::<Vec<_>>();
+
+        if notes.is_empty() {
+            delta += ticks;
+            continue;
+        }
+
+        for note in &notes {
+            write_variable_length_quantity(&mut track, delta);
+            track.extend_from_slice(&[0x90, *note, velocity]);
+            delta = 0;
+        }
+
+        delta = *ticks;
+
+        for note in &notes {
+            write_variable_length_quantity(&mut track, delta);
+            track.extend_from_slice(&[0x80, *note, 0]);
+            delta = 0;
+        }
+    }
+
+    // End of track.
+    write_variable_length_quantity(&mut track, delta);
+    track.extend_from_slice(&[0xFF, 0x2F, 0x00]);
+
+    let mut bytes = Vec::with_capacity(22 + track.len());
+
+    // Header chunk.
+    bytes.extend_from_slice(b"MThd");
+    bytes.extend_from_slice(&6u32.to_be_bytes());
+    bytes.extend_from_slice(&0u16.to_be_bytes());
+    bytes.extend_from_slice(&1u16.to_be_bytes());
+    bytes.extend_from_slice(&SMF_TICKS_PER_QUARTER_NOTE.to_be_bytes());
+
+    // Track chunk.
+    bytes.extend_from_slice(b"MTrk");
+    bytes.extend_from_slice(&(track.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(&track);
+
+    bytes
+}
+
+/// Writes a MIDI variable-length quantity.
+fn write_variable_length_quantity(bytes: &mut Vec<u8>, value: u32) {
+    let mut buffer = vec![(value & 0x7F) as u8];
+    let mut value = value >> 7;
+
+    while value > 0 {
+        buffer.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+
+    bytes.extend(buffer.into_iter().rev());
+}
@@ -1,6 +1,10 @@
 //! A module for working with intervals.
 
-use std::fmt::{Display, Error, Formatter};
+use std::{
+    fmt::{Display, Error, Formatter},
+    ops::{Add, Sub},
+    str::FromStr,
+};
 
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
@@ -8,7 +12,10 @@ use wasm_bindgen::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::octave::{HasOctave, Octave};
+use crate::core::{
+    base::Res,
+    octave::{HasOctave, Octave},
+};
 
 // Traits.
 
@@ -152,8 +159,354 @@ pub enum Interval {
     ThreePerfectOctavesAndMajorSeventh,
 }
 
+/// An enum representing the quality of an [`Interval`] (e.g., the "major" in "major third").
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Quality {
+    /// A perfect interval (unisons, fourths, fifths, and octaves).
+    Perfect,
+    /// A major interval (seconds, thirds, sixths, and sevenths).
+    Major,
+    /// A minor interval (a half step smaller than major).
+    Minor,
+    /// An augmented interval (a half step larger than perfect or major).
+    Augmented,
+    /// A diminished interval (a half step smaller than perfect or minor).
+    Diminished,
+}
+
 // Impls.
 
+impl Interval {
+    /// Returns the interval reduced to within one octave (e.g., a major ninth becomes a major second).
+    ///
+    /// Simple intervals (including the octave itself) are returned unchanged, and compound octaves reduce to a perfect octave.
+    pub fn simple(&self) -> Interval {
+        match self {
+            Interval::MinorNinth => Interval::MinorSecond,
+            Interval::MajorNinth => Interval::MajorSecond,
+            Interval::AugmentedNinth => Interval::AugmentedSecond,
+
+            Interval::DiminishedEleventh => Interval::DiminishedFourth,
+            Interval::PerfectEleventh => Interval::PerfectFourth,
+            Interval::AugmentedEleventh => Interval::AugmentedFourth,
+
+            Interval::MinorThirteenth => Interval::MinorSixth,
+            Interval::MajorThirteenth => Interval::MajorSixth,
+            Interval::AugmentedThirteenth => Interval::AugmentedSixth,
+
+            Interval::PerfectOctaveAndPerfectFifth => Interval::PerfectFifth,
+            Interval::TwoPerfectOctaves => Interval::PerfectOctave,
+            Interval::TwoPerfectOctavesAndMajorThird => Interval::MajorThird,
+            Interval::TwoPerfectOctavesAndPerfectFifth => Interval::PerfectFifth,
+            Interval::TwoPerfectOctavesAndMinorSeventh => Interval::MinorSeventh,
+            Interval::ThreePerfectOctaves => Interval::PerfectOctave,
+            Interval::ThreePerfectOctavesAndMajorSecond => Interval::MajorSecond,
+            Interval::ThreePerfectOctavesAndMajorThird => Interval::MajorThird,
+            Interval::ThreePerfectOctavesAndAugmentedFourth => Interval::AugmentedFourth,
+            Interval::ThreePerfectOctavesAndPerfectFifth => Interval::PerfectFifth,
+            Interval::ThreePerfectOctavesAndMinorSixth => Interval::MinorSixth,
+            Interval::ThreePerfectOctavesAndMinorSeventh => Interval::MinorSeventh,
+            Interval::ThreePerfectOctavesAndMajorSeventh => Interval::MajorSeventh,
+
+            _ => *self,
+        }
+    }
+
+    /// Returns the most common simple interval spanning the given number of semitones (e.g., `7` is a perfect fifth),
+    /// or `None` if the number of semitones is greater than an octave.
+    ///
+    /// The tritone (`6`) is spelled as an augmented fourth.
+    pub fn from_semitones(semitones: u8) -> Option<Interval> {
+        let interval = match semitones {
+            0 => Interval::PerfectUnison,
+            1 => Interval::MinorSecond,
+            2 => Interval::MajorSecond,
+            3 => Interval::MinorThird,
+            4 => Interval::MajorThird,
+            5 => Interval::PerfectFourth,
+            6 => Interval::AugmentedFourth,
+            7 => Interval::PerfectFifth,
+            8 => Interval::MinorSixth,
+            9 => Interval::MajorSixth,
+            10 => Interval::MinorSeventh,
+            11 => Interval::MajorSeventh,
+            12 => Interval::PerfectOctave,
+            _ => return None,
+        };
+
+        Some(interval)
+    }
+
+    /// Returns the number of semitones spanned by the interval (e.g., a perfect fifth spans `7`, and a major ninth spans `14`).
+    pub fn semitones(&self) -> u8 {
+        self.steps_and_semitones().1
+    }
+
+    /// Returns the size of the interval in cents in twelve-tone equal temperament (i.e., `100` cents per semitone).
+    pub fn cents(&self) -> f32 {
+        self.semitones() as f32 * 100.0
+    }
+
+    /// Returns the sum of two intervals (stacking `other` on top of this one), or `None` if the result is not a known [`Interval`].
+    ///
+    /// The letter steps and semitones are summed separately, so the result is spelled correctly (e.g., a major third plus a
+    /// major third is an augmented fifth, not a minor sixth).  Results that exceed an octave are compound intervals
+    /// (e.g., a perfect fifth plus a perfect fifth is a major ninth).
+    pub fn checked_add(&self, other: Interval) -> Option<Interval> {
+        let (steps, semitones) = self.steps_and_semitones();
+        let (other_steps, other_semitones) = other.steps_and_semitones();
+
+        Interval::from_steps_and_semitones(steps + other_steps, semitones + other_semitones)
+    }
+
+    /// Returns the difference of two intervals (removing `other` from the top of this one), or `None` if `other` is larger
+    /// than this interval, or the result is not a known [`Interval`].
+    pub fn checked_sub(&self, other: Interval) -> Option<Interval> {
+        let (steps, semitones) = self.steps_and_semitones();
+        let (other_steps, other_semitones) = other.steps_and_semitones();
+
+        Interval::from_steps_and_semitones(steps.checked_sub(other_steps)?, semitones.checked_sub(other_semitones)?)
+    }
+
+    /// Returns the quality of the interval (e.g., [`Quality::Augmented`] for an augmented fourth).
+    pub fn quality(&self) -> Quality {
+        let (steps, semitones) = self.simple().steps_and_semitones();
+
+        // The semitones of the perfect (or major) interval with the same number, and whether that interval is perfect.
+        let (reference, is_perfect) = match steps {
+            0 => (0, true),
+            1 => (2, false),
+            2 => (4, false),
+            3 => (5, true),
+            4 => (7, true),
+            5 => (9, false),
+            6 => (11, false),
+            _ => (12, true),
+        };
+
+        match (semitones as i8 - reference, is_perfect) {
+            (0, true) => Quality::Perfect,
+            (0, false) => Quality::Major,
+            (-1, false) => Quality::Minor,
+            (delta, _) if delta > 0 => Quality::Augmented,
+            _ => Quality::Diminished,
+        }
+    }
+
+    /// Returns the number of the interval (e.g., `4` for an augmented fourth, or `9` for a major ninth), where a unison is `1`.
+    pub fn number(&self) -> u8 {
+        self.steps_and_semitones().0 + 1
+    }
+
+    /// Returns the scale degree label of the interval (e.g., `5` for a perfect fifth, `b7` for a minor seventh, or `#11` for an
+    /// augmented eleventh), as used to label chord tones relative to the root.
+    ///
+    /// Major and perfect intervals are unaltered, minor intervals are flattened, and augmented intervals are sharpened.  Diminished
+    /// intervals are flattened once from perfect, and twice from major (e.g., `bb7` for a diminished seventh).
+    pub fn degree_label(&self) -> &'static str {
+        match self {
+            Interval::PerfectUnison => "1",
+            Interval::DiminishedSecond => "bb2",
+
+            Interval::AugmentedUnison => "#1",
+            Interval::MinorSecond => "b2",
+
+            Interval::MajorSecond => "2",
+            Interval::DiminishedThird => "bb3",
+
+            Interval::AugmentedSecond => "#2",
+            Interval::MinorThird => "b3",
+
+            Interval::MajorThird => "3",
+            Interval::DiminishedFourth => "b4",
+
+            Interval::AugmentedThird => "#3",
+            Interval::PerfectFourth => "4",
+
+            Interval::AugmentedFourth => "#4",
+            Interval::DiminishedFifth => "b5",
+
+            Interval::PerfectFifth => "5",
+            Interval::DiminishedSixth => "bb6",
+
+            Interval::AugmentedFifth => "#5",
+            Interval::MinorSixth => "b6",
+
+            Interval::MajorSixth => "6",
+            Interval::DiminishedSeventh => "bb7",
+
+            Interval::AugmentedSixth => "#6",
+            Interval::MinorSeventh => "b7",
+
+            Interval::MajorSeventh => "7",
+            Interval::DiminishedOctave => "b8",
+
+            Interval::AugmentedSeventh => "#7",
+            Interval::PerfectOctave => "8",
+
+            Interval::MinorNinth => "b9",
+            Interval::MajorNinth => "9",
+            Interval::AugmentedNinth => "#9",
+
+            Interval::DiminishedEleventh => "b11",
+            Interval::PerfectEleventh => "11",
+            Interval::AugmentedEleventh => "#11",
+
+            Interval::MinorThirteenth => "b13",
+            Interval::MajorThirteenth => "13",
+            Interval::AugmentedThirteenth => "#13",
+
+            Interval::PerfectOctaveAndPerfectFifth => "12",
+            Interval::TwoPerfectOctaves => "15",
+            Interval::TwoPerfectOctavesAndMajorThird => "17",
+            Interval::TwoPerfectOctavesAndPerfectFifth => "19",
+            Interval::TwoPerfectOctavesAndMinorSeventh => "b21",
+            Interval::ThreePerfectOctaves => "22",
+            Interval::ThreePerfectOctavesAndMajorSecond => "23",
+            Interval::ThreePerfectOctavesAndMajorThird => "24",
+            Interval::ThreePerfectOctavesAndAugmentedFourth => "#25",
+            Interval::ThreePerfectOctavesAndPerfectFifth => "26",
+            Interval::ThreePerfectOctavesAndMinorSixth => "b27",
+            Interval::ThreePerfectOctavesAndMinorSeventh => "b28",
+            Interval::ThreePerfectOctavesAndMajorSeventh => "28",
+        }
+    }
+
+    /// Returns the number of letter steps (e.g., `2` for any third), and the number of semitones spanned by the interval.
+    fn steps_and_semitones(&self) -> (u8, u8) {
+        let (steps, semitones) = match self.simple() {
+            Interval::PerfectUnison => (0, 0),
+            Interval::DiminishedSecond => (1, 0),
+
+            Interval::AugmentedUnison => (0, 1),
+            Interval::MinorSecond => (1, 1),
+
+            Interval::MajorSecond => (1, 2),
+            Interval::DiminishedThird => (2, 2),
+
+            Interval::AugmentedSecond => (1, 3),
+            Interval::MinorThird => (2, 3),
+
+            Interval::MajorThird => (2, 4),
+            Interval::DiminishedFourth => (3, 4),
+
+            Interval::AugmentedThird => (2, 5),
+            Interval::PerfectFourth => (3, 5),
+
+            Interval::AugmentedFourth => (3, 6),
+            Interval::DiminishedFifth => (4, 6),
+
+            Interval::PerfectFifth => (4, 7),
+            Interval::DiminishedSixth => (5, 7),
+
+            Interval::AugmentedFifth => (4, 8),
+            Interval::MinorSixth => (5, 8),
+
+            Interval::MajorSixth => (5, 9),
+            Interval::DiminishedSeventh => (6, 9),
+
+            Interval::AugmentedSixth => (5, 10),
+            Interval::MinorSeventh => (6, 10),
+
+            Interval::MajorSeventh => (6, 11),
+            Interval::DiminishedOctave => (7, 11),
+
+            Interval::AugmentedSeventh => (6, 12),
+            Interval::PerfectOctave => (7, 12),
+
+            _ => unreachable!("Simple intervals are always within the octave."),
+        };
+
+        // Add back the octaves that were removed by the reduction.
+        let octaves = self.octave() as u8 - self.simple().octave() as u8;
+
+        (steps + 7 * octaves, semitones + 12 * octaves)
+    }
+
+    /// Returns the interval with the given number of letter steps and semitones, if it is a known [`Interval`].
+    fn from_steps_and_semitones(steps: u8, semitones: u8) -> Option<Interval> {
+        ALL_INTERVALS.iter().find(|i| i.steps_and_semitones() == (steps, semitones)).copied()
+    }
+
+    /// Returns the inversion of the interval; i.e., the complementary interval within the octave
+    /// (e.g., a major third becomes a minor sixth, and an augmented fourth becomes a diminished fifth).
+    ///
+    /// Compound intervals are first reduced to their [simple](Interval::simple) form.
+    pub fn invert(&self) -> Interval {
+        match self.simple() {
+            Interval::PerfectUnison => Interval::PerfectOctave,
+            Interval::DiminishedSecond => Interval::AugmentedSeventh,
+
+            Interval::AugmentedUnison => Interval::DiminishedOctave,
+            Interval::MinorSecond => Interval::MajorSeventh,
+
+            Interval::MajorSecond => Interval::MinorSeventh,
+            Interval::DiminishedThird => Interval::AugmentedSixth,
+
+            Interval::AugmentedSecond => Interval::DiminishedSeventh,
+            Interval::MinorThird => Interval::MajorSixth,
+
+            Interval::MajorThird => Interval::MinorSixth,
+            Interval::DiminishedFourth => Interval::AugmentedFifth,
+
+            Interval::AugmentedThird => Interval::DiminishedSixth,
+            Interval::PerfectFourth => Interval::PerfectFifth,
+
+            Interval::AugmentedFourth => Interval::DiminishedFifth,
+            Interval::DiminishedFifth => Interval::AugmentedFourth,
+
+            Interval::PerfectFifth => Interval::PerfectFourth,
+            Interval::DiminishedSixth => Interval::AugmentedThird,
+
+            Interval::AugmentedFifth => Interval::DiminishedFourth,
+            Interval::MinorSixth => Interval::MajorThird,
+
+            Interval::MajorSixth => Interval::MinorThird,
+            Interval::DiminishedSeventh => Interval::AugmentedSecond,
+
+            Interval::AugmentedSixth => Interval::DiminishedThird,
+            Interval::MinorSeventh => Interval::MajorSecond,
+
+            Interval::MajorSeventh => Interval::MinorSecond,
+            Interval::DiminishedOctave => Interval::AugmentedUnison,
+
+            Interval::AugmentedSeventh => Interval::DiminishedSecond,
+            Interval::PerfectOctave => Interval::PerfectUnison,
+
+            _ => unreachable!("Simple intervals are always within the octave."),
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    /// Stacks two intervals (see [`Interval::checked_add`]).
+    ///
+    /// Panics if the result is not a known [`Interval`].
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|| panic!("{self} + {rhs} is not a valid interval"))
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    /// Removes one interval from the top of another (see [`Interval::checked_sub`]).
+    ///
+    /// Panics if the result is not a known [`Interval`].
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).unwrap_or_else(|| panic!("{self} - {rhs} is not a valid interval"))
+    }
+}
+
+impl CanReduceFrame for Interval {
+    fn reduce_frame(self) -> Self {
+        self.simple()
+    }
+}
+
 impl HasEnharmonicDistance for Interval {
     fn enharmonic_distance(&self) -> i8 {
         match self {
@@ -299,74 +652,87 @@ impl HasOctave for Interval {
 impl Display for Interval {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
         match self {
-            Interval::PerfectUnison => write!(f, "PerfectUnison"),
-            Interval::DiminishedSecond => write!(f, "DiminishedSecond"),
+            Interval::PerfectUnison => write!(f, "P1"),
+            Interval::DiminishedSecond => write!(f, "d2"),
 
-            Interval::AugmentedUnison => write!(f, "AugmentedUnison"),
-            Interval::MinorSecond => write!(f, "MinorSecond"),
+            Interval::AugmentedUnison => write!(f, "A1"),
+            Interval::MinorSecond => write!(f, "m2"),
 
-            Interval::MajorSecond => write!(f, "MajorSecond"),
-            Interval::DiminishedThird => write!(f, "DiminishedThird"),
+            Interval::MajorSecond => write!(f, "M2"),
+            Interval::DiminishedThird => write!(f, "d3"),
 
-            Interval::AugmentedSecond => write!(f, "AugmentedSecond"),
-            Interval::MinorThird => write!(f, "MinorThird"),
+            Interval::AugmentedSecond => write!(f, "A2"),
+            Interval::MinorThird => write!(f, "m3"),
 
-            Interval::MajorThird => write!(f, "MajorThird"),
-            Interval::DiminishedFourth => write!(f, "DiminishedFourth"),
+            Interval::MajorThird => write!(f, "M3"),
+            Interval::DiminishedFourth => write!(f, "d4"),
 
-            Interval::AugmentedThird => write!(f, "AugmentedThird"),
-            Interval::PerfectFourth => write!(f, "PerfectFourth"),
+            Interval::AugmentedThird => write!(f, "A3"),
+            Interval::PerfectFourth => write!(f, "P4"),
 
-            Interval::AugmentedFourth => write!(f, "AugmentedFourth"),
-            Interval::DiminishedFifth => write!(f, "DiminishedFifth"),
+            Interval::AugmentedFourth => write!(f, "A4"),
+            Interval::DiminishedFifth => write!(f, "d5"),
 
-            Interval::PerfectFifth => write!(f, "PerfectFifth"),
-            Interval::DiminishedSixth => write!(f, "DiminishedSixth"),
+            Interval::PerfectFifth => write!(f, "P5"),
+            Interval::DiminishedSixth => write!(f, "d6"),
 
-            Interval::AugmentedFifth => write!(f, "AugmentedFifth"),
-            Interval::MinorSixth => write!(f, "MinorSixth"),
+            Interval::AugmentedFifth => write!(f, "A5"),
+            Interval::MinorSixth => write!(f, "m6"),
 
-            Interval::MajorSixth => write!(f, "MajorSixth"),
-            Interval::DiminishedSeventh => write!(f, "DiminishedSeventh"),
+            Interval::MajorSixth => write!(f, "M6"),
+            Interval::DiminishedSeventh => write!(f, "d7"),
 
-            Interval::AugmentedSixth => write!(f, "AugmentedSixth"),
-            Interval::MinorSeventh => write!(f, "MinorSeventh"),
+            Interval::AugmentedSixth => write!(f, "A6"),
+            Interval::MinorSeventh => write!(f, "m7"),
 
-            Interval::MajorSeventh => write!(f, "MajorSeventh"),
-            Interval::DiminishedOctave => write!(f, "DiminishedOctave"),
+            Interval::MajorSeventh => write!(f, "M7"),
+            Interval::DiminishedOctave => write!(f, "d8"),
 
-            Interval::AugmentedSeventh => write!(f, "AugmentedSeventh"),
-            Interval::PerfectOctave => write!(f, "PerfectOctave"),
+            Interval::AugmentedSeventh => write!(f, "A7"),
+            Interval::PerfectOctave => write!(f, "P8"),
 
-            Interval::MinorNinth => write!(f, "MinorNinth"),
-            Interval::MajorNinth => write!(f, "MajorNinth"),
-            Interval::AugmentedNinth => write!(f, "AugmentedNinth"),
+            Interval::MinorNinth => write!(f, "m9"),
+            Interval::MajorNinth => write!(f, "M9"),
+            Interval::AugmentedNinth => write!(f, "A9"),
 
-            Interval::DiminishedEleventh => write!(f, "DiminishedEleventh"),
-            Interval::PerfectEleventh => write!(f, "PerfectEleventh"),
-            Interval::AugmentedEleventh => write!(f, "AugmentedEleventh"),
+            Interval::DiminishedEleventh => write!(f, "d11"),
+            Interval::PerfectEleventh => write!(f, "P11"),
+            Interval::AugmentedEleventh => write!(f, "A11"),
 
-            Interval::MinorThirteenth => write!(f, "MinorThirteenth"),
-            Interval::MajorThirteenth => write!(f, "MajorThirteenth"),
-            Interval::AugmentedThirteenth => write!(f, "AugmentedThirteenth"),
+            Interval::MinorThirteenth => write!(f, "m13"),
+            Interval::MajorThirteenth => write!(f, "M13"),
+            Interval::AugmentedThirteenth => write!(f, "A13"),
 
-            Interval::PerfectOctaveAndPerfectFifth => write!(f, "PerfectOctaveAndPerfectFifth"),
-            Interval::TwoPerfectOctaves => write!(f, "TwoPerfectOctaves"),
-            Interval::TwoPerfectOctavesAndMajorThird => write!(f, "TwoPerfectOctavesAndMajorThird"),
-            Interval::TwoPerfectOctavesAndPerfectFifth => write!(f, "TwoPerfectOctavesAndPerfectFifth"),
-            Interval::TwoPerfectOctavesAndMinorSeventh => write!(f, "TwoPerfectOctavesAndMinorSeventh"),
-            Interval::ThreePerfectOctaves => write!(f, "ThreePerfectOctaves"),
-            Interval::ThreePerfectOctavesAndMajorSecond => write!(f, "ThreePerfectOctavesAndMajorSecond"),
-            Interval::ThreePerfectOctavesAndMajorThird => write!(f, "ThreePerfectOctavesAndMajorThird"),
-            Interval::ThreePerfectOctavesAndAugmentedFourth => write!(f, "ThreePerfectOctavesAndAugmentedFourth"),
-            Interval::ThreePerfectOctavesAndPerfectFifth => write!(f, "ThreePerfectOctavesAndPerfectFifth"),
-            Interval::ThreePerfectOctavesAndMinorSixth => write!(f, "ThreePerfectOctavesAndMinorSixth"),
-            Interval::ThreePerfectOctavesAndMinorSeventh => write!(f, "ThreePerfectOctavesAndMinorSeventh"),
-            Interval::ThreePerfectOctavesAndMajorSeventh => write!(f, "ThreePerfectOctavesAndMajorSeventh"),
+            Interval::PerfectOctaveAndPerfectFifth => write!(f, "P12"),
+            Interval::TwoPerfectOctaves => write!(f, "P15"),
+            Interval::TwoPerfectOctavesAndMajorThird => write!(f, "M17"),
+            Interval::TwoPerfectOctavesAndPerfectFifth => write!(f, "P19"),
+            Interval::TwoPerfectOctavesAndMinorSeventh => write!(f, "m21"),
+            Interval::ThreePerfectOctaves => write!(f, "P22"),
+            Interval::ThreePerfectOctavesAndMajorSecond => write!(f, "M23"),
+            Interval::ThreePerfectOctavesAndMajorThird => write!(f, "M24"),
+            Interval::ThreePerfectOctavesAndAugmentedFourth => write!(f, "A25"),
+            Interval::ThreePerfectOctavesAndPerfectFifth => write!(f, "P26"),
+            Interval::ThreePerfectOctavesAndMinorSixth => write!(f, "m27"),
+            Interval::ThreePerfectOctavesAndMinorSeventh => write!(f, "m28"),
+            Interval::ThreePerfectOctavesAndMajorSeventh => write!(f, "M28"),
         }
     }
 }
 
+impl FromStr for Interval {
+    type Err = anyhow::Error;
+
+    /// Parses the standard shorthand (e.g., `P5`, `m3`, `A4`, `d5`, or `M9`) produced by the [`Display`] implementation.
+    fn from_str(s: &str) -> Res<Self> {
+        ALL_INTERVALS
+            .iter()
+            .find(|i| i.to_string() == s)
+            .copied()
+            .ok_or_else(|| anyhow::Error::msg(format!("Invalid interval `{}` (e.g., use `P5`, `m3`, or `M9`).", s)))
+    }
+}
+
 // Statics.
 
 /// All known [`Interval`]s.
@@ -437,3 +803,202 @@ pub static PRIMARY_HARMONIC_SERIES: [Interval; 13] = [
     Interval::ThreePerfectOctavesAndMinorSeventh,
     Interval::ThreePerfectOctavesAndMajorSeventh,
 ];
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{named_pitch::HasNamedPitch, note::*};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_simple() {
+        assert_eq!(Interval::MajorNinth.simple(), Interval::MajorSecond);
+        assert_eq!(Interval::AugmentedEleventh.simple(), Interval::AugmentedFourth);
+        assert_eq!(Interval::ThreePerfectOctavesAndMajorSeventh.simple(), Interval::MajorSeventh);
+        assert_eq!(Interval::TwoPerfectOctaves.simple(), Interval::PerfectOctave);
+        assert_eq!(Interval::PerfectOctave.simple(), Interval::PerfectOctave);
+        assert_eq!(Interval::MinorThird.reduce_frame(), Interval::MinorThird);
+
+        for interval in ALL_INTERVALS {
+            let simple = interval.simple();
+
+            assert_eq!(simple.simple(), simple);
+            assert_eq!(simple.enharmonic_distance(), interval.enharmonic_distance());
+            assert_eq!((C + simple).named_pitch(), (C + interval).named_pitch());
+            assert!(simple.octave() == Octave::Zero || matches!(simple, Interval::PerfectOctave | Interval::AugmentedSeventh));
+        }
+    }
+
+    #[test]
+    fn test_quality_and_number() {
+        assert_eq!(Interval::AugmentedFourth.quality(), Quality::Augmented);
+        assert_eq!(Interval::AugmentedFourth.number(), 4);
+        assert_eq!(Interval::MinorSeventh.quality(), Quality::Minor);
+        assert_eq!(Interval::MinorSeventh.number(), 7);
+        assert_eq!(Interval::DiminishedOctave.quality(), Quality::Diminished);
+        assert_eq!(Interval::DiminishedOctave.number(), 8);
+        assert_eq!(Interval::MajorThirteenth.quality(), Quality::Major);
+        assert_eq!(Interval::MajorThirteenth.number(), 13);
+
+        // The quality and number agree with the shorthand.
+        for interval in ALL_INTERVALS {
+            let quality = match interval.quality() {
+                Quality::Perfect => "P",
+                Quality::Major => "M",
+                Quality::Minor => "m",
+                Quality::Augmented => "A",
+                Quality::Diminished => "d",
+            };
+
+            assert_eq!(format!("{}{}", quality, interval.number()), interval.to_string());
+        }
+    }
+
+    #[test]
+    fn test_degree_label() {
+        assert_eq!(Interval::PerfectUnison.degree_label(), "1");
+        assert_eq!(Interval::MinorSeventh.degree_label(), "b7");
+        assert_eq!(Interval::DiminishedFifth.degree_label(), "b5");
+        assert_eq!(Interval::DiminishedSeventh.degree_label(), "bb7");
+        assert_eq!(Interval::AugmentedEleventh.degree_label(), "#11");
+
+        for interval in ALL_INTERVALS {
+            assert!(interval.degree_label().ends_with(&interval.number().to_string()));
+        }
+    }
+
+    #[test]
+    fn test_from_semitones() {
+        assert_eq!(Interval::from_semitones(0), Some(Interval::PerfectUnison));
+        assert_eq!(Interval::from_semitones(6), Some(Interval::AugmentedFourth));
+        assert_eq!(Interval::from_semitones(7), Some(Interval::PerfectFifth));
+        assert_eq!(Interval::from_semitones(12), Some(Interval::PerfectOctave));
+        assert_eq!(Interval::from_semitones(13), None);
+
+        for semitones in 0..=12 {
+            let interval = Interval::from_semitones(semitones).unwrap();
+
+            assert_eq!((C + interval).to_midi() - C.to_midi(), semitones);
+        }
+    }
+
+    #[test]
+    fn test_add() {
+        // Stacked thirds.
+        assert_eq!(Interval::MajorThird + Interval::MinorThird, Interval::PerfectFifth);
+        assert_eq!(Interval::MinorThird + Interval::MajorThird, Interval::PerfectFifth);
+        assert_eq!(Interval::MajorThird + Interval::MajorThird, Interval::AugmentedFifth);
+        assert_eq!(Interval::MinorThird + Interval::MinorThird, Interval::DiminishedFifth);
+        assert_eq!(Interval::MinorThird + Interval::MinorThird + Interval::MinorThird, Interval::DiminishedSeventh);
+        assert_eq!(Interval::MajorThird + Interval::MajorThird + Interval::MajorThird, Interval::AugmentedSeventh);
+        assert_eq!(Interval::PerfectFifth + Interval::MajorThird, Interval::MajorSeventh);
+        assert_eq!(Interval::DiminishedFifth + Interval::MinorThird, Interval::DiminishedSeventh);
+
+        // Compound results.
+        assert_eq!(Interval::MajorSeventh + Interval::MinorThird, Interval::MajorNinth);
+        assert_eq!(Interval::MinorSeventh + Interval::MajorThird, Interval::MajorNinth);
+        assert_eq!(Interval::MajorNinth + Interval::MinorThird, Interval::PerfectEleventh);
+        assert_eq!(Interval::PerfectEleventh + Interval::MajorThird, Interval::MajorThirteenth);
+        assert_eq!(Interval::PerfectOctave + Interval::PerfectOctave, Interval::TwoPerfectOctaves);
+
+        // Unknown results.
+        assert_eq!(Interval::DiminishedSecond.checked_add(Interval::DiminishedSecond), None);
+        assert_eq!(Interval::PerfectOctave.checked_add(Interval::MinorThird), None);
+
+        // Adding intervals agrees with adding them to notes.
+        for a in ALL_INTERVALS.iter().take(26) {
+            for b in ALL_INTERVALS.iter().take(26) {
+                if let Some(sum) = a.checked_add(*b) {
+                    assert_eq!((C + sum).named_pitch(), (C + *a + *b).named_pitch(), "{a} + {b}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sub() {
+        assert_eq!(Interval::PerfectFifth - Interval::MajorThird, Interval::MinorThird);
+        assert_eq!(Interval::PerfectFifth - Interval::MinorThird, Interval::MajorThird);
+        assert_eq!(Interval::AugmentedFifth - Interval::MajorThird, Interval::MajorThird);
+        assert_eq!(Interval::PerfectOctave - Interval::PerfectFifth, Interval::PerfectFourth);
+        assert_eq!(Interval::MajorNinth - Interval::PerfectOctave, Interval::MajorSecond);
+        assert_eq!(Interval::MinorThird.checked_sub(Interval::PerfectFifth), None);
+
+        for a in ALL_INTERVALS {
+            for b in ALL_INTERVALS {
+                if let Some(sum) = a.checked_add(b) {
+                    assert_eq!(sum - b, a, "{a} + {b} - {b}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_semitones() {
+        assert_eq!(Interval::PerfectUnison.semitones(), 0);
+        assert_eq!(Interval::DiminishedSecond.semitones(), 0);
+        assert_eq!(Interval::PerfectFifth.semitones(), 7);
+        assert_eq!(Interval::AugmentedSeventh.semitones(), 12);
+        assert_eq!(Interval::MajorNinth.semitones(), 14);
+        assert_eq!(Interval::ThreePerfectOctavesAndMajorSeventh.semitones(), 47);
+
+        for interval in ALL_INTERVALS {
+            assert_eq!(interval.semitones(), (C + interval).to_midi() - C.to_midi(), "{interval}");
+        }
+    }
+
+    #[test]
+    fn test_cents() {
+        assert_eq!(Interval::PerfectUnison.cents(), 0.0);
+        assert_eq!(Interval::PerfectFifth.cents(), 700.0);
+        assert_eq!(Interval::PerfectOctave.cents(), 1200.0);
+        assert_eq!(Interval::MajorNinth.cents(), 1400.0);
+
+        for interval in ALL_INTERVALS {
+            assert!((interval.cents() - (C + interval).cents_above(&C)).abs() < 1.0, "{interval}");
+        }
+    }
+
+    #[test]
+    fn test_invert() {
+        assert_eq!(Interval::MajorThird.invert(), Interval::MinorSixth);
+        assert_eq!(Interval::MinorSixth.invert(), Interval::MajorThird);
+        assert_eq!(Interval::PerfectFifth.invert(), Interval::PerfectFourth);
+        assert_eq!(Interval::AugmentedFourth.invert(), Interval::DiminishedFifth);
+        assert_eq!(Interval::AugmentedSixth.invert(), Interval::DiminishedThird);
+        assert_eq!(Interval::PerfectUnison.invert(), Interval::PerfectOctave);
+        assert_eq!(Interval::MajorNinth.invert(), Interval::MinorSeventh);
+
+        for interval in ALL_INTERVALS {
+            let inverted = interval.invert();
+
+            // Inverting twice yields the simple interval.
+            assert_eq!(inverted.invert(), interval.simple(), "{}", interval);
+
+            // An interval and its inversion always span an octave (enharmonically).
+            assert_eq!(inverted.enharmonic_distance(), -interval.enharmonic_distance(), "{}", interval);
+            assert_eq!((C + interval + inverted).named_pitch(), C.named_pitch(), "{}", interval);
+        }
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(Interval::PerfectFifth.to_string(), "P5");
+        assert_eq!(Interval::MinorThird.to_string(), "m3");
+        assert_eq!(Interval::MajorSeventh.to_string(), "M7");
+        assert_eq!(Interval::AugmentedFourth.to_string(), "A4");
+        assert_eq!(Interval::DiminishedFifth.to_string(), "d5");
+        assert_eq!(Interval::MajorNinth.to_string(), "M9");
+        assert_eq!(Interval::TwoPerfectOctaves.to_string(), "P15");
+
+        assert_eq!(Interval::from_str("A11").unwrap(), Interval::AugmentedEleventh);
+        assert!(Interval::from_str("P3").is_err());
+        assert!(Interval::from_str("").is_err());
+
+        for interval in ALL_INTERVALS {
+            assert_eq!(Interval::from_str(&interval.to_string()).unwrap(), interval);
+        }
+    }
+}
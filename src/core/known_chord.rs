@@ -31,6 +31,15 @@ pub trait HasRelativeChord {
     fn relative_chord(&self) -> Vec<Interval>;
 }
 
+/// A trait for types that have scale names.
+pub trait HasScaleNames {
+    /// Returns the names of the scales (or modes) that fit the type (usually a [`Chord`]).
+    ///
+    /// Each name describes the relative scale (e.g., a dominant chord yields
+    /// `["mixolydian"]`), as opposed to the free-form description.
+    fn scale_names(&self) -> &'static [&'static str];
+}
+
 // Enum.
 
 /// An enum representing a known chord.
@@ -72,6 +81,75 @@ pub enum KnownChord {
 
 // Impls.
 
+impl KnownChord {
+    /// Returns the known chord whose [relative chord](HasRelativeChord::relative_chord) matches the given intervals (in any order),
+    /// or [`KnownChord::Unknown`] if none match.
+    ///
+    /// The degree of the dominant-like chords is taken from the highest of the major ninth, perfect eleventh, and major thirteenth
+    /// intervals that are present (e.g., a dominant chord with a major ninth is [`KnownChord::Dominant`] of [`Degree::Nine`]).
+    pub fn from_relative_chord(intervals: &[Interval]) -> KnownChord {
+        const DEGREE_INTERVALS: [Interval; 3] = [Interval::MajorNinth, Interval::PerfectEleventh, Interval::MajorThirteenth];
+
+        let degree = if intervals.contains(&Interval::MajorThirteenth) {
+            Degree::Thirteen
+        } else if intervals.contains(&Interval::PerfectEleventh) {
+            Degree::Eleven
+        } else if intervals.contains(&Interval::MajorNinth) {
+            Degree::Nine
+        } else {
+            Degree::Seven
+        };
+
+        let candidates = [
+            KnownChord::Major,
+            KnownChord::Minor,
+            KnownChord::Major7,
+            KnownChord::Dominant(degree),
+            KnownChord::MinorMajor7,
+            KnownChord::MinorDominant(degree),
+            KnownChord::DominantSharp11(degree),
+            KnownChord::Augmented,
+            KnownChord::AugmentedMajor7,
+            KnownChord::AugmentedDominant(degree),
+            KnownChord::HalfDiminished(degree),
+            KnownChord::Diminished,
+            KnownChord::DominantFlat9(degree),
+            KnownChord::DominantSharp9(degree),
+        ];
+
+        let mut best = (KnownChord::Unknown, 0);
+
+        for candidate in candidates {
+            let relative_chord = candidate.relative_chord();
+
+            // Any intervals beyond the relative chord must only be the degree of a chord that has one.
+            let has_degree = matches!(candidate.degree(), Some(d) if d == degree);
+            let is_match = relative_chord.iter().all(|i| intervals.contains(i)) && intervals.iter().all(|i| relative_chord.contains(i) || (has_degree && DEGREE_INTERVALS.contains(i)));
+
+            // Prefer the most specific match (e.g., a dominant sharp 11 over a dominant).
+            if is_match && relative_chord.len() > best.1 {
+                best = (candidate, relative_chord.len());
+            }
+        }
+
+        best.0
+    }
+
+    /// Returns the degree of the known chord, if it has one (e.g., [`Degree::Nine`] for [`KnownChord::Dominant`] of [`Degree::Nine`]).
+    fn degree(&self) -> Option<Degree> {
+        match self {
+            KnownChord::Dominant(d)
+            | KnownChord::MinorDominant(d)
+            | KnownChord::DominantSharp11(d)
+            | KnownChord::AugmentedDominant(d)
+            | KnownChord::HalfDiminished(d)
+            | KnownChord::DominantFlat9(d)
+            | KnownChord::DominantSharp9(d) => Some(*d),
+            _ => None,
+        }
+    }
+}
+
 impl HasDescription for KnownChord {
     fn description(&self) -> &'static str {
         match self {
@@ -229,6 +307,28 @@ impl HasRelativeScale for KnownChord {
     }
 }
 
+impl HasScaleNames for KnownChord {
+    fn scale_names(&self) -> &'static [&'static str] {
+        match self {
+            KnownChord::Unknown => &[],
+            KnownChord::Major => &["major", "ionian"],
+            KnownChord::Minor => &["natural minor", "aeolian"],
+            KnownChord::Major7 => &["ionian", "major"],
+            KnownChord::Dominant(_) => &["mixolydian"],
+            KnownChord::MinorMajor7 => &["melodic minor"],
+            KnownChord::MinorDominant(_) => &["dorian"],
+            KnownChord::DominantSharp11(_) => &["lydian dominant"],
+            KnownChord::Augmented => &["ionian augmented"],
+            KnownChord::AugmentedMajor7 => &["lydian augmented"],
+            KnownChord::AugmentedDominant(_) => &["whole tone"],
+            KnownChord::HalfDiminished(_) => &["locrian"],
+            KnownChord::Diminished => &["whole/half diminished"],
+            KnownChord::DominantFlat9(_) => &["half/whole diminished"],
+            KnownChord::DominantSharp9(_) => &["altered", "super locrian", "diminished whole tone"],
+        }
+    }
+}
+
 impl HasRelativeChord for KnownChord {
     fn relative_chord(&self) -> Vec<Interval> {
         match self {
@@ -278,3 +378,86 @@ impl HasName for KnownChord {
         }
     }
 }
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_scale_names() {
+        assert_eq!(KnownChord::Major7.scale_names(), ["ionian", "major"]);
+        assert_eq!(KnownChord::Dominant(Degree::Seven).scale_names(), ["mixolydian"]);
+        assert_eq!(KnownChord::HalfDiminished(Degree::Seven).scale_names(), ["locrian"]);
+        assert!(KnownChord::Unknown.scale_names().is_empty());
+    }
+
+    #[test]
+    fn test_from_relative_chord() {
+        assert_eq!(KnownChord::from_relative_chord(&KnownChord::Major.relative_chord()), KnownChord::Major);
+        assert_eq!(
+            KnownChord::from_relative_chord(&KnownChord::Dominant(Degree::Seven).relative_chord()),
+            KnownChord::Dominant(Degree::Seven)
+        );
+        assert_eq!(
+            KnownChord::from_relative_chord(&KnownChord::HalfDiminished(Degree::Seven).relative_chord()),
+            KnownChord::HalfDiminished(Degree::Seven)
+        );
+
+        // Order does not matter.
+        assert_eq!(
+            KnownChord::from_relative_chord(&[Interval::MinorSeventh, Interval::PerfectFifth, Interval::MinorThird, Interval::PerfectUnison]),
+            KnownChord::MinorDominant(Degree::Seven)
+        );
+
+        // Degrees.
+        assert_eq!(
+            KnownChord::from_relative_chord(&[Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth, Interval::MinorSeventh, Interval::MajorNinth]),
+            KnownChord::Dominant(Degree::Nine)
+        );
+        assert_eq!(
+            KnownChord::from_relative_chord(&[
+                Interval::PerfectUnison,
+                Interval::MajorThird,
+                Interval::PerfectFifth,
+                Interval::MinorSeventh,
+                Interval::MinorNinth,
+                Interval::MajorThirteenth
+            ]),
+            KnownChord::DominantFlat9(Degree::Thirteen)
+        );
+
+        // Unknown.
+        assert_eq!(
+            KnownChord::from_relative_chord(&[Interval::PerfectUnison, Interval::MajorSecond, Interval::PerfectFifth]),
+            KnownChord::Unknown
+        );
+        assert_eq!(
+            KnownChord::from_relative_chord(&[Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth, Interval::MajorNinth]),
+            KnownChord::Unknown
+        );
+        assert_eq!(KnownChord::from_relative_chord(&[]), KnownChord::Unknown);
+
+        // Every known chord round-trips (the relative chords do not carry the degree).
+        for known_chord in [
+            KnownChord::Major,
+            KnownChord::Minor,
+            KnownChord::Major7,
+            KnownChord::Dominant(Degree::Seven),
+            KnownChord::MinorMajor7,
+            KnownChord::MinorDominant(Degree::Seven),
+            KnownChord::DominantSharp11(Degree::Seven),
+            KnownChord::Augmented,
+            KnownChord::AugmentedMajor7,
+            KnownChord::AugmentedDominant(Degree::Seven),
+            KnownChord::HalfDiminished(Degree::Seven),
+            KnownChord::Diminished,
+            KnownChord::DominantFlat9(Degree::Seven),
+            KnownChord::DominantSharp9(Degree::Seven),
+        ] {
+            assert_eq!(KnownChord::from_relative_chord(&known_chord.relative_chord()), known_chord);
+        }
+    }
+}
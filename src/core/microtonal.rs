@@ -0,0 +1,155 @@
+//! A module for working with microtonal pitches in arbitrary equal divisions of the octave (EDO), such as 19-EDO or 24-EDO.
+//!
+//! This is kept separate from the (twelve-based) [`Pitch`](crate::core::pitch::Pitch) and [`Note`](crate::core::note::Note)
+//! types, which always use twelve-tone equal temperament (i.e., 12-EDO).
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{base::Res, pitch::HasFrequency, tuning::STANDARD_A4};
+
+// Struct.
+
+/// A struct representing a pitch in an equal division of the octave (EDO).
+///
+/// The pitch is the number of steps (each `1200 / division` cents) above `C0`, so steps beyond the division are in higher octaves.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct EdoPitch {
+    /// The number of equal steps in the octave.
+    division: u16,
+    /// The number of steps above `C0`.
+    step: u16,
+}
+
+// Impls.
+
+impl EdoPitch {
+    /// Returns a new pitch that is the given number of steps above `C0` in the given equal division of the octave.
+    pub fn new(division: u16, step: u16) -> Res<Self> {
+        if division == 0 {
+            return Err(anyhow::Error::msg("The division of the octave must be at least one step."));
+        }
+
+        Ok(Self { division, step })
+    }
+
+    /// Returns the nearest pitch (in the given equal division of the octave) to the given frequency, using the given reference
+    /// frequency for `A4` (e.g., `440.0`, or `432.0`).
+    ///
+    /// Frequencies below `C0` are clamped to `C0`.
+    pub fn from_frequency(division: u16, frequency: f32, a4: f32) -> Res<Self> {
+        if frequency.is_nan() || frequency <= 0.0 {
+            return Err(anyhow::Error::msg("The frequency must be positive."));
+        }
+
+        let steps = (division as f32 * (frequency / c0_frequency(a4)).log2()).round();
+
+        Self::new(division, steps.clamp(0.0, u16::MAX as f32) as u16)
+    }
+
+    /// Returns the number of equal steps in the octave.
+    pub fn division(&self) -> u16 {
+        self.division
+    }
+
+    /// Returns the number of steps above `C0`.
+    pub fn step(&self) -> u16 {
+        self.step
+    }
+
+    /// Returns the octave of the pitch (where the octave of `C0` is `0`).
+    pub fn octave(&self) -> u16 {
+        self.step / self.division
+    }
+
+    /// Returns the number of steps above the `C` of the pitch's octave.
+    pub fn step_in_octave(&self) -> u16 {
+        self.step % self.division
+    }
+
+    /// Returns the number of cents above the `C` of the pitch's octave (e.g., `50` for the first step of 24-EDO).
+    pub fn cents(&self) -> f32 {
+        1200.0 * self.step_in_octave() as f32 / self.division as f32
+    }
+
+    /// Returns the frequency of the pitch, using the given reference frequency for `A4`.
+    pub fn frequency_with(&self, a4: f32) -> f32 {
+        c0_frequency(a4) * 2f32.powf(self.step as f32 / self.division as f32)
+    }
+
+    /// Returns the number of cents that the given frequency is above the pitch (negative if it is below), using the given
+    /// reference frequency for `A4`.
+    pub fn cents_from(&self, frequency: f32, a4: f32) -> f32 {
+        1200.0 * (frequency / self.frequency_with(a4)).log2()
+    }
+}
+
+impl HasFrequency for EdoPitch {
+    fn frequency(&self) -> f32 {
+        self.frequency_with(STANDARD_A4)
+    }
+}
+
+// Helpers.
+
+/// Returns the frequency of `C0` (57 equal-tempered semitones below `A4`) for the given reference frequency for `A4`.
+fn c0_frequency(a4: f32) -> f32 {
+    a4 * 2f32.powf(-57.0 / 12.0)
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::note::{HasNoteId, ALL_PITCH_NOTES};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_quarter_tones() {
+        // 24-EDO steps are quarter tones (50 cents).
+        for step in 0..24 {
+            let pitch = EdoPitch::new(24, 24 * 4 + step).unwrap();
+
+            assert_eq!(pitch.octave(), 4);
+            assert!((pitch.cents() - 50.0 * step as f32).abs() < 1e-3);
+        }
+
+        // `A4` is 18 steps (900 cents) above `C4`.
+        let a4 = EdoPitch::new(24, 24 * 4 + 18).unwrap();
+        assert!((a4.frequency() - 440.0).abs() < 0.01);
+
+        // A quarter tone above `A4` quantizes to the next step.
+        let a4_up = EdoPitch::from_frequency(24, 440.0 * 2f32.powf(50.0 / 1200.0), 440.0).unwrap();
+        assert_eq!(a4_up, EdoPitch::new(24, 24 * 4 + 19).unwrap());
+        assert!((a4_up.cents() - 950.0).abs() < 1e-3);
+        assert!((a4_up.cents_from(440.0, 440.0) + 50.0).abs() < 0.01);
+
+        // A frequency between steps quantizes to the nearest one, and reports how far off it is.
+        let pitch = EdoPitch::from_frequency(24, 440.0 * 2f32.powf(20.0 / 1200.0), 440.0).unwrap();
+        assert_eq!(pitch, a4);
+        assert!((pitch.cents_from(440.0 * 2f32.powf(20.0 / 1200.0), 440.0) - 20.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_other_divisions() {
+        // 12-EDO agrees with the (equal-tempered) notes.
+        for note in ALL_PITCH_NOTES.iter().take(9 * 12) {
+            let pitch = EdoPitch::new(12, note.id_index() as u16).unwrap();
+
+            assert!((pitch.frequency() / note.frequency() - 1.0).abs() < 1e-5);
+        }
+
+        // 19-EDO steps are about 63.16 cents.
+        let pitch = EdoPitch::new(19, 19 * 4 + 1).unwrap();
+        assert!((pitch.cents() - 1200.0 / 19.0).abs() < 1e-3);
+        assert_eq!(EdoPitch::from_frequency(19, pitch.frequency_with(432.0), 432.0).unwrap(), pitch);
+
+        // Errors and clamping.
+        assert!(EdoPitch::new(0, 0).is_err());
+        assert!(EdoPitch::from_frequency(24, 0.0, 440.0).is_err());
+        assert!(EdoPitch::from_frequency(24, f32::NAN, 440.0).is_err());
+        assert_eq!(EdoPitch::from_frequency(24, 1.0, 440.0).unwrap().step(), 0);
+    }
+}
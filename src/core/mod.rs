@@ -2,9 +2,11 @@
 
 pub mod base;
 pub mod chord;
+pub mod guitar;
 pub mod helpers;
 pub mod interval;
 pub mod known_chord;
+pub mod microtonal;
 pub mod modifier;
 pub mod named_pitch;
 pub mod note;
@@ -12,3 +14,5 @@ pub mod octave;
 #[allow(missing_docs)]
 pub mod parser;
 pub mod pitch;
+pub mod progression;
+pub mod tuning;
@@ -108,6 +108,21 @@ pub enum Extension {
     Add13,
 }
 
+/// An enum representing a tone omitted from a chord.
+///
+/// Omissions do not change how the chord is interpreted by the system.  E.g., a `C7(no5)` is still
+/// a dominant chord, but its fifth is not voiced.  A power chord (e.g., `C5`) is a major chord with
+/// no third.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Omission {
+    /// No third.
+    No3,
+    /// No fifth.
+    No5,
+}
+
 // Impls.
 
 impl HasIsDominant for Modifier {
@@ -173,6 +188,16 @@ impl HasStaticName for Extension {
     }
 }
 
+impl HasStaticName for Omission {
+    #[coverage(off)]
+    fn static_name(&self) -> &'static str {
+        match self {
+            Omission::No3 => "no3",
+            Omission::No5 => "no5",
+        }
+    }
+}
+
 // Helpers.
 
 /// Returns the sets of modifiers that have associated known chords.
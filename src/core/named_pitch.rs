@@ -141,8 +141,64 @@ pub enum NamedPitch {
     BTripleSharp,
 }
 
+/// An enum representing a preferred accidental when spelling a pitch.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Accidental {
+    /// Prefer sharps (e.g., `F♯` over `G♭`).
+    Sharp,
+    /// Prefer flats (e.g., `G♭` over `F♯`).
+    Flat,
+    /// Prefer naturals (e.g., `B` over `C♭`).
+    #[default]
+    Natural,
+}
+
 // Impls.
 
+impl NamedPitch {
+    /// Returns the number of accidentals on the named pitch (negative for flats, positive for sharps).
+    pub fn accidental_count(&self) -> i8 {
+        (ALL_PITCHES.iter().position(|p| p == self).unwrap() / 7) as i8 - 3
+    }
+
+    /// Returns the enharmonically equal named pitch with the preferred accidental (e.g., `G♭` respelled with sharps is `F♯`).
+    ///
+    /// Natural spellings always win when they exist (e.g., `C♭` is respelled as `B`, and `C` stays `C`).  Otherwise, when no
+    /// spelling with the preferred accidental exists, the spelling with the fewest accidentals is chosen, and ties keep the
+    /// original spelling (e.g., `C♯` respelled with naturals is `C♯`).
+    pub fn respell(&self, prefer: Accidental) -> NamedPitch {
+        let target = match prefer {
+            Accidental::Sharp => 1,
+            Accidental::Flat => -1,
+            Accidental::Natural => 0,
+        };
+
+        // Enharmonic spellings are twelve fifths apart.
+        let index = ALL_PITCHES.iter().position(|p| p == self).unwrap();
+
+        ALL_PITCHES
+            .iter()
+            .skip(index % 12)
+            .step_by(12)
+            .min_by_key(|p| (p.accidental_count() != 0, p.accidental_count() != target, p.accidental_count().abs(), *p != self))
+            .cloned()
+            .unwrap()
+    }
+
+    /// Returns every enharmonically equal named pitch with at most two accidentals (including this one), ordered by the number
+    /// of accidentals (with sharps before flats); e.g., `E`, `F♭`, and `D𝄪`, or `C♯` and `D♭`.
+    pub fn enharmonics(&self) -> Vec<NamedPitch> {
+        // Enharmonic spellings are twelve fifths apart.
+        let index = ALL_PITCHES.iter().position(|p| p == self).unwrap();
+
+        let mut result = ALL_PITCHES.iter().skip(index % 12).step_by(12).filter(|p| p.accidental_count().abs() <= 2).cloned().collect::<Vec<_>>();
+        result.sort_by_key(|p| (p.accidental_count().abs(), p.accidental_count() < 0));
+
+        result
+    }
+}
+
 impl HasNamedPitch for NamedPitch {
     fn named_pitch(&self) -> NamedPitch {
         *self
@@ -458,6 +514,25 @@ mod tests {
         assert_eq!(NamedPitch::A.named_pitch(), NamedPitch::A);
     }
 
+    #[test]
+    fn test_respell() {
+        assert_eq!(NamedPitch::GFlat.respell(Accidental::Sharp), NamedPitch::FSharp);
+        assert_eq!(NamedPitch::FSharp.respell(Accidental::Flat), NamedPitch::GFlat);
+        assert_eq!(NamedPitch::CFlat.respell(Accidental::Natural), NamedPitch::B);
+        assert_eq!(NamedPitch::CFlat.respell(Accidental::Flat), NamedPitch::B);
+        assert_eq!(NamedPitch::B.respell(Accidental::Flat), NamedPitch::B);
+        assert_eq!(NamedPitch::C.respell(Accidental::Sharp), NamedPitch::C);
+        assert_eq!(NamedPitch::BSharp.respell(Accidental::Sharp), NamedPitch::C);
+        assert_eq!(NamedPitch::CSharp.respell(Accidental::Natural), NamedPitch::CSharp);
+        assert_eq!(NamedPitch::EDoubleFlat.respell(Accidental::Sharp), NamedPitch::D);
+        assert_eq!(NamedPitch::FDoubleSharp.respell(Accidental::Flat), NamedPitch::G);
+        assert_eq!(NamedPitch::FTripleFlat.respell(Accidental::Natural), NamedPitch::D);
+
+        assert_eq!(NamedPitch::BTripleFlat.accidental_count(), -3);
+        assert_eq!(NamedPitch::A.accidental_count(), 0);
+        assert_eq!(NamedPitch::CDoubleSharp.accidental_count(), 2);
+    }
+
     #[test]
     fn test_pitch_conversion() {
         assert_eq!(NamedPitch::from(Pitch::C), NamedPitch::C);
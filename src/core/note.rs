@@ -1,6 +1,10 @@
 //! A module for working with notes.
 //!
 //! A note is a named pitch with an octave.
+//!
+//! Every [`NamedPitch`] (from triple flats to triple sharps) has a constant in every [`Octave`], named as the named pitch
+//! followed by the octave (e.g., `BDoubleFlatThree`, `CDoubleFlatFive`, or `FTripleSharpFifteen`).  The named pitch alone
+//! (e.g., `BDoubleFlat`) is the note in the fourth octave.
 
 #![allow(dead_code)]
 #![allow(non_upper_case_globals)]
@@ -15,10 +19,11 @@ use crate::core::{
     base::{HasName, HasStaticName, Parsable, Res},
     chord::Chord,
     interval::{HasEnharmonicDistance, Interval, PRIMARY_HARMONIC_SERIES},
-    named_pitch::{HasNamedPitch, NamedPitch},
+    named_pitch::{Accidental, HasLetter, HasNamedPitch, NamedPitch},
     octave::{HasOctave, Octave, ALL_OCTAVES},
-    parser::{note_str_to_note, octave_str_to_octave, ChordParser, Rule},
-    pitch::{HasBaseFrequency, HasFrequency, HasPitch, Pitch, ALL_PITCHES},
+    parser::{note_str_to_note, octave_str_to_octave, ChordParser, ParseError, Rule},
+    pitch::{HasFrequency, HasPitch, Pitch, ALL_PITCHES},
+    tuning::{Temperament, Tuning, JUST_INTONATION_RATIOS},
 };
 use once_cell::sync::Lazy;
 use paste::paste;
@@ -187,6 +192,157 @@ impl Note {
     pub fn new(pitch: NamedPitch, octave: Octave) -> Self {
         Self { named_pitch: pitch, octave }
     }
+
+    /// Returns the MIDI note number of the note (where `C4` is `60`).
+    ///
+    /// Spellings that cross an octave boundary are accounted for (e.g., `C♭4` sounds as `B3`, so it is `59`).  Notes above
+    /// `G9` are clamped to `127` (the highest MIDI note number).
+    pub fn to_midi(&self) -> u8 {
+        self.semitone_index().clamp(0, 127) as u8
+    }
+
+    /// Returns the pitch class of the note (i.e., its [`Pitch`], which ignores the spelling and the octave).
+    pub fn pitch_class(&self) -> Pitch {
+        self.pitch()
+    }
+
+    /// Returns whether the note sounds the same as the other note, ignoring the spelling (e.g., `C♯4` and `D♭4`, or `B♯3` and `C4`).
+    ///
+    /// Unlike comparing [pitch classes](Note::pitch_class), the octave matters (i.e., `C4` and `C5` are not enharmonic).
+    pub fn is_enharmonic_with(&self, other: &Note) -> bool {
+        self.semitone_index() == other.semitone_index()
+    }
+
+    /// Returns the twelve-tone equal temperament frequency of the note, using the given reference frequency for `A4`.
+    fn equal_tempered_frequency(&self, a4: f32) -> f32 {
+        a4 * 2f32.powf((self.semitone_index() - A.semitone_index()) as f32 / 12.0)
+    }
+
+    /// Returns the (unclamped) number of semitones above the MIDI note `0` (i.e., `C-1`).
+    fn semitone_index(&self) -> i16 {
+        12 * (self.octave as i16 + octave_adjustment(self.named_pitch) as i16 + 1) + self.pitch() as i16
+    }
+
+    /// Returns the LilyPond (absolute) spelling of the note (e.g., `cis'` for `C♯4`, or `ees,` for `E♭2`).
+    ///
+    /// Accidentals use the Dutch names (`is` for sharp and `es` for flat, repeated as needed), and the octave is
+    /// marked relative to LilyPond's unmarked octave (`c` is `C3`), with one `'` per octave above and one `,` per octave below.
+    pub fn to_lilypond(&self) -> String {
+        let mut lilypond = self.named_pitch.letter().to_lowercase();
+
+        let accidentals = self.named_pitch.accidental_count();
+        let accidental = if accidentals > 0 { "is" } else { "es" };
+        lilypond.push_str(&accidental.repeat(accidentals.unsigned_abs() as usize));
+
+        let octaves = self.octave as i8 - Octave::Three as i8;
+        let mark = if octaves > 0 { "'" } else { "," };
+        lilypond.push_str(&mark.repeat(octaves.unsigned_abs() as usize));
+
+        lilypond
+    }
+
+    /// Returns the ABC notation spelling of the note (e.g., `^C` for `C♯4`, or `_e` for `E♭5`).
+    ///
+    /// Accidentals are written before the letter (`^` for sharp and `_` for flat, repeated as needed).  Following ABC's
+    /// middle C convention, `C` is `C4` and `c` is `C5`, with one `'` per octave above `C5` and one `,` per octave below `C4`.
+    pub fn to_abc(&self) -> String {
+        let accidentals = self.named_pitch.accidental_count();
+        let accidental = if accidentals > 0 { "^" } else { "_" };
+        let mut abc = accidental.repeat(accidentals.unsigned_abs() as usize);
+
+        let letter = self.named_pitch.letter();
+        if self.octave >= Octave::Five {
+            abc.push_str(&letter.to_lowercase());
+            abc.push_str(&"'".repeat((self.octave as u8 - Octave::Five as u8) as usize));
+        } else {
+            abc.push_str(letter);
+            abc.push_str(&",".repeat((Octave::Four as u8 - self.octave as u8) as usize));
+        }
+
+        abc
+    }
+
+    /// Returns an enharmonically equal note spelled with the preferred accidental (e.g., `G♭4` as `F♯4`).
+    ///
+    /// The sounding pitch is preserved, so the octave is adjusted when the spelling crosses an octave boundary
+    /// (e.g., `C♭4` is respelled as `B3`).  See [`NamedPitch::respell`] for how the spelling is chosen.
+    pub fn respell(&self, prefer: Accidental) -> Note {
+        let named_pitch = self.named_pitch.respell(prefer);
+        let octave = self.octave as i8 + octave_adjustment(self.named_pitch) - octave_adjustment(named_pitch);
+
+        match Octave::try_from_i8(octave) {
+            Some(octave) => Note::new(named_pitch, octave),
+            None => *self,
+        }
+    }
+
+    /// Returns every enharmonically equal note with at most two accidentals (including this one), ordered by the number of
+    /// accidentals (e.g., `E4`, `F♭4`, and `D𝄪4`).
+    ///
+    /// Like [`Note::respell`], the sounding pitch is preserved, so the octave is adjusted when a spelling crosses an octave
+    /// boundary (e.g., `B3` includes `C♭4`).  See [`NamedPitch::enharmonics`] for how the spellings are chosen.
+    pub fn enharmonics(&self) -> Vec<Note> {
+        self.named_pitch
+            .enharmonics()
+            .into_iter()
+            .filter_map(|named_pitch| {
+                let octave = self.octave as i8 + octave_adjustment(self.named_pitch) - octave_adjustment(named_pitch);
+
+                Octave::try_from_i8(octave).map(|octave| Note::new(named_pitch, octave))
+            })
+            .collect()
+    }
+
+    /// Returns the frequency of the note using the given [`Tuning`].
+    ///
+    /// This is the primary frequency computation ([`HasFrequency::frequency`] uses [`Tuning::default`]).  The frequencies are
+    /// computed exactly, rather than scaled from the rounded [base frequencies](crate::core::pitch::HasBaseFrequency) of the
+    /// pitches, so they differ slightly (by well under a cent) from those of earlier versions (e.g., `C4` is `261.6256` Hz,
+    /// rather than `261.6` Hz).
+    pub fn frequency_with(&self, tuning: &Tuning) -> f32 {
+        match tuning.temperament() {
+            Temperament::Equal => self.equal_tempered_frequency(tuning.a4()),
+            Temperament::Just(tonic) => {
+                let tonic_frequency = tonic.equal_tempered_frequency(tuning.a4());
+                let semitones = (self.semitone_index() - tonic.semitone_index()) as i32;
+
+                tonic_frequency * JUST_INTONATION_RATIOS[semitones.rem_euclid(12) as usize] * 2f32.powi(semitones.div_euclid(12))
+            }
+        }
+    }
+
+    /// Returns the nearest (equal-tempered) note to the given frequency, using the given reference frequency for `A4`
+    /// (e.g., `440.0`, or `432.0`).
+    ///
+    /// Frequencies outside of the supported octaves are clamped to the nearest supported note.  The frequency and the reference
+    /// frequency must be finite and positive.
+    pub fn from_frequency(frequency: f32, a4: f32) -> Res<Note> {
+        let semitones = semitones_above_a4(frequency, a4)?;
+        let index = (semitones.round() + (4 * 12 + Pitch::A as u8) as f32).clamp(0.0, (16 * 12 - 1) as f32) as u8;
+
+        let octave = Octave::try_from(index / 12).unwrap();
+        let pitch = Pitch::try_from(index % 12).unwrap();
+
+        Ok(Note::new(NamedPitch::from(pitch), octave))
+    }
+
+    /// Returns the deviation (in cents) of the given frequency from the nearest (equal-tempered) note, using the given
+    /// reference frequency for `A4`.
+    ///
+    /// The result is in the range `[-50, 50]`, where positive values are sharp, and negative values are flat.  The frequency and
+    /// the reference frequency must be finite and positive.
+    pub fn cents_from(frequency: f32, a4: f32) -> Res<f32> {
+        let semitones = semitones_above_a4(frequency, a4)?;
+
+        Ok(100.0 * (semitones - semitones.round()))
+    }
+
+    /// Returns the distance (in cents) of this note above the other note (i.e., `1200 × log2` of the ratio of their frequencies).
+    ///
+    /// The result is negative if this note is below the other note.
+    pub fn cents_above(&self, other: &Note) -> f32 {
+        1200.0 * (self.frequency() / other.frequency()).log2()
+    }
 }
 
 impl Note {
@@ -265,20 +421,7 @@ impl HasName for Note {
 
 impl HasFrequency for Note {
     fn frequency(&self) -> f32 {
-        let mut octave = self.octave();
-        let base_frequency = self.pitch().base_frequency();
-
-        match self.named_pitch {
-            NamedPitch::ATripleSharp | NamedPitch::BTripleSharp | NamedPitch::BDoubleSharp | NamedPitch::BSharp => {
-                octave += 1;
-            }
-            NamedPitch::DTripleFlat | NamedPitch::CTripleFlat | NamedPitch::CDoubleFlat | NamedPitch::CFlat => {
-                octave -= 1;
-            }
-            _ => {}
-        }
-
-        base_frequency * 2.0_f32.powf(octave as u8 as f32)
+        self.frequency_with(&Tuning::default())
     }
 }
 
@@ -293,7 +436,7 @@ impl Parsable for Note {
     where
         Self: Sized,
     {
-        let root = ChordParser::parse(Rule::note_with_octave, input)?.next().unwrap();
+        let root = ChordParser::parse(Rule::note_with_octave, input).map_err(|e| ParseError::from_pest(input, e))?.next().unwrap();
 
         assert_eq!(Rule::note_with_octave, root.as_rule());
 
@@ -303,12 +446,12 @@ impl Parsable for Note {
 
         assert_eq!(Rule::note, note.as_rule());
 
-        let mut result = note_str_to_note(note.as_str())?;
+        let mut result = note_str_to_note(note.as_str()).map_err(|e| ParseError::new(input, note.as_span().start(), e.to_string()))?;
 
         if let Some(octave) = components.next() {
             assert_eq!(Rule::digit, octave.as_rule());
 
-            let octave = octave_str_to_octave(octave.as_str())?;
+            let octave = octave_str_to_octave(octave.as_str()).map_err(|e| ParseError::new(input, octave.as_span().start(), e.to_string()))?;
 
             result = result.with_octave(octave);
         }
@@ -414,8 +557,23 @@ impl Sub for Note {
 impl Add<Interval> for Note {
     type Output = Self;
 
-    #[rustfmt::skip]
     fn add(self, rhs: Interval) -> Self::Output {
+        self.checked_add(rhs).expect("Octave overflow")
+    }
+}
+
+impl Sub<Interval> for Note {
+    type Output = Self;
+
+    fn sub(self, rhs: Interval) -> Self::Output {
+        self.checked_sub(rhs).expect("Octave underflow.")
+    }
+}
+
+impl Note {
+    /// Adds the given interval, returning `None` if the resulting octave is out of range (unlike `+`, which panics).
+    #[rustfmt::skip]
+    pub fn checked_add(&self, rhs: Interval) -> Option<Note> {
         let new_pitch = self.named_pitch() + rhs.enharmonic_distance();
 
         // Compute whether or not we "crossed" an octave.
@@ -445,18 +603,14 @@ impl Add<Interval> for Note {
         // Get whether or not the interval itself contains an octave.
         let interval_octave = rhs.octave();
 
-        Note {
-            octave: self.octave + wrapping_octave + special_octave + interval_octave,
-            named_pitch: new_pitch,
-        }
-    }
-}
+        let octave = self.octave.number() + wrapping_octave.number() + special_octave + interval_octave.number();
 
-impl Sub<Interval> for Note {
-    type Output = Self;
+        Octave::try_from_i8(octave).map(|octave| Note::new(new_pitch, octave))
+    }
 
+    /// Subtracts the given interval, returning `None` if the resulting octave is out of range (unlike `-`, which panics).
     #[rustfmt::skip]
-    fn sub(self, rhs: Interval) -> Self::Output {
+    pub fn checked_sub(&self, rhs: Interval) -> Option<Note> {
         let new_pitch = self.named_pitch() - rhs.enharmonic_distance();
 
         // Compute whether or not we "crossed" an octave.
@@ -486,10 +640,9 @@ impl Sub<Interval> for Note {
         // Get whether or not the interval itself contains an octave.
         let interval_octave = rhs.octave();
 
-        Note {
-            octave: self.octave - wrapping_octave - special_octave - interval_octave,
-            named_pitch: new_pitch,
-        }
+        let octave = self.octave.number() - wrapping_octave.number() - special_octave - interval_octave.number();
+
+        Octave::try_from_i8(octave).map(|octave| Note::new(new_pitch, octave))
     }
 }
 
@@ -524,6 +677,11 @@ define_octave!(Seven, Octave::Seven);
 define_octave!(Eight, Octave::Eight);
 define_octave!(Nine, Octave::Nine);
 define_octave!(Ten, Octave::Ten);
+define_octave!(Eleven, Octave::Eleven);
+define_octave!(Twelve, Octave::Twelve);
+define_octave!(Thirteen, Octave::Thirteen);
+define_octave!(Fourteen, Octave::Fourteen);
+define_octave!(Fifteen, Octave::Fifteen);
 
 // Define notes.
 
@@ -632,6 +790,30 @@ pub const ETripleSharp: Note = ETripleSharpFour;
 /// The default B triple sharp (in the fourth octave).
 pub const BTripleSharp: Note = BTripleSharpFour;
 
+// Helpers.
+
+/// Returns the octave adjustment for named pitches whose spelling crosses an octave boundary (e.g., `B♯` sounds in the next octave).
+fn octave_adjustment(named_pitch: NamedPitch) -> i8 {
+    match named_pitch {
+        NamedPitch::ATripleSharp | NamedPitch::BTripleSharp | NamedPitch::BDoubleSharp | NamedPitch::BSharp => 1,
+        NamedPitch::DTripleFlat | NamedPitch::CTripleFlat | NamedPitch::CDoubleFlat | NamedPitch::CFlat => -1,
+        _ => 0,
+    }
+}
+
+/// Returns the (fractional) number of equal-tempered semitones that the frequency is above the reference frequency for `A4`.
+fn semitones_above_a4(frequency: f32, a4: f32) -> Res<f32> {
+    if !frequency.is_finite() || frequency <= 0.0 {
+        return Err(anyhow::Error::msg(format!("The frequency must be finite and positive (got {}).", frequency)));
+    }
+
+    if !a4.is_finite() || a4 <= 0.0 {
+        return Err(anyhow::Error::msg(format!("The reference frequency for A4 must be finite and positive (got {}).", a4)));
+    }
+
+    Ok(12.0 * (frequency / a4).log2())
+}
+
 // Statics.
 
 /// All the notes in all octaves.
@@ -757,6 +939,14 @@ mod tests {
         assert_eq!(BSharp + Interval::PerfectUnison, BSharp);
 
         assert_eq!(ATripleSharp + Interval::TwoPerfectOctaves, ATripleSharpSix);
+
+        // Checked arithmetic.
+
+        assert_eq!(C.checked_add(Interval::PerfectFifth), Some(G));
+        assert_eq!(C.checked_sub(Interval::MinorSecond), Some(BThree));
+        assert_eq!(CZero.checked_sub(Interval::MinorSecond), None);
+        assert_eq!(BFifteen.checked_add(Interval::MinorSecond), None);
+        assert_eq!(CFifteen.checked_add(Interval::PerfectOctave), None);
     }
 
     #[test]
@@ -828,6 +1018,208 @@ mod tests {
         assert_eq!(Note::from_id_mask(1 << 13 | 1 << 48).unwrap(), vec![DFlatOne, CFour]);
     }
 
+    #[test]
+    fn test_respell() {
+        assert_eq!(GFlat.respell(Accidental::Sharp), FSharp);
+        assert_eq!(FSharp.respell(Accidental::Flat), GFlat);
+        assert_eq!(CFlat.respell(Accidental::Natural), BThree);
+        assert_eq!(BSharp.respell(Accidental::Sharp), CFive);
+        assert_eq!(C.respell(Accidental::Sharp), C);
+        assert_eq!(C.respell(Accidental::Flat), C);
+        assert_eq!(EDoubleFlat.respell(Accidental::Flat), D);
+        assert_eq!(GDoubleSharp.respell(Accidental::Flat), A);
+
+        for note in [CFlat, BSharp, EDoubleFlat, GFlat, FSharp, CDoubleFlat, BDoubleSharp] {
+            for prefer in [Accidental::Sharp, Accidental::Flat, Accidental::Natural] {
+                assert_eq!(note.respell(prefer).to_midi(), note.to_midi());
+            }
+        }
+    }
+
+    #[test]
+    fn test_lilypond() {
+        assert_eq!(C.to_lilypond(), "c'");
+        assert_eq!(CThree.to_lilypond(), "c");
+        assert_eq!(CSharpFive.to_lilypond(), "cis''");
+        assert_eq!(EFlatTwo.to_lilypond(), "ees,");
+        assert_eq!(BDoubleFlatZero.to_lilypond(), "beses,,,");
+        assert_eq!(FDoubleSharp.to_lilypond(), "fisis'");
+    }
+
+    #[test]
+    fn test_abc() {
+        assert_eq!(C.to_abc(), "C");
+        assert_eq!(CFive.to_abc(), "c");
+        assert_eq!(CSeven.to_abc(), "c''");
+        assert_eq!(CSharp.to_abc(), "^C");
+        assert_eq!(EFlatFive.to_abc(), "_e");
+        assert_eq!(BFlatTwo.to_abc(), "_B,,");
+        assert_eq!(GDoubleSharpThree.to_abc(), "^^G,");
+    }
+
+    #[test]
+    fn test_enharmonics() {
+        assert_eq!(E.enharmonics(), vec![E, FFlat, DDoubleSharp]);
+        assert_eq!(CSharp.enharmonics(), vec![CSharp, DFlat, BDoubleSharpThree]);
+        assert_eq!(DFlat.enharmonics(), vec![CSharp, DFlat, BDoubleSharpThree]);
+        assert_eq!(BThree.enharmonics(), vec![BThree, CFlat, ADoubleSharpThree]);
+        assert_eq!(GSharp.enharmonics(), vec![GSharp, AFlat]);
+
+        for note in [C, E, CSharp, DFlat, BThree, GSharp, BSharp, FDoubleFlat] {
+            let enharmonics = note.enharmonics();
+
+            assert!(enharmonics.contains(&note));
+            assert!(enharmonics.iter().all(|n| n.to_midi() == note.to_midi()));
+        }
+    }
+
+    #[test]
+    fn test_midi() {
+        assert_eq!(C.to_midi(), 60);
+        assert_eq!(A.to_midi(), 69);
+        assert_eq!(CZero.to_midi(), 12);
+        assert_eq!(CFlat.to_midi(), 59);
+        assert_eq!(BSharpThree.to_midi(), 60);
+        assert_eq!(GNine.to_midi(), 127);
+        assert_eq!(ASharpNine.to_midi(), 127);
+        assert_eq!(BFifteen.to_midi(), 127);
+    }
+
+    #[test]
+    fn test_enharmonic() {
+        assert!(DFlat.is_enharmonic_with(&CSharp));
+        assert!(BSharpThree.is_enharmonic_with(&C));
+        assert!(CFlat.is_enharmonic_with(&BThree));
+        assert!(!C.is_enharmonic_with(&CFive));
+        assert!(!C.is_enharmonic_with(&CSharp));
+
+        assert_eq!(CSharp.pitch_class(), DFlat.pitch_class());
+        assert_eq!(C.pitch_class(), CFive.pitch_class());
+        assert_eq!(C.pitch_class(), Pitch::C);
+    }
+
+    #[test]
+    fn test_from_frequency() {
+        assert_eq!(Note::from_frequency(261.6, 440.0).unwrap(), C);
+        assert!(Note::cents_from(261.6, 440.0).unwrap().abs() < 1.0);
+
+        assert_eq!(Note::from_frequency(440.0, 440.0).unwrap(), A);
+        assert_eq!(Note::from_frequency(27.5, 440.0).unwrap(), AZero);
+        assert_eq!(Note::from_frequency(466.16, 440.0).unwrap(), BFlat);
+        assert_eq!(Note::from_frequency(450.0, 440.0).unwrap(), A);
+        assert!((Note::cents_from(450.0, 440.0).unwrap() - 38.9).abs() < 0.1);
+
+        // At A4 = 432 Hz, everything is about 32 cents flat, so 261.6 Hz is a sharp C4.
+        assert_eq!(Note::from_frequency(432.0, 432.0).unwrap(), A);
+        assert_eq!(Note::from_frequency(261.6, 432.0).unwrap(), C);
+        assert!((Note::cents_from(261.6, 432.0).unwrap() - 31.8).abs() < 0.5);
+        assert!(Note::cents_from(256.87, 432.0).unwrap().abs() < 1.0);
+
+        // Out of range frequencies are clamped.
+        assert_eq!(Note::from_frequency(1.0, 440.0).unwrap(), CZero);
+        assert_eq!(Note::from_frequency(f32::MAX, 1e-30).unwrap(), Note::new(NamedPitch::B, Octave::Fifteen));
+
+        // Non-finite and non-positive frequencies are rejected.
+        assert!(Note::from_frequency(f32::INFINITY, 440.0).is_err());
+        assert!(Note::from_frequency(f32::NAN, 440.0).is_err());
+        assert!(Note::from_frequency(0.0, 440.0).is_err());
+        assert!(Note::from_frequency(440.0, 0.0).is_err());
+        assert!(Note::cents_from(-440.0, 440.0).is_err());
+        assert!(Note::cents_from(440.0, f32::NAN).is_err());
+    }
+
+    #[test]
+    fn test_cents_above() {
+        // The frequencies are exact, so only allow for floating point error.
+        assert!((G.cents_above(&C) - 700.0).abs() < 1e-3);
+        assert!((CFive.cents_above(&C) - 1200.0).abs() < 1e-3);
+        assert!((C.cents_above(&G) + 700.0).abs() < 1e-3);
+        assert_eq!(C.cents_above(&C), 0.0);
+
+        // A just perfect fifth (3:2) is about two cents wider than the tempered one.
+        let just = Tuning::just(C, 440.0);
+        let just_fifth = 1200.0 * (G.frequency_with(&just) / C.frequency_with(&just)).log2();
+        assert!((just_fifth - Interval::PerfectFifth.cents() - 1.96).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_constants() {
+        macro_rules! assert_constants {
+            ($($name:ident),*) => {
+                paste! {
+                    $(
+                        let notes = [
+                            [<$name Zero>], [<$name One>], [<$name Two>], [<$name Three>], [<$name Four>], [<$name Five>], [<$name Six>], [<$name Seven>],
+                            [<$name Eight>], [<$name Nine>], [<$name Ten>], [<$name Eleven>], [<$name Twelve>], [<$name Thirteen>], [<$name Fourteen>], [<$name Fifteen>],
+                        ];
+
+                        for (note, octave) in notes.into_iter().zip(ALL_OCTAVES.iter()) {
+                            assert_eq!(note, Note::new(NamedPitch::$name, *octave));
+                        }
+
+                        assert_eq!($name, [<$name Four>]);
+                    )*
+                }
+            };
+        }
+
+        assert_constants!(
+            FTripleFlat,
+            CTripleFlat,
+            GTripleFlat,
+            DTripleFlat,
+            ATripleFlat,
+            ETripleFlat,
+            BTripleFlat,
+            FDoubleFlat,
+            CDoubleFlat,
+            GDoubleFlat,
+            DDoubleFlat,
+            ADoubleFlat,
+            EDoubleFlat,
+            BDoubleFlat,
+            FFlat,
+            CFlat,
+            GFlat,
+            DFlat,
+            AFlat,
+            EFlat,
+            BFlat,
+            F,
+            C,
+            G,
+            D,
+            A,
+            E,
+            B,
+            FSharp,
+            CSharp,
+            GSharp,
+            DSharp,
+            ASharp,
+            ESharp,
+            BSharp,
+            FDoubleSharp,
+            CDoubleSharp,
+            GDoubleSharp,
+            DDoubleSharp,
+            ADoubleSharp,
+            EDoubleSharp,
+            BDoubleSharp,
+            FTripleSharp,
+            CTripleSharp,
+            GTripleSharp,
+            DTripleSharp,
+            ATripleSharp,
+            ETripleSharp,
+            BTripleSharp
+        );
+
+        // The spellings used by the diminished chord scales.
+        assert_eq!(CDoubleFlatFive.to_midi(), 70);
+        assert_eq!(BDoubleFlatThree.to_midi(), 57);
+    }
+
     #[test]
     fn test_universal() {
         assert_eq!(FSharpFive.to_universal(), Note::parse("Gb5").unwrap());
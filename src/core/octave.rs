@@ -61,6 +61,43 @@ pub enum Octave {
 
 // Octave impls.
 
+impl Octave {
+    /// Returns all of the octaves, in ascending order.
+    pub fn all() -> &'static [Octave] {
+        &*ALL_OCTAVES
+    }
+
+    /// Attempts to convert the given number into an octave, returning `None` if it is out of range.
+    pub fn try_from_i8(value: i8) -> Option<Octave> {
+        u8::try_from(value).ok().and_then(|v| Octave::try_from(v).ok())
+    }
+
+    /// Returns the number of the octave (e.g., `4` for [`Octave::Four`]).
+    pub fn number(&self) -> i8 {
+        *self as i8
+    }
+
+    /// Adds the given number of octaves, returning `None` if the result is out of range (unlike `+`, which panics).
+    pub fn checked_add(&self, octaves: i8) -> Option<Octave> {
+        self.number().checked_add(octaves).and_then(Octave::try_from_i8)
+    }
+
+    /// Subtracts the given number of octaves, returning `None` if the result is out of range (unlike `-`, which panics).
+    pub fn checked_sub(&self, octaves: i8) -> Option<Octave> {
+        self.number().checked_sub(octaves).and_then(Octave::try_from_i8)
+    }
+
+    /// Adds the given number of octaves, clamping the result to [`Octave::Zero`] through [`Octave::Fifteen`].
+    pub fn saturating_add(&self, octaves: i8) -> Octave {
+        Octave::try_from_i8(self.number().saturating_add(octaves).clamp(0, 15)).unwrap()
+    }
+
+    /// Subtracts the given number of octaves, clamping the result to [`Octave::Zero`] through [`Octave::Fifteen`].
+    pub fn saturating_sub(&self, octaves: i8) -> Octave {
+        Octave::try_from_i8(self.number().saturating_sub(octaves).clamp(0, 15)).unwrap()
+    }
+}
+
 impl HasStaticName for Octave {
     #[inline]
     fn static_name(&self) -> &'static str {
@@ -262,6 +299,43 @@ mod tests {
         assert_eq!(Octave::default(), Octave::Four);
     }
 
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Octave::all().len(), 16);
+        assert_eq!(Octave::all().first(), Some(&Octave::Zero));
+        assert_eq!(Octave::all().last(), Some(&Octave::Fifteen));
+
+        assert_eq!(Octave::try_from_i8(0), Some(Octave::Zero));
+        assert_eq!(Octave::try_from_i8(4), Some(Octave::Four));
+        assert_eq!(Octave::try_from_i8(15), Some(Octave::Fifteen));
+        assert_eq!(Octave::try_from_i8(16), None);
+        assert_eq!(Octave::try_from_i8(-1), None);
+        assert_eq!(Octave::try_from_i8(i8::MIN), None);
+        assert_eq!(Octave::try_from(16u8), Err("Octave overflow."));
+
+        for octave in Octave::all() {
+            assert_eq!(Octave::try_from_i8(octave.number()), Some(*octave));
+        }
+    }
+
+    #[test]
+    fn test_checked_and_saturating() {
+        assert_eq!(Octave::Four.checked_add(1), Some(Octave::Five));
+        assert_eq!(Octave::Four.checked_sub(1), Some(Octave::Three));
+        assert_eq!(Octave::Four.checked_add(-4), Some(Octave::Zero));
+        assert_eq!(Octave::Fifteen.checked_add(1), None);
+        assert_eq!(Octave::Zero.checked_sub(1), None);
+        assert_eq!(Octave::Zero.checked_sub(i8::MIN), None);
+
+        assert_eq!(Octave::Four.saturating_add(1), Octave::Five);
+        assert_eq!(Octave::Four.saturating_sub(1), Octave::Three);
+        assert_eq!(Octave::Fifteen.saturating_add(1), Octave::Fifteen);
+        assert_eq!(Octave::Zero.saturating_sub(1), Octave::Zero);
+        assert_eq!(Octave::Four.saturating_add(i8::MAX), Octave::Fifteen);
+        assert_eq!(Octave::Four.saturating_sub(i8::MAX), Octave::Zero);
+        assert_eq!(Octave::Four.saturating_add(i8::MIN), Octave::Zero);
+    }
+
     #[test]
     fn test_names() {
         assert_eq!(ALL_OCTAVES.map(|o| o.static_name()).join(" "), "0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15");
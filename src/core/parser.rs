@@ -1,5 +1,8 @@
 //! A module for working with the parser for chord symbols.
 
+use std::fmt::Display;
+
+use pest::error::{ErrorVariant, InputLocation};
 use pest_derive::Parser;
 
 use crate::core::{
@@ -15,53 +18,183 @@ use crate::core::{
 #[grammar = "../chord.pest"]
 pub struct ChordParser;
 
+// Errors.
+
+/// An error that occurs when parsing a chord (or note) symbol.
+///
+/// [`Parsable::parse`](crate::core::base::Parsable::parse) returns a [`Res`], so callers may recover this error
+/// via [`anyhow::Error::downcast_ref`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseError {
+    /// The input that failed to parse.
+    input: String,
+    /// The byte offset into the input at which parsing failed.
+    offset: usize,
+    /// A hint describing what was expected at the offset.
+    expected: String,
+}
+
+impl ParseError {
+    /// Returns a new parse error for the given input, byte offset, and expected-token hint.
+    pub fn new(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        Self {
+            input: input.to_owned(),
+            offset: offset.min(input.len()),
+            expected: expected.into(),
+        }
+    }
+
+    /// Returns a new parse error from a `pest` error that occurred when parsing the given input.
+    pub fn from_pest(input: &str, error: pest::error::Error<Rule>) -> Self {
+        let offset = match error.location {
+            InputLocation::Pos(offset) => offset,
+            InputLocation::Span((start, _)) => start,
+        };
+
+        let expected = match error.variant {
+            ErrorVariant::ParsingError { positives, .. } => {
+                let mut hints = Vec::new();
+
+                for hint in positives.into_iter().map(rule_hint) {
+                    if !hints.contains(&hint) {
+                        hints.push(hint);
+                    }
+                }
+
+                if hints.is_empty() {
+                    "a valid symbol".to_owned()
+                } else {
+                    hints.join(", or ")
+                }
+            }
+            ErrorVariant::CustomError { message } => message,
+        };
+
+        Self::new(input, offset, expected)
+    }
+
+    /// Returns the input that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the byte offset into the input at which parsing failed.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns a hint describing what was expected at the offset.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The caret is aligned by characters (not bytes), so that symbols like `♭` line up.
+        let column = self.input.get(..self.offset).map_or(self.offset, |prefix| prefix.chars().count());
+
+        writeln!(f, "Unable to parse `{}` at position {}: expected {}.", self.input, self.offset, self.expected)?;
+        writeln!(f, "    {}", self.input)?;
+        write!(f, "    {}^", " ".repeat(column))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 // Helpers.
 
+/// Returns a human readable hint for the given grammar rule.
+pub(crate) fn rule_hint(rule: Rule) -> String {
+    let hint = match rule {
+        Rule::letter | Rule::note | Rule::note_with_octave => "a note (e.g., `C`, or `Eb`)",
+        Rule::accidental => "an accidental (e.g., `#`, or `b`)",
+        Rule::digit | Rule::number => "a digit",
+        Rule::minor | Rule::augmented | Rule::diminished | Rule::half_diminished => "a quality (e.g., `m`, `+`, `dim`, or `ø`)",
+        Rule::maj7_modifier | Rule::dominant_modifier => "a seventh (e.g., `maj7`, `7`, or `9`)",
+        Rule::power => "a power chord (`5`)",
+        Rule::modifier | Rule::sus_modifier | Rule::add_modifier | Rule::five_modifier | Rule::nine_modifier | Rule::eleven_modifier | Rule::thirteen_modifier | Rule::omission_modifier => {
+            "a modifier (e.g., `sus4`, `b5`, `#11`, or `no5`)"
+        }
+        Rule::slash => "a slash (e.g., `/E`)",
+        Rule::at => "an octave (e.g., `@4`)",
+        Rule::hat => "an inversion (e.g., `^1`)",
+        Rule::bang => "a crunchy marker (`!`)",
+        Rule::EOI => "the end of the symbol",
+        _ => return format!("{:?}", rule),
+    };
+
+    hint.to_owned()
+}
+
 /// Parses a [`Note`] [`str`] into a [`Note`].
+///
+/// The note is [normalized](normalize_note_str) first, so lowercase letters and Unicode (or `x`) accidentals are accepted.
 #[coverage(off)]
 pub fn note_str_to_note(note_str: &str) -> Res<Note> {
-    let chord = match note_str {
+    let chord = match normalize_note_str(note_str).as_str() {
         "A" => note::A,
-        "A#" | "A♯" => note::ASharp,
-        "A##" | "A𝄪" => note::ADoubleSharp,
-        "Ab" | "A♭" => note::AFlat,
-        "Abb" | "A𝄫" => note::ADoubleFlat,
+        "A#" => note::ASharp,
+        "A##" => note::ADoubleSharp,
+        "Ab" => note::AFlat,
+        "Abb" => note::ADoubleFlat,
         "B" => note::B,
-        "B#" | "B♯" => note::BSharp,
-        "B##" | "B𝄪" => note::BDoubleSharp,
-        "Bb" | "B♭" => note::BFlat,
-        "Bbb" | "B𝄫" => note::BDoubleFlat,
+        "B#" => note::BSharp,
+        "B##" => note::BDoubleSharp,
+        "Bb" => note::BFlat,
+        "Bbb" => note::BDoubleFlat,
         "C" => note::C,
-        "C#" | "C♯" => note::CSharp,
-        "C##" | "C𝄪" => note::CDoubleSharp,
-        "Cb" | "C♭" => note::CFlat,
-        "Cbb" | "C𝄫" => note::CDoubleFlat,
+        "C#" => note::CSharp,
+        "C##" => note::CDoubleSharp,
+        "Cb" => note::CFlat,
+        "Cbb" => note::CDoubleFlat,
         "D" => note::D,
-        "D#" | "D♯" => note::DSharp,
-        "D##" | "D𝄪" => note::DDoubleSharp,
-        "Db" | "D♭" => note::DFlat,
-        "Dbb" | "D𝄫" => note::DDoubleFlat,
+        "D#" => note::DSharp,
+        "D##" => note::DDoubleSharp,
+        "Db" => note::DFlat,
+        "Dbb" => note::DDoubleFlat,
         "E" => note::E,
-        "E#" | "E♯" => note::ESharp,
-        "E##" | "E𝄪" => note::EDoubleSharp,
-        "Eb" | "E♭" => note::EFlat,
-        "Ebb" | "E𝄫" => note::EDoubleFlat,
+        "E#" => note::ESharp,
+        "E##" => note::EDoubleSharp,
+        "Eb" => note::EFlat,
+        "Ebb" => note::EDoubleFlat,
         "F" => note::F,
-        "F#" | "F♯" => note::FSharp,
-        "F##" | "F𝄪" => note::FDoubleSharp,
-        "Fb" | "F♭" => note::FFlat,
-        "Fbb" | "F𝄫" => note::FDoubleFlat,
+        "F#" => note::FSharp,
+        "F##" => note::FDoubleSharp,
+        "Fb" => note::FFlat,
+        "Fbb" => note::FDoubleFlat,
         "G" => note::G,
-        "G#" | "G♯" => note::GSharp,
-        "G##" | "G𝄪" => note::GDoubleSharp,
-        "Gb" | "G♭" => note::GFlat,
-        "Gbb" | "G𝄫" => note::GDoubleFlat,
+        "G#" => note::GSharp,
+        "G##" => note::GDoubleSharp,
+        "Gb" => note::GFlat,
+        "Gbb" => note::GDoubleFlat,
         _ => return Err(crate::core::base::Err::msg("Please use fairly standard notes (e.g., don't use triple sharps / flats).")),
     };
 
     Ok(chord)
 }
 
+/// Normalizes a [`Note`] [`str`] (e.g., `c♯`, `Dx`, or `e𝄫`) to an uppercase letter followed by ASCII accidentals
+/// (e.g., `C#`, `D##`, or `Ebb`).
+///
+/// The grammar has already resolved the letter from the accidentals by position, so a `b` after the letter is always a flat.
+fn normalize_note_str(note_str: &str) -> String {
+    let mut chars = note_str.chars();
+    let mut result = chars.next().map(|c| c.to_ascii_uppercase().to_string()).unwrap_or_default();
+
+    for c in chars {
+        match c {
+            '♯' | '＃' => result.push('#'),
+            'x' | '𝄪' => result.push_str("##"),
+            '♭' => result.push('b'),
+            '𝄫' => result.push_str("bb"),
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
 /// Parses an [`Octave`] [`str`] into an [`Octave`].
 #[coverage(off)]
 pub fn octave_str_to_octave(note_str: &str) -> Res<Octave> {
@@ -81,3 +214,79 @@ pub fn octave_str_to_octave(note_str: &str) -> Res<Octave> {
 
     Ok(octave)
 }
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        base::Parsable,
+        chord::{Chord, HasInversion},
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Chord::parse("Cmj7").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!(error.input(), "Cmj7");
+        assert_eq!(error.offset(), 2);
+        assert!(error.expected().contains("a seventh"));
+        assert!(error.to_string().ends_with("\n    Cmj7\n      ^"));
+
+        let error = Chord::parse("H7").unwrap_err();
+
+        assert_eq!(error.downcast_ref::<ParseError>().unwrap().offset(), 0);
+
+        assert_eq!(Note::parse("X").unwrap_err().downcast_ref::<ParseError>().unwrap().offset(), 0);
+
+        // Unicode symbols are aligned by characters.
+        let error = Chord::parse("E♭mj7").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!(error.offset(), 5);
+        assert!(error.to_string().ends_with("\n    E♭mj7\n       ^"));
+
+        // Unsupported notes point at the note.
+        let error = Chord::parse("C7/D#b").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!(error.offset(), 3);
+        assert!(error.expected().contains("triple sharps"));
+
+        // Out-of-range octaves and inversions point at the number.
+        let error = Chord::parse("C7@10").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!(error.offset(), 3);
+        assert!(error.expected().contains("valid octave"));
+        assert!(error.to_string().ends_with("\n    C7@10\n       ^"));
+
+        let error = Chord::parse("C^256").unwrap_err();
+
+        assert_eq!(error.downcast_ref::<ParseError>().unwrap().offset(), 2);
+        assert_eq!(Chord::parse("C^12").unwrap().inversion(), 12);
+    }
+
+    #[test]
+    fn test_normalized_input() {
+        assert_eq!(Chord::parse("cmaj7").unwrap(), Chord::parse("Cmaj7").unwrap());
+        assert_eq!(Chord::parse("C♯m7").unwrap(), Chord::parse("C#m7").unwrap());
+        assert_eq!(Chord::parse("Dbmaj7").unwrap(), Chord::parse("D♭maj7").unwrap());
+        assert_eq!(Chord::parse("d＃m/f𝄪").unwrap(), Chord::parse("D#m/F##").unwrap());
+        assert_eq!(Chord::parse("Gx7").unwrap(), Chord::parse("G##7").unwrap());
+        assert_eq!(Chord::parse("e𝄫").unwrap(), Chord::parse("Ebb").unwrap());
+
+        // A `b` is a note in the letter position, and a flat after it.
+        assert_eq!(Chord::parse("b7").unwrap(), Chord::parse("B7").unwrap());
+        assert_eq!(Chord::parse("bb7").unwrap(), Chord::parse("Bb7").unwrap());
+        assert_eq!(Chord::parse("bbb").unwrap(), Chord::parse("Bbb").unwrap());
+
+        assert_eq!(Note::parse("f♯3").unwrap(), Note::parse("F#3").unwrap());
+        assert_eq!(Note::parse("cx").unwrap(), Note::parse("C##").unwrap());
+
+        assert!(Chord::parse("C𝄪#").is_err());
+    }
+}
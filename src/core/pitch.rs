@@ -6,7 +6,7 @@ use std::time::Duration;
 
 use once_cell::sync::Lazy;
 
-use super::helpers::mel;
+use super::{helpers::mel, note::Note, tuning::STANDARD_A4};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -54,16 +54,16 @@ pub trait HasMel: HasFrequency {
 }
 
 #[cfg(feature = "audio")]
-use super::base::{Playable, PlaybackHandle, Res};
+use super::base::{tone_source, Playable, PlaybackHandle, Res, Waveform};
 
 #[cfg(feature = "audio")]
 impl<T: HasFrequency> Playable for T {
-    fn play(&self, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle> {
-        use rodio::{source::SineWave, OutputStream, Sink, Source};
+    fn play_with(&self, waveform: Waveform, delay: Duration, length: Duration, fade_in: Duration) -> Res<PlaybackHandle> {
+        use rodio::{OutputStream, Sink};
 
         let (stream, stream_handle) = OutputStream::try_default()?;
         let sink = Sink::try_new(&stream_handle)?;
-        let source = SineWave::new(self.frequency()).take_duration(length - delay).buffered().delay(delay).fade_in(fade_in).amplify(0.20);
+        let source = tone_source(waveform, self.frequency(), delay, length, fade_in);
         sink.append(source);
 
         Ok(PlaybackHandle::new(stream, stream_handle, vec![sink]))
@@ -128,6 +128,13 @@ impl HasBaseFrequency for Pitch {
     }
 }
 
+impl Pitch {
+    /// Returns whether the pitch is played on a black key of a piano keyboard (i.e., it is not one of the natural pitches).
+    pub fn is_black_key(&self) -> bool {
+        matches!(self, Pitch::DFlat | Pitch::EFlat | Pitch::GFlat | Pitch::AFlat | Pitch::BFlat)
+    }
+}
+
 impl HasPitch for Pitch {
     fn pitch(&self) -> Pitch {
         *self
@@ -156,8 +163,235 @@ impl TryFrom<u8> for Pitch {
     }
 }
 
+// Pitch class sets.
+
+/// A struct representing a pitch class set (i.e., an unordered collection of unique [`Pitch`]es).
+///
+/// This is used for post-tonal (set theory) analysis, where pitches are treated as the integers `0` (C) through `11` (B).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Clone, Hash, Debug, Default)]
+pub struct PitchClassSet {
+    /// The (sorted and deduplicated) pitches of the set.
+    pitches: Vec<Pitch>,
+}
+
+impl PitchClassSet {
+    /// Creates a new [`PitchClassSet`] from the given pitches (duplicates are removed).
+    pub fn new(pitches: &[Pitch]) -> Self {
+        let mut pitches = pitches.to_vec();
+        pitches.sort();
+        pitches.dedup();
+
+        Self { pitches }
+    }
+
+    /// Returns the (sorted) pitches of the set.
+    pub fn pitches(&self) -> &[Pitch] {
+        &self.pitches
+    }
+
+    /// Returns the normal form of the set.
+    ///
+    /// The normal form is the rotation of the set with the smallest span, with ties broken by choosing the
+    /// rotation that is most "packed to the left" (i.e., smallest intervals from the first pitch), and then by lowest first pitch.
+    pub fn normal_form(&self) -> Vec<Pitch> {
+        let classes = self.pitches.iter().map(|p| *p as u8).collect::<Vec<_>>();
+
+        normal_order(&classes).into_iter().map(|c| Pitch::try_from(c).unwrap()).collect()
+    }
+
+    /// Returns the prime form of the set (e.g., `[0, 1, 4]`).
+    ///
+    /// The prime form is the most compact of the normal forms of the set and its inversion, transposed to start at `0`.
+    pub fn prime_form(&self) -> Vec<u8> {
+        let classes = self.pitches.iter().map(|p| *p as u8).collect::<Vec<_>>();
+        let inverted = classes.iter().map(|c| (12 - c) % 12).collect::<Vec<_>>();
+
+        let prime = transpose_to_zero(&normal_order(&classes));
+        let inverted_prime = transpose_to_zero(&normal_order(&inverted));
+
+        std::cmp::min(prime, inverted_prime)
+    }
+
+    /// Returns the interval (class) vector of the set.
+    ///
+    /// Each entry counts the number of pitch pairs separated by the given interval class (1 through 6 semitones).
+    pub fn interval_vector(&self) -> [u8; 6] {
+        let mut vector = [0u8; 6];
+
+        for (k, a) in self.pitches.iter().enumerate() {
+            for b in &self.pitches[k + 1..] {
+                let distance = (*b as u8 - *a as u8) % 12;
+                let interval_class = distance.min(12 - distance);
+
+                vector[interval_class as usize - 1] += 1;
+            }
+        }
+
+        vector
+    }
+}
+
+impl From<&[Pitch]> for PitchClassSet {
+    fn from(pitches: &[Pitch]) -> Self {
+        Self::new(pitches)
+    }
+}
+
+/// Returns the normal order of the given pitch classes.
+fn normal_order(classes: &[u8]) -> Vec<u8> {
+    let mut classes = classes.to_vec();
+    classes.sort();
+    classes.dedup();
+
+    let len = classes.len();
+
+    if len == 0 {
+        return classes;
+    }
+
+    (0..len)
+        .map(|k| classes[k..].iter().chain(&classes[..k]).cloned().collect::<Vec<_>>())
+        .min_by_key(|rotation| {
+            let first = rotation[0];
+            let span = (rotation[len - 1] + 12 - first) % 12;
+            let packing = rotation.iter().map(|c| (c + 12 - first) % 12).collect::<Vec<_>>();
+
+            (span, packing, first)
+        })
+        .unwrap()
+}
+
+/// Transposes the given pitch classes so that the first one is `0`.
+fn transpose_to_zero(classes: &[u8]) -> Vec<u8> {
+    let first = classes.first().cloned().unwrap_or_default();
+
+    classes.iter().map(|c| (c + 12 - first) % 12).collect()
+}
+
+// Pitch histograms.
+
+/// A struct representing a pitch class histogram (i.e., a weight for each of the twelve [`Pitch`]es, indexed from `C`).
+///
+/// This is used to summarize notes (or audio) by pitch class, ignoring octaves (e.g., for key detection).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+pub struct PitchHistogram(pub [f32; 12]);
+
+impl PitchHistogram {
+    /// Creates a new [`PitchHistogram`] by counting the pitch classes of the given items (usually [`Pitch`]es, or [`Note`](crate::core::note::Note)s).
+    pub fn from_pitches<T: HasPitch>(items: &[T]) -> Self {
+        let mut result = Self::default();
+
+        for item in items {
+            result.0[item.pitch() as usize] += 1.0;
+        }
+
+        result
+    }
+
+    /// Creates a new [`PitchHistogram`] from a frequency space (i.e., `(frequency, magnitude)` pairs) by summing the magnitudes
+    /// into the pitch class of the nearest (equal-tempered) note.
+    ///
+    /// Only frequencies within the range of a piano (roughly `27.5` to `4186` Hz) are counted.
+    pub fn from_frequency_space(frequency_space: &[(f32, f32)]) -> Self {
+        let mut result = Self::default();
+
+        for (frequency, magnitude) in frequency_space {
+            if !(MIN_HISTOGRAM_FREQUENCY..=MAX_HISTOGRAM_FREQUENCY).contains(frequency) {
+                continue;
+            }
+
+            let semitones = (12.0 * (frequency / STANDARD_A4).log2()).round() as i32;
+            let pitch = (semitones + Pitch::A as i32).rem_euclid(12) as usize;
+
+            result.0[pitch] += magnitude;
+        }
+
+        result
+    }
+
+    /// Returns the weights of the histogram (indexed by [`Pitch`]).
+    pub fn values(&self) -> &[f32; 12] {
+        &self.0
+    }
+
+    /// Returns the histogram scaled so that the weights sum to `1` (leaving an empty histogram untouched).
+    pub fn normalize(&self) -> Self {
+        let sum = self.0.iter().sum::<f32>();
+
+        if sum <= 0.0 {
+            return *self;
+        }
+
+        Self(self.0.map(|v| v / sum))
+    }
+
+    /// Returns the pitch class with the largest weight, or `None` if the histogram is empty.
+    ///
+    /// Ties are broken by choosing the lowest pitch class (starting from `C`).
+    pub fn dominant(&self) -> Option<Pitch> {
+        let (index, max) = self.0.iter().enumerate().fold((0, 0f32), |(k, max), (j, v)| if *v > max { (j, *v) } else { (k, max) });
+
+        if max > 0.0 {
+            Pitch::try_from(index as u8).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the histogram transposed by the given number of semitones (up, if positive, or down, if negative).
+    pub fn transpose(&self, semitones: i8) -> Self {
+        let mut result = Self::default();
+
+        for (k, v) in self.0.iter().enumerate() {
+            result.0[(k as i32 + semitones as i32).rem_euclid(12) as usize] = *v;
+        }
+
+        result
+    }
+
+    /// Returns the cosine similarity (from `0` to `1`, for non-negative weights) between this histogram and the other one.
+    ///
+    /// Returns `0` if either histogram is empty.
+    pub fn similarity(&self, other: &PitchHistogram) -> f32 {
+        let dot = self.0.iter().zip(&other.0).map(|(a, b)| a * b).sum::<f32>();
+        let norm = self.0.iter().map(|v| v * v).sum::<f32>().sqrt() * other.0.iter().map(|v| v * v).sum::<f32>().sqrt();
+
+        if norm > 0.0 {
+            dot / norm
+        } else {
+            0.0
+        }
+    }
+}
+
+impl From<[f32; 12]> for PitchHistogram {
+    fn from(values: [f32; 12]) -> Self {
+        Self(values)
+    }
+}
+
+impl From<&[Pitch]> for PitchHistogram {
+    fn from(pitches: &[Pitch]) -> Self {
+        Self::from_pitches(pitches)
+    }
+}
+
+impl From<&[Note]> for PitchHistogram {
+    fn from(notes: &[Note]) -> Self {
+        Self::from_pitches(notes)
+    }
+}
+
 // Statics.
 
+/// The lowest frequency counted by [`PitchHistogram::from_frequency_space`] (roughly `A0`).
+const MIN_HISTOGRAM_FREQUENCY: f32 = 27.0;
+
+/// The highest frequency counted by [`PitchHistogram::from_frequency_space`] (roughly `C8`).
+const MAX_HISTOGRAM_FREQUENCY: f32 = 4200.0;
+
 /// An array of all the pitches.
 pub static ALL_PITCHES: Lazy<[Pitch; 12]> = Lazy::new(|| {
     [
@@ -187,5 +421,71 @@ mod tests {
     fn test_properties() {
         assert_eq!(Pitch::G.pitch(), Pitch::G);
         assert_eq!(Pitch::G.base_frequency(), 24.50);
+        assert!(Pitch::AFlat.is_black_key());
+        assert!(!Pitch::B.is_black_key());
+    }
+
+    #[test]
+    fn test_pitch_class_set() {
+        let set = PitchClassSet::new(&[Pitch::E, Pitch::C, Pitch::DFlat, Pitch::C]);
+
+        assert_eq!(set.pitches(), &[Pitch::C, Pitch::DFlat, Pitch::E]);
+        assert_eq!(set.normal_form(), vec![Pitch::C, Pitch::DFlat, Pitch::E]);
+        assert_eq!(set.prime_form(), vec![0, 1, 4]);
+        assert_eq!(set.interval_vector(), [1, 0, 1, 1, 0, 0]);
+
+        // The inversion of [0, 1, 4] has the same prime form.
+        let inverted = PitchClassSet::new(&[Pitch::AFlat, Pitch::B, Pitch::C]);
+
+        assert_eq!(inverted.normal_form(), vec![Pitch::AFlat, Pitch::B, Pitch::C]);
+        assert_eq!(inverted.prime_form(), vec![0, 1, 4]);
+
+        // The major triad is the inversion of the minor triad.
+        let major = PitchClassSet::new(&[Pitch::G, Pitch::B, Pitch::D]);
+
+        assert_eq!(major.normal_form(), vec![Pitch::G, Pitch::B, Pitch::D]);
+        assert_eq!(major.prime_form(), vec![0, 3, 7]);
+        assert_eq!(major.interval_vector(), [0, 0, 1, 1, 1, 0]);
+
+        // The diminished seventh is symmetrical.
+        let diminished = PitchClassSet::new(&[Pitch::B, Pitch::D, Pitch::F, Pitch::AFlat]);
+
+        assert_eq!(diminished.normal_form(), vec![Pitch::D, Pitch::F, Pitch::AFlat, Pitch::B]);
+        assert_eq!(diminished.prime_form(), vec![0, 3, 6, 9]);
+        assert_eq!(diminished.interval_vector(), [0, 0, 4, 0, 0, 2]);
+
+        assert_eq!(PitchClassSet::default().prime_form(), Vec::<u8>::new());
+        assert_eq!(PitchClassSet::default().interval_vector(), [0; 6]);
+    }
+
+    #[test]
+    fn test_pitch_histogram() {
+        use crate::core::note::{CFive, GThree, B, C, D, E, G};
+
+        let c_major = PitchHistogram::from(&[C, E, G, CFive][..]);
+
+        assert_eq!(c_major.values(), &[2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(c_major.normalize().values().iter().sum::<f32>(), 1.0);
+        assert_eq!(c_major.normalize().values()[0], 0.5);
+        assert_eq!(c_major.dominant(), Some(Pitch::C));
+        assert_eq!(PitchHistogram::from(&[Pitch::C, Pitch::E, Pitch::G][..]), PitchHistogram::from_pitches(&[C, E, G]));
+
+        // A transposed histogram is dissimilar, until it is transposed back.
+        let g_major = PitchHistogram::from(&[GThree, B, D, G][..]);
+
+        assert_eq!(g_major.dominant(), Some(Pitch::G));
+        assert!(c_major.similarity(&g_major) < 0.5);
+        assert_eq!(c_major.transpose(7), g_major);
+        assert!((c_major.similarity(&g_major.transpose(-7)) - 1.0).abs() < 1e-6);
+        assert!((c_major.similarity(&c_major.normalize()) - 1.0).abs() < 1e-6);
+
+        // A frequency space is binned to the nearest pitch class.
+        let frequency_space = [(261.6, 1.0), (329.6, 0.5), (392.0, 0.5), (10.0, 100.0), (523.3, 1.0)];
+        let histogram = PitchHistogram::from_frequency_space(&frequency_space);
+
+        assert_eq!(histogram.values(), &[2.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0]);
+
+        assert_eq!(PitchHistogram::default().dominant(), None);
+        assert_eq!(PitchHistogram::default().similarity(&c_major), 0.0);
     }
 }
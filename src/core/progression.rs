@@ -0,0 +1,681 @@
+//! A module for working with chord progressions.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    base::{HasStaticName, Res},
+    chord::{Chord, Chordable, HasChord, HasModifiers, HasRoot},
+    helpers::write_smf,
+    interval::Interval,
+    modifier::{Degree, Modifier},
+    named_pitch::{HasNamedPitch, NamedPitch},
+    note::Note,
+    pitch::HasPitch,
+};
+
+// Structs.
+
+/// A struct representing a chord progression (i.e., an ordered sequence of [`Chord`]s).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct Progression {
+    /// The chords of the progression.
+    chords: Vec<Chord>,
+}
+
+/// The options used when reharmonizing a [`Progression`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct ReharmOptions {
+    /// Whether or not to replace dominant chords with their tritone substitutions.
+    pub tritone_substitutions: bool,
+    /// Whether or not to swap major and minor chords with their relatives.
+    pub relative_substitutions: bool,
+    /// Whether or not to insert dominant approach chords (a half step above or below) before chords.
+    pub approach_chords: bool,
+}
+
+// Enums.
+
+/// An enum representing the harmonic function of a chord within a (major) key.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum HarmonicFunction {
+    /// A diatonic tonic chord (i.e., `I`, `iii`, or `vi`).
+    Tonic,
+    /// A diatonic subdominant (predominant) chord (i.e., `ii`, or `IV`).
+    Subdominant,
+    /// A diatonic dominant chord (i.e., `V`, or `vii°`).
+    Dominant,
+    /// A chord with tones outside of the key (e.g., a borrowed chord, or a secondary dominant).
+    Borrowed,
+}
+
+// Impls.
+
+impl HasStaticName for HarmonicFunction {
+    fn static_name(&self) -> &'static str {
+        match self {
+            HarmonicFunction::Tonic => "tonic",
+            HarmonicFunction::Subdominant => "subdominant",
+            HarmonicFunction::Dominant => "dominant",
+            HarmonicFunction::Borrowed => "borrowed",
+        }
+    }
+}
+
+impl Default for ReharmOptions {
+    fn default() -> Self {
+        Self {
+            tritone_substitutions: true,
+            relative_substitutions: true,
+            approach_chords: true,
+        }
+    }
+}
+
+impl Progression {
+    /// Returns a new progression with the given chords.
+    pub fn new(chords: Vec<Chord>) -> Self {
+        Self { chords }
+    }
+
+    /// Returns the chords of the progression.
+    pub fn chords(&self) -> &[Chord] {
+        &self.chords
+    }
+
+    /// Parses a roman numeral progression (e.g., `ii V7 I`, or `I vi IV V`) in the given (major) key into concrete chords.
+    ///
+    /// Uppercase numerals are major, and lowercase numerals are minor.  Each numeral may be preceded by an accidental
+    /// (`b`, `♭`, `#`, or `♯`), and followed by a quality (`°` / `o` for diminished, `ø` for half-diminished, or `+` for
+    /// augmented), a seventh (`7`, `maj7`, `9`, `11`, or `13`), and a secondary target (e.g., `V7/V`).
+    ///
+    /// A bare numeral yields a triad (e.g., `ii` is `Dm`, and `II` is `D`, in C), where the bare leading tone (`vii`)
+    /// is the diatonic diminished triad.  Sevenths are only added by an explicit suffix (e.g., `V7`, `Imaj7`, or `viiø7`);
+    /// use [`Progression::parse_sevenths`] to have bare numerals yield the diatonic sevenths instead.
+    pub fn parse(input: &str, key: Note) -> Res<Vec<Chord>> {
+        parse_numerals(input, key, false)
+    }
+
+    /// Parses a roman numeral progression (e.g., `ii V7 I`) in the given (major) key into concrete chords, where bare numerals
+    /// yield the diatonic sevenths of the key (e.g., `ii V7 I` is `Dm7 G7 Cmaj7` in C).
+    ///
+    /// The numerals of the key are `Imaj7`, `ii7`, `iii7`, `IVmaj7`, `V7`, `vi7`, and `viiø7`; any other bare numeral
+    /// (e.g., `II` or `bVII`) yields a dominant seventh when uppercase, and a minor seventh when lowercase.  Otherwise, the
+    /// syntax is the same as [`Progression::parse`].
+    pub fn parse_sevenths(input: &str, key: Note) -> Res<Vec<Chord>> {
+        parse_numerals(input, key, true)
+    }
+
+    /// Returns the roman numeral of the chord in the given (major) key (e.g., `ii7`, `V7`, `Imaj7`, or `bVII`).
+    ///
+    /// The result uses the syntax accepted by [`Progression::parse`] (with sevenths always explicit).
+    pub fn roman_numeral(chord: &Chord, key: Note) -> String {
+        let modifiers = chord.modifiers();
+        let semitones = (chord.root().pitch() as u8 + 12 - key.pitch() as u8) % 12;
+        let (accidental, numeral) = ROMAN_NUMERAL_DEGREES[semitones as usize];
+
+        let is_diminished = modifiers.contains(&Modifier::Diminished);
+        let is_flat5 = modifiers.contains(&Modifier::Flat5);
+        let is_minor = is_diminished || modifiers.contains(&Modifier::Minor);
+
+        let dominant = modifiers.iter().find_map(|m| match m {
+            Modifier::Dominant(degree) => Some(*degree),
+            _ => None,
+        });
+
+        let seventh = if modifiers.contains(&Modifier::Major7) {
+            "maj7"
+        } else if let Some(degree) = dominant {
+            degree.static_name()
+        } else {
+            ""
+        };
+
+        let (quality, seventh) = if is_diminished {
+            ("°", "7")
+        } else if is_minor && is_flat5 && seventh == "7" {
+            ("ø", "7")
+        } else if is_minor && is_flat5 && seventh.is_empty() {
+            ("°", "")
+        } else if modifiers.contains(&Modifier::Augmented5) {
+            ("+", seventh)
+        } else {
+            ("", seventh)
+        };
+
+        let numeral = if is_minor { numeral.to_lowercase() } else { numeral.to_owned() };
+
+        format!("{}{}{}{}", accidental, numeral, quality, seventh)
+    }
+
+    /// Returns the harmonic function of the chord in the given (major) key.
+    ///
+    /// Chords with any tones outside of the key are considered [borrowed](HarmonicFunction::Borrowed).
+    pub fn harmonic_function(chord: &Chord, key: Note) -> HarmonicFunction {
+        let scale = MAJOR_SCALE_INTERVALS.iter().map(|i| (key + *i).pitch()).collect::<Vec<_>>();
+
+        if !chord.chord().iter().all(|n| scale.contains(&n.pitch())) {
+            return HarmonicFunction::Borrowed;
+        }
+
+        match (chord.root().pitch() as u8 + 12 - key.pitch() as u8) % 12 {
+            0 | 4 | 9 => HarmonicFunction::Tonic,
+            2 | 5 => HarmonicFunction::Subdominant,
+            _ => HarmonicFunction::Dominant,
+        }
+    }
+
+    /// Returns a minimal MusicXML `score-partwise` document for the progression.
+    ///
+    /// Each chord is written to its own 4/4 measure, and the key signature is derived from the `key` note.
+    pub fn to_musicxml(&self, key: Note) -> String {
+        let mut xml = String::new();
+
+        xml.push_str(r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>"#);
+        xml.push_str(r#"<score-partwise version="4.0">"#);
+        xml.push_str(r#"<part-list><score-part id="P1"><part-name>Kord</part-name></score-part></part-list>"#);
+        xml.push_str(r#"<part id="P1">"#);
+
+        for (k, chord) in self.chords.iter().enumerate() {
+            xml.push_str(&format!(r#"<measure number="{}">"#, k + 1));
+
+            if k == 0 {
+                // Named pitches are ordered by the circle of fifths, so the key signature is the distance from C.
+                let fifths = key.named_pitch() as i8 - NamedPitch::C as i8;
+
+                xml.push_str(&format!(
+                    "<attributes><divisions>1</divisions><key><fifths>{}</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time></attributes>",
+                    fifths
+                ));
+            }
+
+            xml.push_str(&chord.to_musicxml());
+            xml.push_str("</measure>");
+        }
+
+        xml.push_str("</part>");
+        xml.push_str("</score-partwise>");
+
+        xml
+    }
+}
+
+impl Progression {
+    /// Returns a type-0 Standard MIDI File that sounds each chord of the progression for the given number of ticks.
+    pub fn to_smf(&self, ticks: u32, velocity: u8) -> Vec<u8> {
+        Self::timed_to_smf(&self.chords.iter().map(|c| (c.clone(), ticks)).collect::<Vec<_>>(), velocity)
+    }
+
+    /// Returns a type-0 Standard MIDI File that sounds each chord for its own number of ticks.
+    ///
+    /// This mirrors the `Cm7|32` format of the CLI `loop` command, where every chord carries its own length.
+    pub fn timed_to_smf(chords: &[(Chord, u32)], velocity: u8) -> Vec<u8> {
+        let sequence = chords.iter().map(|(c, ticks)| (c.chord_midi(), *ticks)).collect::<Vec<_>>();
+
+        write_smf(&sequence, velocity)
+    }
+}
+
+impl Progression {
+    /// Returns the voice leading score of the progression.
+    ///
+    /// This is the average [voice leading distance](Chord::voice_leading_distance) between consecutive chords,
+    /// so lower scores imply "smoother" progressions.
+    pub fn voice_leading_score(&self) -> f32 {
+        if self.chords.len() < 2 {
+            return 0.0;
+        }
+
+        let total = self.chords.windows(2).map(|w| w[0].voice_leading_distance(&w[1])).sum::<u32>();
+
+        total as f32 / (self.chords.len() - 1) as f32
+    }
+
+    /// Returns variants of the progression, each with a single reharmonization applied (as allowed by the options).
+    ///
+    /// The variants are ranked by their [voice leading score](Progression::voice_leading_score), smoothest first.
+    pub fn reharmonize(&self, options: ReharmOptions) -> Vec<Progression> {
+        let mut result: Vec<Progression> = Vec::new();
+
+        for (k, chord) in self.chords.iter().enumerate() {
+            let mut substitutions = Vec::new();
+
+            if options.tritone_substitutions {
+                substitutions.extend(chord.tritone_substitution());
+            }
+
+            if options.relative_substitutions {
+                substitutions.extend(chord.relative_substitution());
+            }
+
+            for substitution in substitutions {
+                let mut chords = self.chords.clone();
+                chords[k] = substitution;
+
+                result.push(Progression::new(chords));
+            }
+
+            if options.approach_chords && k > 0 {
+                for from_below in [false, true] {
+                    // There are no approach chords when they would fall out of the octave range.
+                    let Some(approach) = chord.approach_chords(from_below).into_iter().next() else {
+                        continue;
+                    };
+
+                    if self.chords[k - 1] == approach {
+                        continue;
+                    }
+
+                    let mut chords = self.chords.clone();
+                    chords.insert(k, approach);
+
+                    result.push(Progression::new(chords));
+                }
+            }
+        }
+
+        // Remove duplicates (e.g., an approach chord that is also a substitution).
+        let mut unique: Vec<Progression> = Vec::with_capacity(result.len());
+        for progression in result {
+            if !unique.contains(&progression) {
+                unique.push(progression);
+            }
+        }
+
+        unique.sort_by(|a, b| a.voice_leading_score().total_cmp(&b.voice_leading_score()));
+
+        unique
+    }
+}
+
+impl From<Vec<Chord>> for Progression {
+    fn from(chords: Vec<Chord>) -> Self {
+        Self::new(chords)
+    }
+}
+
+// Roman numerals.
+
+/// The roman numerals (and their scale degrees), ordered so that longer numerals are matched first.
+static ROMAN_NUMERALS: [(&str, u8); 7] = [("VII", 7), ("III", 3), ("VI", 6), ("IV", 4), ("II", 2), ("V", 5), ("I", 1)];
+
+/// The roman numerals (and accidentals) for each semitone above the tonic of a major key.
+static ROMAN_NUMERAL_DEGREES: [(&str, &str); 12] = [
+    ("", "I"),
+    ("b", "II"),
+    ("", "II"),
+    ("b", "III"),
+    ("", "III"),
+    ("", "IV"),
+    ("#", "IV"),
+    ("", "V"),
+    ("b", "VI"),
+    ("", "VI"),
+    ("b", "VII"),
+    ("", "VII"),
+];
+
+/// The intervals of the major scale.
+static MAJOR_SCALE_INTERVALS: [Interval; 7] = [
+    Interval::PerfectUnison,
+    Interval::MajorSecond,
+    Interval::MajorThird,
+    Interval::PerfectFourth,
+    Interval::PerfectFifth,
+    Interval::MajorSixth,
+    Interval::MajorSeventh,
+];
+
+/// Returns the interval above the tonic of a major key for the given scale degree and accidental.
+fn degree_to_interval(degree: u8, accidental: i8) -> Option<Interval> {
+    match (degree, accidental) {
+        (1, 0) => Some(Interval::PerfectUnison),
+        (1, 1) => Some(Interval::AugmentedUnison),
+        (2, -1) => Some(Interval::MinorSecond),
+        (2, 0) => Some(Interval::MajorSecond),
+        (2, 1) => Some(Interval::AugmentedSecond),
+        (3, -1) => Some(Interval::MinorThird),
+        (3, 0) => Some(Interval::MajorThird),
+        (3, 1) => Some(Interval::AugmentedThird),
+        (4, -1) => Some(Interval::DiminishedFourth),
+        (4, 0) => Some(Interval::PerfectFourth),
+        (4, 1) => Some(Interval::AugmentedFourth),
+        (5, -1) => Some(Interval::DiminishedFifth),
+        (5, 0) => Some(Interval::PerfectFifth),
+        (5, 1) => Some(Interval::AugmentedFifth),
+        (6, -1) => Some(Interval::MinorSixth),
+        (6, 0) => Some(Interval::MajorSixth),
+        (6, 1) => Some(Interval::AugmentedSixth),
+        (7, -1) => Some(Interval::MinorSeventh),
+        (7, 0) => Some(Interval::MajorSeventh),
+        _ => None,
+    }
+}
+
+/// Parses the accidental and numeral at the start of the token.
+///
+/// Returns the root of the numeral in the key, whether or not the numeral is uppercase, the diatonic
+/// scale degree (or `0` if the numeral has an accidental), and the remainder of the token.
+fn parse_numeral(token: &str, key: Note) -> Res<(Note, bool, u8, &str)> {
+    let error = || anyhow::Error::msg(format!("Invalid roman numeral: `{}`.", token));
+
+    let (accidental, rest) = if let Some(rest) = token.strip_prefix('b').or_else(|| token.strip_prefix('♭')) {
+        (-1, rest)
+    } else if let Some(rest) = token.strip_prefix('#').or_else(|| token.strip_prefix('♯')) {
+        (1, rest)
+    } else {
+        (0, token)
+    };
+
+    let (numeral, degree) = ROMAN_NUMERALS.iter().find(|(n, _)| rest.get(..n.len()).is_some_and(|w| w.eq_ignore_ascii_case(n))).ok_or_else(error)?;
+    let (written, rest) = rest.split_at(numeral.len());
+
+    let is_upper = if written == *numeral {
+        true
+    } else if written == numeral.to_lowercase() {
+        false
+    } else {
+        return Err(error());
+    };
+
+    let interval = degree_to_interval(*degree, accidental).ok_or_else(error)?;
+    let diatonic_degree = if accidental == 0 { *degree } else { 0 };
+
+    Ok((key + interval, is_upper, diatonic_degree, rest))
+}
+
+/// Parses a roman numeral progression in the given (major) key, where bare numerals yield triads, or, with `sevenths`, the
+/// diatonic sevenths.
+fn parse_numerals(input: &str, key: Note, sevenths: bool) -> Res<Vec<Chord>> {
+    let tokens = input
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '-' || c == '|')
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>();
+
+    if tokens.is_empty() {
+        return Err(anyhow::Error::msg("The progression must contain at least one roman numeral."));
+    }
+
+    tokens.into_iter().map(|token| roman_numeral_to_chord(token, key, sevenths)).collect()
+}
+
+/// Converts a single roman numeral (e.g., `ii`, `V7`, `bVII`, or `V7/V`) into a chord in the given (major) key.
+fn roman_numeral_to_chord(token: &str, key: Note, sevenths: bool) -> Res<Chord> {
+    let error = || anyhow::Error::msg(format!("Invalid roman numeral: `{}`.", token));
+
+    // Secondary chords (e.g., `V7/V`) are parsed in the key of their target.
+    if let Some((primary, target)) = token.split_once('/') {
+        let (target_root, _, _, rest) = parse_numeral(target, key)?;
+
+        if !rest.is_empty() {
+            return Err(error());
+        }
+
+        return roman_numeral_to_chord(primary, target_root, sevenths);
+    }
+
+    let (root, is_upper, diatonic_degree, rest) = parse_numeral(token, key)?;
+
+    let (quality, rest) = match rest.chars().next() {
+        Some(c @ ('°' | 'o' | 'ø' | '+')) => (Some(c), &rest[c.len_utf8()..]),
+        _ => (None, rest),
+    };
+
+    let chord = Chord::new(root);
+
+    let chord = match (quality, rest, is_upper) {
+        // Bare numerals yield the diatonic sevenths (when requested), where non-diatonic numerals are dominant or minor.
+        (None, "", true) if sevenths && matches!(diatonic_degree, 1 | 4) => chord.maj7(),
+        (None, "", true) if sevenths => chord.seven(),
+        (None, "", false) if sevenths && diatonic_degree == 7 => chord.half_dim(),
+        (None, "", false) if sevenths => chord.minor().seven(),
+
+        // Bare numerals yield triads (and the leading tone is diminished, as in the key).
+        (None, "", false) if diatonic_degree == 7 => chord.minor().flat5(),
+        (None, "", true) => chord,
+        (None, "", false) => chord.minor(),
+
+        // Explicit sevenths (and extensions).
+        (None, "maj7", true) => chord.maj7(),
+        (None, "maj7", false) => chord.minor().maj7(),
+        (None, "7", true) => chord.seven(),
+        (None, "7", false) => chord.minor().seven(),
+        (None, "9", true) => chord.dominant(Degree::Nine),
+        (None, "9", false) => chord.minor().dominant(Degree::Nine),
+        (None, "11", true) => chord.dominant(Degree::Eleven),
+        (None, "11", false) => chord.minor().dominant(Degree::Eleven),
+        (None, "13", true) => chord.dominant(Degree::Thirteen),
+        (None, "13", false) => chord.minor().dominant(Degree::Thirteen),
+
+        // Explicit qualities.
+        (Some('°' | 'o'), "", _) => chord.minor().flat5(),
+        (Some('°' | 'o'), "7", _) => chord.dim(),
+        (Some('ø'), "" | "7", _) => chord.half_dim(),
+        (Some('+'), "", _) => chord.augmented(),
+        (Some('+'), "7", _) => chord.augmented().seven(),
+        (Some('+'), "maj7", _) => chord.augmented().maj7(),
+
+        _ => return Err(error()),
+    };
+
+    Ok(chord)
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        base::Parsable,
+        chord::tests::{assert_well_formed_xml, decode_smf_note_events},
+        note::*,
+        octave::{HasOctave, Octave},
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_musicxml() {
+        let progression = Progression::new(vec![Chord::parse("Dm7").unwrap(), Chord::parse("G7").unwrap(), Chord::parse("Cmaj7").unwrap()]);
+
+        let xml = progression.to_musicxml(C);
+
+        assert_well_formed_xml(&xml);
+        assert_eq!(xml.matches("<measure ").count(), 3);
+        assert!(xml.contains("<fifths>0</fifths>"));
+        assert!(xml.contains("<kind text=\"maj7\">major-seventh</kind>"));
+
+        assert!(progression.to_musicxml(EFlat).contains("<fifths>-3</fifths>"));
+        assert!(progression.to_musicxml(FSharp).contains("<fifths>6</fifths>"));
+    }
+
+    #[test]
+    fn test_smf() {
+        let chords = vec![(Chord::parse("C").unwrap(), 480), (Chord::parse("G").unwrap(), 960)];
+
+        let events = decode_smf_note_events(&Progression::timed_to_smf(&chords, 90));
+
+        assert_eq!(
+            events,
+            vec![
+                (0, true, 60, 90),
+                (0, true, 64, 90),
+                (0, true, 67, 90),
+                (480, false, 60, 0),
+                (480, false, 64, 0),
+                (480, false, 67, 0),
+                (480, true, 67, 90),
+                (480, true, 71, 90),
+                (480, true, 74, 90),
+                (1440, false, 67, 0),
+                (1440, false, 71, 0),
+                (1440, false, 74, 0),
+            ]
+        );
+
+        let progression = Progression::new(chords.into_iter().map(|(c, _)| c).collect());
+
+        assert_eq!(decode_smf_note_events(&progression.to_smf(480, 90)).last(), Some(&(960, false, 74, 0)));
+    }
+
+    #[test]
+    fn test_reharmonize() {
+        let progression = Progression::new(vec![Chord::parse("Dm7").unwrap(), Chord::parse("G7").unwrap(), Chord::parse("Cmaj7").unwrap()]);
+
+        let variants = progression.reharmonize(ReharmOptions::default());
+
+        assert!(variants
+            .iter()
+            .any(|p| p.chords() == [Chord::parse("Dm7").unwrap(), Chord::parse("Db7").unwrap(), Chord::parse("Cmaj7").unwrap()]));
+        assert!(variants.iter().any(|p| p.chords().len() == 4));
+        assert!(variants.windows(2).all(|w| w[0].voice_leading_score() <= w[1].voice_leading_score()));
+
+        let tritone_only = progression.reharmonize(ReharmOptions {
+            tritone_substitutions: true,
+            relative_substitutions: false,
+            approach_chords: false,
+        });
+
+        assert_eq!(tritone_only.len(), 1);
+        assert_eq!(tritone_only[0].chords()[1], Chord::parse("Db7").unwrap());
+
+        let none = progression.reharmonize(ReharmOptions {
+            tritone_substitutions: false,
+            relative_substitutions: false,
+            approach_chords: false,
+        });
+
+        assert!(none.is_empty());
+
+        // Chords at the edges of the octave range skip the approach chords that they cannot produce.
+        let progression = Progression::new(vec![Chord::new(GZero).seven(), Chord::new(CZero)]);
+        let variants = progression.reharmonize(ReharmOptions::default());
+
+        assert!(variants.iter().any(|p| p.chords() == [Chord::new(GZero).seven(), Chord::new(DFlatZero).seven(), Chord::new(CZero)]));
+        assert!(variants.iter().all(|p| p.chords().iter().all(|c| c.root().octave() == Octave::Zero)));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Progression::parse("ii V7 I", C).unwrap(),
+            vec![Chord::parse("Dm").unwrap(), Chord::parse("G7").unwrap(), Chord::parse("C").unwrap()]
+        );
+
+        // Bare numerals are triads, and sevenths are explicit.
+        assert_eq!(
+            Progression::parse("I vi IV V", G).unwrap(),
+            vec![Chord::new(G), Chord::new(EFive).minor(), Chord::new(CFive), Chord::new(DFive)]
+        );
+        assert_eq!(
+            Progression::parse("Imaj7 vi7 ii7 V7 vii", C).unwrap(),
+            vec![
+                Chord::new(C).maj7(),
+                Chord::new(A).minor().seven(),
+                Chord::new(D).minor().seven(),
+                Chord::new(G).seven(),
+                Chord::new(B).minor().flat5()
+            ]
+        );
+        assert_ne!(Progression::parse("V", C).unwrap(), Progression::parse("V7", C).unwrap());
+
+        // Bare numerals are diatonic sevenths in the sevenths mode.
+        assert_eq!(
+            Progression::parse_sevenths("ii V7 I", C).unwrap(),
+            vec![Chord::parse("Dm7").unwrap(), Chord::parse("G7").unwrap(), Chord::parse("Cmaj7").unwrap()]
+        );
+        assert_eq!(
+            Progression::parse_sevenths("I ii iii IV V vi vii", C).unwrap(),
+            vec![
+                Chord::new(C).maj7(),
+                Chord::new(D).minor().seven(),
+                Chord::new(E).minor().seven(),
+                Chord::new(F).maj7(),
+                Chord::new(G).seven(),
+                Chord::new(A).minor().seven(),
+                Chord::new(B).half_dim()
+            ]
+        );
+        assert_eq!(
+            Progression::parse_sevenths("II bVII iv V/V V°", C).unwrap(),
+            vec![
+                Chord::new(D).seven(),
+                Chord::new(BFlat).seven(),
+                Chord::new(F).minor().seven(),
+                Chord::new(DFive).seven(),
+                Chord::new(G).minor().flat5()
+            ]
+        );
+
+        assert_eq!(
+            Progression::parse("bVII7 | #iv° - viiø7, iii II III+ vii°7", C).unwrap(),
+            vec![
+                Chord::new(BFlat).seven(),
+                Chord::new(FSharp).minor().flat5(),
+                Chord::new(B).half_dim(),
+                Chord::new(E).minor(),
+                Chord::new(D),
+                Chord::new(E).augmented(),
+                Chord::new(B).dim(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_secondary() {
+        assert_eq!(
+            Progression::parse("V7/V V7 I", C).unwrap(),
+            vec![Chord::new(DFive).seven(), Chord::parse("G7").unwrap(), Chord::parse("C").unwrap()]
+        );
+
+        assert_eq!(Progression::parse("V7/ii", F).unwrap(), vec![Chord::new(DFive).seven()]);
+    }
+
+    #[test]
+    fn test_roman_numeral() {
+        let chords = Chord::parse_many("Dm7 G7 Cmaj7 Bbmaj7 Bm7b5 Bdim F#m7b5 Em Ab+ D9").unwrap();
+        let numerals = chords.iter().map(|c| Progression::roman_numeral(c, C)).collect::<Vec<_>>();
+
+        assert_eq!(numerals, vec!["ii7", "V7", "Imaj7", "bVIImaj7", "viiø7", "vii°7", "#ivø7", "iii", "bVI+", "II9"]);
+
+        // The numerals round trip through the parser.
+        for (chord, numeral) in chords.iter().zip(&numerals) {
+            assert_eq!(&Progression::parse(numeral, C).unwrap()[0].chord(), &chord.chord());
+        }
+
+        assert_eq!(Progression::roman_numeral(&Chord::parse("D7").unwrap(), G), "V7");
+        assert_eq!(Progression::roman_numeral(&Chord::parse("F#m(b5)").unwrap(), C), "#iv°");
+    }
+
+    #[test]
+    fn test_harmonic_function() {
+        let function = |symbol: &str| Progression::harmonic_function(&Chord::parse(symbol).unwrap(), C);
+
+        assert_eq!(function("Cmaj7"), HarmonicFunction::Tonic);
+        assert_eq!(function("Em7"), HarmonicFunction::Tonic);
+        assert_eq!(function("Am"), HarmonicFunction::Tonic);
+        assert_eq!(function("Dm7"), HarmonicFunction::Subdominant);
+        assert_eq!(function("F"), HarmonicFunction::Subdominant);
+        assert_eq!(function("G7"), HarmonicFunction::Dominant);
+        assert_eq!(function("Bm7b5"), HarmonicFunction::Dominant);
+        assert_eq!(function("Bbmaj7"), HarmonicFunction::Borrowed);
+        assert_eq!(function("D7"), HarmonicFunction::Borrowed);
+        assert_eq!(function("Fm"), HarmonicFunction::Borrowed);
+
+        assert_eq!(HarmonicFunction::Subdominant.static_name(), "subdominant");
+    }
+
+    #[test]
+    fn test_parse_failure() {
+        assert!(Progression::parse("", C).is_err());
+        assert!(Progression::parse("X", C).is_err());
+        assert!(Progression::parse("Ii", C).is_err());
+        assert!(Progression::parse("IIIb", C).is_err());
+        assert!(Progression::parse("V7/", C).is_err());
+        assert!(Progression::parse("Vsus", C).is_err());
+        assert!(Progression::parse("#VII", C).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+//! A module for working with tuning systems (i.e., the reference pitch and temperament used to compute frequencies).
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::note::Note;
+
+// Enum.
+
+/// An enum representing the temperament of a [`Tuning`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum Temperament {
+    /// Twelve-tone equal temperament (12-TET).
+    #[default]
+    Equal,
+    /// Just intonation (five-limit) relative to the given tonic.
+    ///
+    /// The tonic itself is tuned using equal temperament, and every other note is a pure ratio from the tonic.
+    Just(Note),
+}
+
+// Struct.
+
+/// A struct representing a tuning system (i.e., a reference frequency for `A4`, and a [`Temperament`]).
+///
+/// The default tuning is twelve-tone equal temperament with `A4` at 440 Hz.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Tuning {
+    /// The reference frequency for `A4`.
+    a4: f32,
+    /// The temperament.
+    temperament: Temperament,
+}
+
+// Impls.
+
+impl Tuning {
+    /// Returns a new equal temperament tuning with the given reference frequency for `A4`.
+    pub fn equal(a4: f32) -> Self {
+        Self { a4, temperament: Temperament::Equal }
+    }
+
+    /// Returns a new just intonation tuning relative to the given tonic, with the given reference frequency for `A4`.
+    pub fn just(tonic: Note, a4: f32) -> Self {
+        Self {
+            a4,
+            temperament: Temperament::Just(tonic),
+        }
+    }
+
+    /// Returns the reference frequency for `A4`.
+    pub fn a4(&self) -> f32 {
+        self.a4
+    }
+
+    /// Returns the temperament.
+    pub fn temperament(&self) -> Temperament {
+        self.temperament
+    }
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::equal(STANDARD_A4)
+    }
+}
+
+// Statics.
+
+/// The standard reference frequency for `A4`.
+pub const STANDARD_A4: f32 = 440.0;
+
+/// The (five-limit) just intonation ratios for each semitone above the tonic.
+pub static JUST_INTONATION_RATIOS: [f32; 12] = [
+    1.0,
+    16.0 / 15.0,
+    9.0 / 8.0,
+    6.0 / 5.0,
+    5.0 / 4.0,
+    4.0 / 3.0,
+    45.0 / 32.0,
+    3.0 / 2.0,
+    8.0 / 5.0,
+    5.0 / 3.0,
+    9.0 / 5.0,
+    15.0 / 8.0,
+];
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{note::*, pitch::HasFrequency};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_equal() {
+        for note in ALL_PITCH_NOTES.iter() {
+            assert_eq!(note.frequency_with(&Tuning::default()), note.frequency());
+        }
+
+        assert!((A.frequency_with(&Tuning::equal(432.0)) - 432.0).abs() < 0.01);
+        assert!((C.frequency_with(&Tuning::equal(432.0)) - 256.87).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_just() {
+        let equal = Tuning::default();
+        let just = Tuning::just(C, 440.0);
+
+        // The tonic is unchanged.
+        assert_eq!(C.frequency_with(&just), C.frequency());
+
+        // The just major third is a pure 5:4, which is narrower than the equal tempered third.
+        let just_third = E.frequency_with(&just) / C.frequency_with(&just);
+        let equal_third = E.frequency_with(&equal) / C.frequency_with(&equal);
+
+        assert!((just_third - 1.25).abs() < 1e-6);
+        assert!((equal_third - 1.2599).abs() < 1e-3);
+        assert!(just_third < equal_third);
+
+        // The just perfect fifth is a pure 3:2.
+        assert!((G.frequency_with(&just) / C.frequency_with(&just) - 1.5).abs() < 1e-6);
+
+        // Ratios hold across octaves, including below the tonic.
+        assert!((EFive.frequency_with(&just) / C.frequency_with(&just) - 2.5).abs() < 1e-5);
+        assert!((AThree.frequency_with(&just) / C.frequency_with(&just) - 5.0 / 6.0).abs() < 1e-5);
+    }
+}
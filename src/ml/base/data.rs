@@ -2,15 +2,22 @@
 
 use burn::tensor::{backend::Backend, Data, Tensor};
 
+use crate::core::tuning::Tuning;
+
 use super::{
-    helpers::{get_deterministic_guess, mel_filter_banks_from, note_binned_convolution, u128_to_binary},
+    helpers::{get_deterministic_guess, get_deterministic_guess_with_tuning, mel_filter_banks_from, note_binned_convolution_with_tuning, u128_to_binary},
     KordItem, INPUT_SPACE_SIZE, NUM_CLASSES,
 };
 
 /// Takes a loaded kord item and converts it to a sample tensor that is ready for classification.
 pub fn kord_item_to_sample_tensor<B: Backend>(item: &KordItem) -> Tensor<B, 2> {
+    kord_item_to_sample_tensor_with_tuning(item, &Tuning::default())
+}
+
+/// Takes a loaded kord item (recorded with the given tuning) and converts it to a sample tensor that is ready for classification.
+pub fn kord_item_to_sample_tensor_with_tuning<B: Backend>(item: &KordItem, tuning: &Tuning) -> Tensor<B, 2> {
     //kord_item_to_large_sample_tensor(item)
-    kord_item_to_note_binned_convolution_tensor(item)
+    kord_item_to_note_binned_convolution_tensor(item, tuning)
     //kord_item_to_mel_sample_tensor(item)
     //kord_item_to_bins_sample_tensor(item)
 }
@@ -64,16 +71,21 @@ fn kord_item_to_large_sample_tensor<B: Backend>(item: &KordItem) -> Tensor<B, 2>
     tensor.reshape([1, INPUT_SPACE_SIZE])
 }
 
-fn kord_item_to_note_binned_convolution_tensor<B: Backend>(item: &KordItem) -> Tensor<B, 2> {
+fn kord_item_to_note_binned_convolution_tensor<B: Backend>(item: &KordItem, tuning: &Tuning) -> Tensor<B, 2> {
     let frequency_space = item.frequency_space;
 
-    let mut convolution = note_binned_convolution(&frequency_space);
+    let mut convolution = note_binned_convolution_with_tuning(&frequency_space, tuning);
 
     // Normalize the mel space peaks.
     normalize(&mut convolution);
 
     // Get the "deterministic guess".
-    let deterministic_guess: [f32; 128] = u128_to_binary(get_deterministic_guess(item)).iter().map(|v| v * 1.0).collect::<Vec<_>>().try_into().unwrap();
+    let deterministic_guess: [f32; 128] = u128_to_binary(get_deterministic_guess_with_tuning(item, tuning))
+        .iter()
+        .map(|v| v * 1.0)
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
     //let deterministic_guess = fold_binary(&deterministic_guess);
 
     let mut result: [f32; INPUT_SPACE_SIZE] = [&deterministic_guess[..], &convolution[..]].concat().try_into().unwrap();
@@ -21,6 +21,7 @@ use crate::{
         helpers::{inv_mel, mel},
         note::{HasNoteId, Note, ALL_PITCH_NOTES_WITH_FREQUENCY},
         pitch::HasFrequency,
+        tuning::{Tuning, STANDARD_A4},
     },
 };
 
@@ -113,14 +114,21 @@ pub fn mel_filter_banks_from(spectrum: &[f32]) -> [f32; MEL_SPACE_SIZE] {
 
 /// Run a note-binned "harmonic convolution" over the frequency space data.
 pub fn note_binned_convolution(spectrum: &[f32]) -> [f32; NUM_CLASSES] {
+    note_binned_convolution_with_tuning(spectrum, &Tuning::default())
+}
+
+/// Run a note-binned "harmonic convolution" over the frequency space data, binning the notes with the given tuning.
+pub fn note_binned_convolution_with_tuning(spectrum: &[f32], tuning: &Tuning) -> [f32; NUM_CLASSES] {
     let mut convolution = [0f32; NUM_CLASSES];
 
-    for (note, _) in ALL_PITCH_NOTES_WITH_FREQUENCY.iter().skip(7).take(90) {
+    for (note, frequency) in ALL_PITCH_NOTES_WITH_FREQUENCY.iter().skip(7).take(90) {
         let id_index = note.id_index();
 
+        // Scale the (standard) tight frequency range of the note to the tuned frequency.
+        let ratio = note.frequency_with(tuning) / frequency;
         let (low, high) = note.tight_frequency_range();
-        let low = low.round() as usize;
-        let high = high.round() as usize;
+        let low = (low * ratio).round() as usize;
+        let high = (high * ratio).round() as usize;
 
         if high >= FREQUENCY_SPACE_SIZE {
             continue;
@@ -174,13 +182,42 @@ pub fn linspace(start: f32, end: f32, num_points: usize) -> Vec<f32> {
 
 /// Gets the "deterministic guess" for a given kord item.
 pub fn get_deterministic_guess(kord_item: &KordItem) -> u128 {
-    let smoothed_frequency_space = kord_item.frequency_space.into_iter().enumerate().map(|(k, v)| (k as f32, v)).collect::<Vec<_>>();
+    get_deterministic_guess_with_tuning(kord_item, &Tuning::default())
+}
+
+/// Gets the "deterministic guess" for a given kord item that was recorded with the given tuning.
+pub fn get_deterministic_guess_with_tuning(kord_item: &KordItem, tuning: &Tuning) -> u128 {
+    let frequency_space = retune_frequency_space(&kord_item.frequency_space, tuning.a4());
+    let smoothed_frequency_space = frequency_space.into_iter().enumerate().map(|(k, v)| (k as f32, v)).collect::<Vec<_>>();
 
     let notes = get_notes_from_smoothed_frequency_space(&smoothed_frequency_space);
 
     Note::id_mask(&notes)
 }
 
+/// Rescales a (1 Hz resolution) frequency space recorded with the given `A4` reference so that its notes line up with the
+/// standard 440 Hz reference, linearly interpolating between bins.
+pub fn retune_frequency_space(spectrum: &[f32; FREQUENCY_SPACE_SIZE], a4: f32) -> [f32; FREQUENCY_SPACE_SIZE] {
+    if a4 == STANDARD_A4 {
+        return *spectrum;
+    }
+
+    let ratio = a4 / STANDARD_A4;
+    let mut retuned = [0f32; FREQUENCY_SPACE_SIZE];
+
+    for (k, value) in retuned.iter_mut().enumerate() {
+        let position = k as f32 * ratio;
+        let index = position as usize;
+        let fraction = position.fract();
+
+        if index + 1 < FREQUENCY_SPACE_SIZE {
+            *value = spectrum[index] * (1.0 - fraction) + spectrum[index + 1] * fraction;
+        }
+    }
+
+    retuned
+}
+
 /// Produces a 128 element array of 0s and 1s from a u128.
 pub fn u128_to_binary(num: u128) -> [f32; 128] {
     let mut binary = [0f32; 128];
@@ -238,3 +275,43 @@ impl<B: Backend> Sigmoid<B> {
         scaled.clone().exp().div(scaled.exp().add_scalar(1.0))
     }
 }
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::note::{HasNoteId, ALL_PITCH_NOTES};
+
+    #[test]
+    fn test_note_binned_convolution_with_tuning() {
+        let tuning = Tuning::equal(432.0);
+        let notes = ALL_PITCH_NOTES.iter().skip(12 * 4).take(12).copied().collect::<Vec<_>>();
+
+        let mut spectrum = [0f32; FREQUENCY_SPACE_SIZE];
+        for note in &notes {
+            spectrum[note.frequency_with(&tuning).round() as usize] = 1.0;
+        }
+
+        let standard = note_binned_convolution(&spectrum);
+        let tuned = note_binned_convolution_with_tuning(&spectrum, &tuning);
+
+        for note in &notes {
+            assert_eq!(tuned[note.id_index() as usize], 1.0);
+            assert_eq!(standard[note.id_index() as usize], 0.0);
+        }
+
+        assert_eq!(tuned.iter().sum::<f32>(), notes.len() as f32);
+    }
+
+    #[test]
+    fn test_retune_frequency_space() {
+        let mut spectrum = [0f32; FREQUENCY_SPACE_SIZE];
+        spectrum[432] = 1.0;
+
+        let retuned = retune_frequency_space(&spectrum, 432.0);
+
+        assert!((retuned[440] - 1.0).abs() < 1e-3);
+        assert_eq!(retune_frequency_space(&spectrum, 440.0), spectrum);
+    }
+}
@@ -80,6 +80,35 @@ pub struct TrainConfig {
     pub no_plots: bool,
 }
 
+// Progress.
+
+/// A progress event emitted while training or inferring, so that embedding applications can display progress.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// A training epoch has started (both numbers are one-based).
+    EpochStart {
+        /// The epoch that started.
+        epoch: usize,
+        /// The total number of epochs.
+        epochs: usize,
+    },
+    /// A training batch has completed.
+    BatchDone {
+        /// The (one-based) epoch of the batch.
+        epoch: usize,
+        /// The (one-based) batch within the epoch.
+        batch: usize,
+        /// The number of items processed so far in the epoch.
+        items_processed: usize,
+        /// The total number of items in the epoch.
+        items_total: usize,
+    },
+    /// An inference stage has started (e.g., `"loading model"`).
+    Stage(&'static str),
+    /// The training or inference has finished.
+    Finished,
+}
+
 /// A single kord sample.
 ///
 /// This is a single sample of a kord, which is a set of notes played together.
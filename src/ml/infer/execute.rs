@@ -8,18 +8,66 @@ use burn::{
 };
 use burn_ndarray::{NdArray, NdArrayDevice};
 use serde::{de::DeserializeOwned, Serialize};
+use std::path::PathBuf;
 
 use crate::{
     analyze::base::{get_frequency_space, get_smoothed_frequency_space},
     core::{
         base::Res,
         note::{HasNoteId, Note},
+        tuning::Tuning,
     },
-    ml::base::{data::kord_item_to_sample_tensor, helpers::binary_to_u128, model::KordModel, KordItem, TrainConfig, FREQUENCY_SPACE_SIZE},
+    ml::base::{data::kord_item_to_sample_tensor_with_tuning, helpers::binary_to_u128, model::KordModel, KordItem, ProgressEvent, TrainConfig, FREQUENCY_SPACE_SIZE, NUM_CLASSES},
 };
 
+// Structs.
+
+/// The result of running inference on a single audio file as part of a batch (see [`infer_batch`]).
+#[derive(Debug)]
+pub struct InferenceResult {
+    /// The path to the audio file.
+    pub path: PathBuf,
+    /// The inferred notes (empty if the inference failed).
+    pub notes: Vec<Note>,
+    /// The raw per-class probabilities from the model's final sigmoid (all zeros if the inference failed).
+    ///
+    /// See [`notes_from_probabilities`] for the encoding of the indices.
+    pub probabilities: [f32; NUM_CLASSES],
+    /// The error that occurred while processing the file, if any.
+    pub error: Option<anyhow::Error>,
+}
+
+// Impls.
+
+impl InferenceResult {
+    /// Returns whether the inference succeeded for this file.
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Re-thresholds the probabilities at the given cutoff (without re-running the model), returning the notes whose probability
+    /// is at least `threshold`.
+    ///
+    /// The default threshold used to produce [`InferenceResult::notes`] is `0.5`.
+    pub fn notes_at_threshold(&self, threshold: f32) -> Vec<Note> {
+        notes_from_probabilities(&self.probabilities, threshold)
+    }
+}
+
+// Functions.
+
 /// Run the inference on a sample to produce a [`Vec`] of [`Note`]s.
 pub fn run_inference<B: Backend>(device: &B::Device, kord_item: &KordItem) -> Res<Vec<Note>>
+where
+    B::FloatElem: Serialize + DeserializeOwned,
+{
+    let model = load_model::<B>()?;
+
+    run_inference_with_model(&model, device, kord_item)
+}
+
+/// Load the model (and its config) that is embedded in the binary.
+pub fn load_model<B: Backend>() -> Res<KordModel<B>>
 where
     B::FloatElem: Serialize + DeserializeOwned,
 {
@@ -42,20 +90,139 @@ where
     // Define the model.
     let model = KordModel::<B>::new(config.mha_heads, config.mha_dropout, config.sigmoid_strength).load_record(recorder);
 
+    Ok(model)
+}
+
+/// Run the inference on a sample with an already loaded model to produce a [`Vec`] of [`Note`]s.
+pub fn run_inference_with_model<B: Backend>(model: &KordModel<B>, device: &B::Device, kord_item: &KordItem) -> Res<Vec<Note>> {
+    let probabilities = run_inference_probabilities_with_model(model, device, kord_item, &Tuning::default())?;
+
+    Ok(notes_from_probabilities(&probabilities, DEFAULT_THRESHOLD))
+}
+
+/// Run the inference on a sample (recorded with the given tuning) with an already loaded model to produce the raw per-class probabilities.
+///
+/// See [`notes_from_probabilities`] for the encoding of the indices.
+pub fn run_inference_probabilities_with_model<B: Backend>(model: &KordModel<B>, device: &B::Device, kord_item: &KordItem, tuning: &Tuning) -> Res<[f32; NUM_CLASSES]> {
     // Prepare the sample.
-    let sample = kord_item_to_sample_tensor(kord_item).to_device(device).detach();
+    let sample = kord_item_to_sample_tensor_with_tuning(kord_item, tuning).to_device(device).detach();
 
     // Run the inference.
-    let inferred = model.forward(sample).to_data().convert().value.into_iter().map(f32::round).collect::<Vec<_>>();
-    let inferred_array: [_; 128] = inferred.try_into().unwrap();
-    let mut inferred_notes = Note::from_id_mask(binary_to_u128(&inferred_array)).unwrap();
-    inferred_notes.sort();
+    let inferred: Vec<f32> = model.forward(sample).to_data().convert().value;
+    let probabilities: [f32; NUM_CLASSES] = inferred.try_into().map_err(|_| anyhow::Error::msg("The model produced an unexpected number of classes."))?;
+
+    Ok(probabilities)
+}
+
+/// Converts the per-class probabilities into the [`Note`]s whose probability is at least `threshold`.
+///
+/// The probabilities use the full (unfolded) 128-class encoding, in which the note with ID index `i` (i.e., `12 * octave + pitch`,
+/// see [`HasNoteId::id_index`]) is at index `127 - i`.
+pub fn notes_from_probabilities(probabilities: &[f32; NUM_CLASSES], threshold: f32) -> Vec<Note> {
+    let binary = probabilities.map(|p| if p >= threshold { 1.0 } else { 0.0 });
 
-    Ok(inferred_notes)
+    let mut notes = Note::from_id_mask(binary_to_u128(&binary)).unwrap();
+    notes.sort();
+
+    notes
+}
+
+/// Infer the raw per-class probabilities from the audio data.
+///
+/// See [`notes_from_probabilities`] for the encoding of the indices.
+pub fn infer_probabilities(audio_data: &[f32], length_in_seconds: u8) -> Res<[f32; NUM_CLASSES]> {
+    let kord_item = audio_data_to_kord_item(audio_data, length_in_seconds);
+
+    let device = NdArrayDevice::Cpu;
+    let model = load_model::<NdArray<f32>>()?;
+
+    run_inference_probabilities_with_model(&model, &device, &kord_item, &Tuning::default())
 }
 
 /// Infer notes from the audio data.
 pub fn infer(audio_data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
+    infer_with_tuning(audio_data, length_in_seconds, &Tuning::default())
+}
+
+/// Infer notes from audio data that was recorded with the given tuning (e.g., with `A4` at 432 Hz).
+pub fn infer_with_tuning(audio_data: &[f32], length_in_seconds: u8, tuning: &Tuning) -> Res<Vec<Note>> {
+    infer_with_progress(audio_data, length_in_seconds, tuning, |_| {})
+}
+
+/// Infer notes from audio data that was recorded with the given tuning (see [`infer_with_tuning`]), reporting each
+/// [`ProgressEvent::Stage`] (and then [`ProgressEvent::Finished`]) to the `progress` callback.
+pub fn infer_with_progress(audio_data: &[f32], length_in_seconds: u8, tuning: &Tuning, progress: impl Fn(ProgressEvent)) -> Res<Vec<Note>> {
+    progress(ProgressEvent::Stage("analyzing audio"));
+    let kord_item = audio_data_to_kord_item(audio_data, length_in_seconds);
+
+    progress(ProgressEvent::Stage("loading model"));
+    let device = NdArrayDevice::Cpu;
+    let model = load_model::<NdArray<f32>>()?;
+
+    // Run the inference.
+    progress(ProgressEvent::Stage("running model"));
+    let probabilities = run_inference_probabilities_with_model(&model, &device, &kord_item, tuning)?;
+
+    progress(ProgressEvent::Finished);
+
+    Ok(notes_from_probabilities(&probabilities, DEFAULT_THRESHOLD))
+}
+
+/// Infer notes from each of the given audio files, returning one [`InferenceResult`] per file (in the same order as `paths`).
+///
+/// The model is only loaded once for the whole batch, and the files are decoded in parallel.  A file that fails to decode or infer
+/// does not abort the batch; instead, its result carries the error.
+#[cfg(feature = "analyze_file")]
+pub fn infer_batch(paths: &[PathBuf]) -> Res<Vec<InferenceResult>> {
+    use crate::analyze::file::get_audio_data_from_file;
+    use rayon::prelude::*;
+
+    // Decode the files, and prepare the samples.
+
+    let kord_items = paths
+        .par_iter()
+        .map(|path| {
+            let (audio_data, length_in_seconds) = get_audio_data_from_file(path, None, None)?;
+
+            if length_in_seconds < 1 {
+                return Err(anyhow::Error::msg(format!("Audio file `{}` is shorter than one second.", path.display())));
+            }
+
+            Ok(audio_data_to_kord_item(&audio_data, length_in_seconds))
+        })
+        .collect::<Vec<_>>();
+
+    // Run the inference.
+
+    let device = NdArrayDevice::Cpu;
+    let model = load_model::<NdArray<f32>>()?;
+
+    let results = paths
+        .iter()
+        .zip(kord_items)
+        .map(
+            |(path, kord_item)| match kord_item.and_then(|kord_item| run_inference_probabilities_with_model(&model, &device, &kord_item, &Tuning::default())) {
+                Ok(probabilities) => InferenceResult {
+                    path: path.clone(),
+                    notes: notes_from_probabilities(&probabilities, DEFAULT_THRESHOLD),
+                    probabilities,
+                    error: None,
+                },
+                Err(error) => InferenceResult {
+                    path: path.clone(),
+                    notes: Vec::new(),
+                    probabilities: [0.0; NUM_CLASSES],
+                    error: Some(error),
+                },
+            },
+        )
+        .collect();
+
+    Ok(results)
+}
+
+/// Converts audio data into a [`KordItem`] (with its smoothed frequency space) that is ready for inference.
+fn audio_data_to_kord_item(audio_data: &[f32], length_in_seconds: u8) -> KordItem {
     let frequency_space = get_frequency_space(audio_data, length_in_seconds);
     let smoothed_frequency_space: [_; FREQUENCY_SPACE_SIZE] = get_smoothed_frequency_space(&frequency_space, length_in_seconds)
         .into_iter()
@@ -65,20 +232,17 @@ pub fn infer(audio_data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
         .try_into()
         .unwrap();
 
-    let kord_item = KordItem {
+    KordItem {
         frequency_space: smoothed_frequency_space,
         ..Default::default()
-    };
-
-    let device = NdArrayDevice::Cpu;
-
-    // Run the inference.
-    let notes = run_inference::<NdArray<f32>>(&device, &kord_item)?;
-
-    Ok(notes)
+    }
 }
 
 // Statics.
+
+/// The default probability threshold at which a note is considered present.
+const DEFAULT_THRESHOLD: f32 = 0.5;
+
 #[cfg(host_family_unix)]
 static CONFIG: &[u8] = include_bytes!("../../../model/model_config.json");
 #[cfg(host_family_unix)]
@@ -99,7 +263,11 @@ mod tests {
     use std::{fs::File, io::Read};
 
     use super::*;
-    use crate::core::{base::Parsable, chord::Chord};
+    use crate::core::{
+        base::Parsable,
+        chord::Chord,
+        note::{C, E, G},
+    };
 
     #[test]
     fn test_inference() {
@@ -121,4 +289,39 @@ mod tests {
 
         assert_eq!(chord[0], Chord::parse("C7b9").unwrap());
     }
+
+    #[test]
+    fn test_notes_from_probabilities() {
+        let mut probabilities = [0.0; NUM_CLASSES];
+        probabilities[127 - C.id_index() as usize] = 0.9;
+        probabilities[127 - E.id_index() as usize] = 0.7;
+        probabilities[127 - G.id_index() as usize] = 0.35;
+
+        let result = InferenceResult {
+            path: PathBuf::new(),
+            notes: notes_from_probabilities(&probabilities, DEFAULT_THRESHOLD),
+            probabilities,
+            error: None,
+        };
+
+        assert_eq!(result.notes, vec![C, E]);
+        assert_eq!(result.notes_at_threshold(0.5), vec![C, E]);
+        assert_eq!(result.notes_at_threshold(0.3), vec![C, E, G]);
+        assert!(result.notes_at_threshold(0.95).is_empty());
+    }
+
+    #[cfg(feature = "analyze_file")]
+    #[test]
+    fn test_infer_batch() {
+        let paths = vec![PathBuf::from("tests/C7b9.wav"), PathBuf::from("tests/missing.wav"), PathBuf::from("tests/click120.wav")];
+
+        let results = infer_batch(&paths).unwrap();
+
+        assert_eq!(results.iter().map(|r| r.path.clone()).collect::<Vec<_>>(), paths);
+        assert!(results[0].is_ok());
+        assert!(!results[1].is_ok());
+        assert!(results[2].is_ok());
+
+        assert_eq!(Chord::try_from_notes(&results[0].notes).unwrap()[0], Chord::parse("C7b9").unwrap());
+    }
 }
@@ -3,4 +3,10 @@
 pub mod execute;
 
 pub use execute::infer;
+#[cfg(feature = "analyze_file")]
+pub use execute::infer_batch;
+pub use execute::infer_probabilities;
+pub use execute::infer_with_progress;
+pub use execute::infer_with_tuning;
 pub use execute::run_inference;
+pub use execute::InferenceResult;
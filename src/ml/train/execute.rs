@@ -11,7 +11,11 @@ use burn::{
     optim::{decay::WeightDecayConfig, AdamConfig},
     record::{BinFileRecorder, FullPrecisionSettings, Recorder},
     tensor::backend::{AutodiffBackend, Backend},
-    train::{metric::LossMetric, LearnerBuilder},
+    train::{
+        metric::LossMetric,
+        renderer::{MetricState, MetricsRenderer, TrainingProgress},
+        LearnerBuilder,
+    },
 };
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -21,7 +25,7 @@ use crate::{
         data::{kord_item_to_sample_tensor, kord_item_to_target_tensor},
         helpers::{binary_to_u128, get_deterministic_guess},
         model::KordModel,
-        NUM_CLASSES,
+        ProgressEvent, NUM_CLASSES,
     },
 };
 
@@ -37,6 +41,36 @@ use crate::ml::base::TrainConfig;
 /// Given the [`TrainConfig`], this function will run the training and return the overall accuracy on
 /// the validation / test set.
 pub fn run_training<B: AutodiffBackend>(device: B::Device, config: &TrainConfig, print_accuracy_report: bool, save_model: bool) -> Res<f32>
+where
+    B::FloatElem: Serialize + DeserializeOwned,
+{
+    run_training_inner::<B>(device, config, print_accuracy_report, save_model, None)
+}
+
+/// Run the training (see [`run_training`]), reporting the progress of each epoch and batch to the `progress` callback.
+///
+/// The callback replaces the built-in training renderer.
+pub fn run_training_with_progress<B: AutodiffBackend>(
+    device: B::Device,
+    config: &TrainConfig,
+    print_accuracy_report: bool,
+    save_model: bool,
+    progress: impl Fn(ProgressEvent) + Send + Sync + 'static,
+) -> Res<f32>
+where
+    B::FloatElem: Serialize + DeserializeOwned,
+{
+    run_training_inner::<B>(device, config, print_accuracy_report, save_model, Some(Arc::new(progress)))
+}
+
+/// Run the training, optionally reporting the progress to the given callback.
+fn run_training_inner<B: AutodiffBackend>(
+    device: B::Device,
+    config: &TrainConfig,
+    print_accuracy_report: bool,
+    save_model: bool,
+    progress: Option<Arc<dyn Fn(ProgressEvent) + Send + Sync>>,
+) -> Res<f32>
 where
     B::FloatElem: Serialize + DeserializeOwned,
 {
@@ -57,6 +91,7 @@ where
         config.simulation_peak_radius,
         config.simulation_harmonic_decay,
         config.simulation_frequency_wobble,
+        config.model_seed,
     );
 
     // Define the data loaders.
@@ -93,6 +128,10 @@ where
             .metric_valid_numeric(LossMetric::new());
     }
 
+    if let Some(progress) = &progress {
+        learner_builder = learner_builder.renderer(ProgressRenderer { progress: progress.clone(), epoch: 0 });
+    }
+
     let learner = learner_builder.build(model, optimizer, ConstantLr::new(config.adam_learning_rate));
 
     // Train the model.
@@ -116,13 +155,17 @@ where
 
     let accuracy = if print_accuracy_report { compute_overall_accuracy(&model_trained, &device) } else { 0.0 };
 
+    if let Some(progress) = &progress {
+        progress(ProgressEvent::Finished);
+    }
+
     Ok(accuracy)
 }
 
 /// Compute the overall accuracy of the model.
 #[coverage(off)]
 pub fn compute_overall_accuracy<B: Backend>(model_trained: &KordModel<B>, device: &B::Device) -> f32 {
-    let dataset = KordDataset::from_folder_and_simulation("samples", 0, 0.0, 0.0, 0.0);
+    let dataset = KordDataset::from_folder_and_simulation("samples", 0, 0.0, 0.0, 0.0, 0);
 
     let kord_items = dataset.1.items;
 
@@ -267,6 +310,42 @@ pub fn hyper_parameter_tuning(source: String, destination: String, log: String,
     Ok(())
 }
 
+// Progress.
+
+/// A [`MetricsRenderer`] that forwards the training progress to a [`ProgressEvent`] callback.
+struct ProgressRenderer {
+    /// The callback.
+    progress: Arc<dyn Fn(ProgressEvent) + Send + Sync>,
+    /// The last epoch that was reported as started.
+    epoch: usize,
+}
+
+impl MetricsRenderer for ProgressRenderer {
+    fn update_train(&mut self, _state: MetricState) {}
+
+    fn update_valid(&mut self, _state: MetricState) {}
+
+    fn render_train(&mut self, item: TrainingProgress) {
+        if item.epoch != self.epoch {
+            self.epoch = item.epoch;
+
+            (self.progress)(ProgressEvent::EpochStart {
+                epoch: item.epoch,
+                epochs: item.epoch_total,
+            });
+        }
+
+        (self.progress)(ProgressEvent::BatchDone {
+            epoch: item.epoch,
+            batch: item.iteration,
+            items_processed: item.progress.items_processed,
+            items_total: item.progress.items_total,
+        });
+    }
+
+    fn render_valid(&mut self, _item: TrainingProgress) {}
+}
+
 // Tests.
 
 #[cfg(test)]
@@ -276,11 +355,8 @@ mod tests {
     use burn::backend::Autodiff;
     use burn_ndarray::{NdArray, NdArrayDevice};
 
-    #[test]
-    fn test_train() {
-        let device = NdArrayDevice::Cpu;
-
-        let config = TrainConfig {
+    fn test_config() -> TrainConfig {
+        TrainConfig {
             source: "tests/samples".to_string(),
             destination: ".hidden/test_model".to_string(),
             log: ".hidden/test_log".to_string(),
@@ -301,8 +377,32 @@ mod tests {
             adam_epsilon: 1e-5,
             sigmoid_strength: 1.0,
             no_plots: true,
-        };
+        }
+    }
+
+    #[test]
+    fn test_train() {
+        let device = NdArrayDevice::Cpu;
+
+        run_training::<Autodiff<NdArray<f32>>>(device, &test_config(), false, false).unwrap();
+    }
+
+    #[test]
+    fn test_train_with_progress() {
+        use std::sync::Mutex;
+
+        let device = NdArrayDevice::Cpu;
+        let events = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = events.clone();
+        run_training_with_progress::<Autodiff<NdArray<f32>>>(device, &test_config(), false, false, move |event| recorded.lock().unwrap().push(event)).unwrap();
+
+        let events = events.lock().unwrap();
 
-        run_training::<Autodiff<NdArray<f32>>>(device, &config, false, false).unwrap();
+        // One simulated round is 300 items, so there are 30 batches (of 10) in the single epoch.
+        assert_eq!(events.iter().filter(|e| matches!(e, ProgressEvent::EpochStart { .. })).count(), 1);
+        assert_eq!(events.iter().filter(|e| matches!(e, ProgressEvent::BatchDone { .. })).count(), 30);
+        assert_eq!(events.first(), Some(&ProgressEvent::EpochStart { epoch: 1, epochs: 1 }));
+        assert_eq!(events.last(), Some(&ProgressEvent::Finished));
     }
 }
@@ -14,7 +14,7 @@ use burn::{
         TrainOutput, TrainStep, ValidStep,
     },
 };
-use rand::Rng;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
 use crate::{
@@ -161,10 +161,12 @@ impl<B: Backend> Numeric for KordAccuracyMetric<B> {
 // Operations for simulating kord samples.
 
 /// Create a simulated kord sample item from a noise basis and a semi-random collection of notes.
-pub fn get_simulated_kord_item(notes: &[Note], peak_radius: f32, harmonic_decay: f32, frequency_wobble: f32) -> KordItem {
+///
+/// All of the randomness (the noise basis, frequency wobble, and peak strengths) is drawn from `rng`.
+pub fn get_simulated_kord_item<R: Rng>(rng: &mut R, notes: &[Note], peak_radius: f32, harmonic_decay: f32, frequency_wobble: f32) -> KordItem {
     let wobble_divisor = 35.0;
 
-    let mut result = match get_random_between(0.0, 4.0).round() as u32 {
+    let mut result = match get_random_between(rng, 0.0, 4.0).round() as u32 {
         0 | 4 => load_kord_item("assets/no_noise.bin"),
         1 => load_kord_item("assets/pink_noise.bin"),
         2 => load_kord_item("assets/white_noise.bin"),
@@ -175,12 +177,12 @@ pub fn get_simulated_kord_item(notes: &[Note], peak_radius: f32, harmonic_decay:
     for note in notes {
         let mut harmonic_strength = 1.0;
 
-        let note_frequency = note.frequency() * (1.0 + 1.0 / wobble_divisor * get_random_between(-frequency_wobble, frequency_wobble));
+        let note_frequency = note.frequency() * (1.0 + 1.0 / wobble_divisor * get_random_between(rng, -frequency_wobble, frequency_wobble));
 
         let true_harmonic_series = (1..14)
             .map(|k| {
                 let f = k as f32 * note_frequency;
-                f * (1.0 + 1.0 / wobble_divisor * get_random_between(-frequency_wobble, frequency_wobble))
+                f * (1.0 + 1.0 / wobble_divisor * get_random_between(rng, -frequency_wobble, frequency_wobble))
             })
             .collect::<Vec<_>>();
 
@@ -189,7 +191,7 @@ pub fn get_simulated_kord_item(notes: &[Note], peak_radius: f32, harmonic_decay:
                 continue;
             }
 
-            let peak_strength = 4000.0 * harmonic_strength * get_random_between(0.8, 1.0);
+            let peak_strength = 4000.0 * harmonic_strength * get_random_between(rng, 0.8, 1.0);
 
             for i in (harmonic_frequency - peak_radius).round() as usize..(harmonic_frequency + peak_radius).round() as usize {
                 result.frequency_space[i] += peak_strength * (1.0 - ((2.0 / peak_radius) * (i as f32 - harmonic_frequency).abs()).tanh());
@@ -206,8 +208,13 @@ pub fn get_simulated_kord_item(notes: &[Note], peak_radius: f32, harmonic_decay:
 
 /// Create simulated kord sample item by randomly selecting notes from a list of notes,
 /// and use the given configuration.
-pub fn get_simulated_kord_items(count: usize, peak_radius: f32, harmonic_decay: f32, frequency_wobble: f32) -> Vec<KordItem> {
-    let results = (0..count).into_par_iter().map(|_| {
+///
+/// The simulation is fully determined by the `seed`: each of the `count` rounds draws from its own generator (derived
+/// from the `seed` and the round), so the result does not depend on how the rounds are scheduled across threads.
+pub fn get_simulated_kord_items(count: usize, peak_radius: f32, harmonic_decay: f32, frequency_wobble: f32, seed: u64) -> Vec<KordItem> {
+    let results = (0..count).into_par_iter().map(|round| {
+        let mut rng = StdRng::seed_from_u64(seed ^ (round as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+
         let note_count = 60;
         let chord_count = 5;
         let mut inner_result = Vec::with_capacity(note_count * chord_count);
@@ -227,31 +234,49 @@ pub fn get_simulated_kord_items(count: usize, peak_radius: f32, harmonic_decay:
                     }
                     2 => {
                         notes.push(note);
-                        notes.push(note + get_random_item(&[Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth]));
+                        notes.push(
+                            note + get_random_item(
+                                &mut rng,
+                                &[Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth],
+                            ),
+                        );
                     }
                     3 => {
                         notes.push(note);
-                        notes.push(note + get_random_item(&[Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth]));
-                        notes.push(note + get_random_item(&[Interval::AugmentedFourth, Interval::PerfectFifth, Interval::AugmentedFifth, Interval::MajorSixth]));
+                        notes.push(
+                            note + get_random_item(
+                                &mut rng,
+                                &[Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth],
+                            ),
+                        );
+                        notes.push(note + get_random_item(&mut rng, &[Interval::AugmentedFourth, Interval::PerfectFifth, Interval::AugmentedFifth, Interval::MajorSixth]));
                     }
                     4 => {
                         notes.push(note);
-                        notes.push(note + get_random_item(&[Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth]));
-                        notes.push(note + get_random_item(&[Interval::AugmentedFourth, Interval::PerfectFifth, Interval::AugmentedFifth, Interval::MajorSixth]));
                         notes.push(
-                            note + get_random_item(&[
-                                Interval::MinorSeventh,
-                                Interval::MajorSeventh,
-                                Interval::MinorNinth,
-                                Interval::MajorNinth,
-                                Interval::AugmentedNinth,
-                                Interval::DiminishedEleventh,
-                                Interval::PerfectEleventh,
-                                Interval::AugmentedEleventh,
-                                Interval::MinorThirteenth,
-                                Interval::MajorThirteenth,
-                                Interval::AugmentedThirteenth,
-                            ]),
+                            note + get_random_item(
+                                &mut rng,
+                                &[Interval::MinorSecond, Interval::MajorSecond, Interval::MinorThird, Interval::MajorThird, Interval::PerfectFourth],
+                            ),
+                        );
+                        notes.push(note + get_random_item(&mut rng, &[Interval::AugmentedFourth, Interval::PerfectFifth, Interval::AugmentedFifth, Interval::MajorSixth]));
+                        notes.push(
+                            note + get_random_item(
+                                &mut rng,
+                                &[
+                                    Interval::MinorSeventh,
+                                    Interval::MajorSeventh,
+                                    Interval::MinorNinth,
+                                    Interval::MajorNinth,
+                                    Interval::AugmentedNinth,
+                                    Interval::DiminishedEleventh,
+                                    Interval::PerfectEleventh,
+                                    Interval::AugmentedEleventh,
+                                    Interval::MinorThirteenth,
+                                    Interval::MajorThirteenth,
+                                    Interval::AugmentedThirteenth,
+                                ],
+                            ),
                         );
                     }
                     _ => unreachable!(),
@@ -260,7 +285,7 @@ pub fn get_simulated_kord_items(count: usize, peak_radius: f32, harmonic_decay:
                 notes.sort();
 
                 // Generate the sample.
-                let kord_item = get_simulated_kord_item(&notes, peak_radius, harmonic_decay, frequency_wobble);
+                let kord_item = get_simulated_kord_item(&mut rng, &notes, peak_radius, harmonic_decay, frequency_wobble);
 
                 inner_result.push(kord_item);
             }
@@ -273,21 +298,18 @@ pub fn get_simulated_kord_items(count: usize, peak_radius: f32, harmonic_decay:
 }
 
 /// Get a random item from a list of items.
-pub fn get_random_item<T: Copy>(items: &[T]) -> T {
-    let mut rng = rand::thread_rng();
+pub fn get_random_item<T: Copy, R: Rng>(rng: &mut R, items: &[T]) -> T {
     let index = rng.gen_range(0..items.len());
     items[index]
 }
 
 /// Get a random number between 0 and 1.
-pub fn get_random() -> f32 {
-    let mut rng = rand::thread_rng();
+pub fn get_random<R: Rng>(rng: &mut R) -> f32 {
     rng.gen()
 }
 
 /// Get a random number between two numbers.
-pub fn get_random_between(min: f32, max: f32) -> f32 {
-    let mut rng = rand::thread_rng();
+pub fn get_random_between<R: Rng>(rng: &mut R, min: f32, max: f32) -> f32 {
     rng.gen_range(min..max)
 }
 
@@ -318,4 +340,17 @@ mod tests {
 
         assert_eq!(item.label, loaded.label);
     }
+
+    #[test]
+    fn test_simulated_kord_items_are_reproducible() {
+        let to_bits = |items: &[KordItem]| items.iter().map(|item| (item.label, item.frequency_space.map(f32::to_bits))).collect::<Vec<_>>();
+
+        let first = get_simulated_kord_items(2, 1.0, 0.5, 0.5, 42);
+        let second = get_simulated_kord_items(2, 1.0, 0.5, 0.5, 42);
+        let other = get_simulated_kord_items(2, 1.0, 0.5, 0.5, 43);
+
+        assert_eq!(first.len(), 600);
+        assert!(to_bits(&first) == to_bits(&second));
+        assert!(to_bits(&first) != to_bits(&other));
+    }
 }
@@ -5,3 +5,4 @@ pub mod execute;
 pub mod helpers;
 
 pub use execute::run_training;
+pub use execute::run_training_with_progress;
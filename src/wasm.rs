@@ -286,6 +286,16 @@ impl KordChord {
         self.inner.extensions().iter().map(|e| e.static_name()).into_js_array()
     }
 
+    /// Returns a new [`Chord`] transposed by the given number of semitones (up, if positive, or down, if negative).
+    ///
+    /// Returns an error if the chord would be transposed out of the octave range.
+    #[wasm_bindgen]
+    pub fn transpose(&self, semitones: i8) -> JsRes<KordChord> {
+        Ok(KordChord {
+            inner: self.inner.transpose_semitones(semitones).to_js_error()?,
+        })
+    }
+
     /// Returns a new [`Chord`] with the inversion set to the provided value.
     #[wasm_bindgen(js_name = withInversion)]
     pub fn with_inversion(&self, inversion: u8) -> Self {
@@ -345,6 +355,19 @@ impl KordChord {
     }
 }
 
+// Analysis.
+
+/// Returns the detection confidence of each of the 12 pitch classes (indexed from C) in the audio data.
+///
+/// The values are normalized so that the strongest pitch class is `1.0`, which allows a visualizer to use them directly.
+#[cfg(feature = "analyze_base")]
+#[wasm_bindgen(js_name = analyzePitchDeltas)]
+pub fn analyze_pitch_deltas(audio: &[f32], length_in_seconds: u8) -> JsRes<Vec<f32>> {
+    let confidences = crate::analyze::base::get_pitch_confidences(audio, length_in_seconds).to_js_error()?;
+
+    Ok(confidences.to_vec())
+}
+
 // Playback handle.
 
 /// A handle to a [`Chord`] playback.
@@ -491,12 +514,7 @@ impl RefFromJsValue for KordNote {
     where
         Self: Sized + RefFromWasmAbi,
     {
-        let ptr = Reflect::get(abi, &JsValue::from_str("ptr"))?.as_f64().ok_or("Could not cast pointer to f64.")? as u32;
-
-        let object = abi.dyn_ref::<Object>().ok_or("Value is not an object.")?;
-        if object.constructor().name() != "KordNote" {
-            return Err("Invalid object type.".into());
-        }
+        let ptr = ptr_from_js_value(abi, "KordNote")?;
 
         // SAFETY: We have done as much as we can to ensure that this is as safe as it can
         // be, considering the inherent unsafety of working with an ABI.
@@ -514,12 +532,7 @@ impl RefFromJsValue for KordChord {
     where
         Self: Sized + RefFromWasmAbi,
     {
-        let ptr = Reflect::get(abi, &JsValue::from_str("ptr"))?.as_f64().ok_or("Could not cast pointer to f64.")? as u32;
-
-        let object = abi.dyn_ref::<Object>().ok_or("Value is not an object.")?;
-        if object.constructor().name() != "KordChord" {
-            return Err("Invalid object type.".into());
-        }
+        let ptr = ptr_from_js_value(abi, "KordChord")?;
 
         // SAFETY: We have done as much as we can to ensure that this is as safe as it can
         // be, considering the inherent unsafety of working with an ABI.
@@ -532,6 +545,29 @@ impl RefFromJsValue for KordChord {
     }
 }
 
+/// Returns the pointer to the Rust value that backs the given `wasm-bindgen` wrapper object, after confirming
+/// that the object is of the expected type.
+///
+/// Newer versions of `wasm-bindgen` store the pointer as `__wbg_ptr` (older versions used `ptr`), so both are checked.
+fn ptr_from_js_value(abi: &JsValue, type_name: &str) -> JsRes<u32> {
+    let object = abi.dyn_ref::<Object>().ok_or("Value is not an object.")?;
+    if object.constructor().name() != type_name {
+        return Err(format!("Invalid object type (expected `{}`).", type_name).into());
+    }
+
+    for key in ["__wbg_ptr", "ptr"] {
+        if let Some(ptr) = Reflect::get(abi, &JsValue::from_str(key))?.as_f64() {
+            if ptr == 0.0 {
+                return Err(format!("The `{}` has already been freed.", type_name).into());
+            }
+
+            return Ok(ptr as u32);
+        }
+    }
+
+    Err(format!("Could not find the pointer of the `{}`.", type_name).into())
+}
+
 // JS helpers.
 
 // #[wasm_bindgen(inline_js = "export function sleep(millis) { return new Promise(resolve => setTimeout(() => resolve(), millis)); }")]
@@ -686,3 +722,71 @@ impl KordChord {
         KordChord { inner: self.inner.clone().add13() }
     }
 }
+
+// Tests.
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_from_notes() {
+        let notes = ["C4", "E4", "G4"].iter().map(|n| KordNote::parse(n.to_string()).unwrap()).into_js_array();
+
+        let candidates = KordChord::from_notes(notes.clone()).unwrap();
+        let first = KordChord::ref_from_js_value(&candidates.get(0)).unwrap();
+
+        assert_eq!(first.name(), "C");
+
+        // The notes are cloned (not consumed), so they can be reused.
+        assert_eq!(KordNote::ref_from_js_value(&notes.get(0)).unwrap().name(), "C4");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_transpose() {
+        let chord = KordChord::parse("C".to_string()).unwrap();
+
+        assert_eq!(chord.transpose(7).unwrap().name(), "G");
+        assert_eq!(chord.transpose(-5).unwrap().root(), "G3");
+        assert!(chord.transpose(-100).is_err());
+
+        // The original chord is unchanged.
+        assert_eq!(chord.name(), "C");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_with_inversion() {
+        let chord = KordChord::parse("Cmaj7".to_string()).unwrap().with_inversion(1);
+
+        assert_eq!(chord.inversion(), 1);
+        assert_eq!(chord.chord_string(), "E4 G4 B4 C5");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_notes_invalid() {
+        let chord = KordChord::parse("C".to_string()).unwrap();
+        let values = [JsValue::from(chord), JsValue::from_f64(1.0)].into_js_array();
+
+        assert!(KordChord::from_notes(values).is_err());
+    }
+
+    #[cfg(feature = "analyze_base")]
+    #[wasm_bindgen_test]
+    fn test_analyze_pitch_deltas() {
+        use crate::{analyze::base::tests::synthesize_notes, core::note::*};
+
+        let audio = synthesize_notes(&[C, E, G], 44_100, 2.0);
+        let deltas = analyze_pitch_deltas(&audio, 2).unwrap();
+
+        assert_eq!(deltas.len(), 12);
+
+        for (k, delta) in deltas.iter().enumerate() {
+            match k {
+                0 | 4 | 7 => assert!(*delta > 0.5, "pitch class {} should be elevated (got {})", k, delta),
+                _ => assert!(*delta < 0.5, "pitch class {} should not be elevated (got {})", k, delta),
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Tests that run the `kord` binary.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+
+// Tests.
+
+#[test]
+fn test_describe_json() {
+    let output = kord_json(&["describe", "Cmaj7"]);
+
+    assert_eq!(output["name"], "Cmaj7");
+    assert_eq!(output["precise_name"], "Cmaj7");
+    assert_eq!(output["root"], "C4");
+    assert_eq!(output["inversion"], 0);
+    assert_eq!(output["scale"], serde_json::json!(["C4", "D4", "E4", "F4", "G4", "A4", "B4"]));
+    assert_eq!(output["scale_candidates"], serde_json::json!(["ionian", "major"]));
+    assert_eq!(output["chord"], serde_json::json!(["C4", "E4", "G4", "B4"]));
+    assert!(output.get("frequencies").is_none());
+
+    // The frequencies are only included when `--a4` is given.
+    let output = kord_json(&["--a4", "432", "describe", "Am"]);
+    let frequencies = output["frequencies"].as_array().unwrap();
+
+    assert_eq!(frequencies.len(), 3);
+    assert!((frequencies[0].as_f64().unwrap() - 432.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_guess_json() {
+    let output = kord_json(&["guess", "C", "E", "G"]);
+    let candidates = output.as_array().unwrap();
+
+    assert!(!candidates.is_empty());
+    assert_eq!(candidates[0]["name"], "C");
+    assert!(candidates.iter().all(|c| c["scale_candidates"].as_array().is_some_and(|s| !s.is_empty())));
+}
+
+// Helpers.
+
+/// Runs the `kord` binary with `--format json` and the given arguments, and parses its output.
+fn kord_json(args: &[&str]) -> Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_kord")).args(["--format", "json"]).args(args).output().unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    serde_json::from_slice(&output.stdout).unwrap()
+}